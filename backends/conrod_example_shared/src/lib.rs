@@ -57,6 +57,8 @@ pub fn theme() -> conrod_core::Theme {
         widget_styling: conrod_core::theme::StyleMap::default(),
         mouse_drag_threshold: 0.0,
         double_click_threshold: std::time::Duration::from_millis(500),
+        text_cursor_blink_interval: None,
+        transition: conrod_core::animation::Transition::Immediate,
     }
 }
 
@@ -103,6 +103,11 @@ fn main() {
         // Instantiate a GUI demonstrating every widget type provided by conrod.
         conrod_example_shared::gui(&mut ui.set_widgets(), &ids, &mut app);
 
+        // Wake up again if some widget has requested to be updated in the future.
+        if let Some(deadline) = ui.next_redraw_deadline() {
+            event_loop.needs_update_at(deadline);
+        }
+
         // Draw the `Ui`.
         if let Some(primitives) = ui.draw_if_changed() {
             renderer.fill(&display.0, primitives, &image_map);
@@ -10,7 +10,8 @@ extern crate petgraph;
 mod support;
 
 use conrod_core::{widget, Borderable, Colorable, Labelable, Positionable, Sizeable, Widget};
-use conrod_core::widget::graph::{node, Event, EdgeEvent, Node, NodeEvent, NodeSocket};
+use conrod_core::widget::graph::{edge, Event, EdgeEvent, Node, NodeEvent, NodeSocket};
+use conrod_core::widget::graph::{SelectionEvent, SocketEvent};
 use glium::Surface;
 use std::collections::HashMap;
 
@@ -140,6 +141,15 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
     // 1. `Nodes` for setting a node widget for each node.
     // 2. `Edges` for setting an edge widget for each edge.
     // 3. `Final` for optionally displaying zoom percentage and cam position.
+    //
+    // Drag with the middle mouse button (or hold `Space` and drag) to pan, scroll to zoom and
+    // press `F` to zoom to fit all nodes.
+
+    let zoom_to_fit = ui.global_input().events().any(|event| match *event {
+        conrod_core::event::Event::Raw(conrod_core::event::Input::Press(button)) =>
+            button == conrod_core::input::Button::Keyboard(conrod_core::input::Key::F),
+        _ => false,
+    });
 
     let session = {
         // An identifier for each node in the graph.
@@ -152,11 +162,14 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
                 let end = NodeSocket { id: e.target(), socket_index: e.weight.1 };
                 (start, end)
             });
-        widget::Graph::new(node_indices, edges, layout)
+        let graph = widget::Graph::new(node_indices, edges, layout)
             .background_color(conrod_core::color::rgb(0.31, 0.33, 0.35))
             .wh_of(ui.window)
             .middle_of(ui.window)
-            .set(ids.graph, ui)
+            // Never allow a node to be connected to itself.
+            .validate_edge(|c: &widget::graph::Connection<_>| c.start.id != c.end.id);
+        let graph = if zoom_to_fit { graph.zoom_to_fit() } else { graph };
+        graph.set(ids.graph, ui)
     };
 
     //////////////////
@@ -183,9 +196,27 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
                 },
                 EdgeEvent::Cancelled(node_socket) => {
                 },
+                EdgeEvent::TargetStart { start, end } => {
+                },
+                EdgeEvent::TargetEnd { start, end } => {
+                },
                 EdgeEvent::Remove { start, end } => {
                 },
             },
+            Event::Selection(event) => match event {
+                SelectionEvent::Changed => {
+                },
+                SelectionEvent::Duplicate => {
+                },
+                SelectionEvent::Copy => {
+                },
+            },
+            Event::Socket(event) => match event {
+                SocketEvent::HoverStart(node_socket, socket_type) => {
+                },
+                SocketEvent::HoverEnd(node_socket, socket_type) => {
+                },
+            },
         }
     }
 
@@ -200,7 +231,10 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
         // Each `Node` contains:
         //
         // `id` - The unique node identifier for this node.
-        // `point` - The position at which this node will be set.
+        // `point` - The position of this node in graph space.
+        // `zoom` - The zoom of the graph's camera.
+        // `is_selected` - Whether or not the node is selected.
+        // `invalid_sockets` - The sockets that the edge being dragged may not connect to.
         // `inputs`
         // `outputs`
         //
@@ -208,14 +242,24 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
         //
         // `wiget_id` - The widget identifier for the widget that will represent this node.
         let node_id = node.node_id();
+        let zoom = node.zoom();
         let inputs = graph.neighbors_directed(node_id, petgraph::Incoming).count();
         let outputs = graph.neighbors_directed(node_id, petgraph::Outgoing).count();
         let button = widget::Button::new()
             .label(&graph[node_id])
+            .label_font_size((14.0 * zoom) as conrod_core::FontSize)
             .border(0.0);
+        // Use `.input_sockets(..)` and `.output_sockets(..)` with `node::Socket`s to give the
+        // sockets labels, colors and kinds. Only sockets of the same kind may be connected.
         let widget = Node::new(button)
             .inputs(inputs)
             .outputs(outputs)
+            .invalid_sockets(node.invalid_sockets().iter().cloned())
+            .zoom(zoom)
+            .border_color(match node.is_selected() {
+                true => conrod_core::color::LIGHT_BLUE,
+                false => conrod_core::color::DARK_CHARCOAL,
+            })
             //.socket_color(conrod_core::color::LIGHT_RED)
             .w_h(100.0, 60.0);
         for _click in node.widget(widget).set(ui).widget_event {
@@ -231,16 +275,19 @@ fn set_widgets(ui: &mut conrod_core::UiCell, ids: &Ids, graph: &mut MyGraph, lay
 
     let mut session = session.next();
     for edge in session.edges() {
-        let (a, b) = node::edge_socket_rects(&edge, ui);
-        let line = widget::Line::abs(a.xy(), b.xy())
+        // `edge::connector` draws a bezier curve between the edge's sockets. Use `.orthogonal()`
+        // to route it around the nodes instead.
+        let connector = edge::connector(&edge, ui)
             .color(conrod_core::color::DARK_CHARCOAL)
-            .thickness(3.0);
+            .arrow(12.0);
 
         // Each edge contains:
         //
         // `start` - The unique node identifier for the node at the start of the edge with point.
         // `end` - The unique node identifier for the node at the end of the edge with point.
         // `widget_id` - The wiget identifier for this edge.
-        edge.widget(line).set(ui);
+        if edge.widget(connector).set(ui).was_clicked {
+            println!("edge was clicked!");
+        }
     }
 }
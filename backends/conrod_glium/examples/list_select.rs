@@ -46,7 +46,7 @@ fn main() {
     let image_map = conrod_core::image::Map::<glium::texture::Texture2d>::new();
 
     // List of entries to display. They should implement the Display trait.
-    let list_items = [
+    let mut list_items = vec![
         "African Sideneck Turtle".to_string(),
         "Alligator Snapping Turtle".to_string(),
         "Common Snapping Turtle".to_string(),
@@ -114,6 +114,7 @@ fn main() {
                 .flow_down()
                 .item_size(item_h)
                 .scrollbar_next_to()
+                .reorderable()
                 .w_h(400.0, 230.0)
                 .top_left_with_margins_on(ids.canvas, 40.0, 40.0)
                 .set(ids.list_select, ui);
@@ -145,6 +146,20 @@ fn main() {
                         println!("selected indices: {:?}", list_selected);
                     }
 
+                    // Items were dragged to a new position, so move them and their selection.
+                    Event::Move(move_) => {
+                        let mut selected: Vec<bool> = (0..num_items)
+                            .map(|i| list_selected.contains(&i))
+                            .collect();
+                        move_.apply(&mut list_items);
+                        move_.apply(&mut selected);
+                        list_selected = selected.iter()
+                            .enumerate()
+                            .filter(|&(_, &is_selected)| is_selected)
+                            .map(|(i, _)| i)
+                            .collect();
+                    }
+
                     // The remaining events indicate interactions with the `ListSelect` widget.
                     event => println!("{:?}", &event),
                 }
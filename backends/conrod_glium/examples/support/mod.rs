@@ -22,6 +22,7 @@ impl conrod_winit::WinitWindow for GliumDisplayWinitWrapper {
 /// glutin+glium event loop that works efficiently with conrod.
 pub struct EventLoop {
     ui_needs_update: bool,
+    maybe_update_deadline: Option<std::time::Instant>,
     last_update: std::time::Instant,
 }
 
@@ -30,6 +31,7 @@ impl EventLoop {
         EventLoop {
             last_update: std::time::Instant::now(),
             ui_needs_update: true,
+            maybe_update_deadline: None,
         }
     }
 
@@ -48,15 +50,30 @@ impl EventLoop {
         let mut events = Vec::new();
         events_loop.poll_events(|event| events.push(event));
 
-        // If there are no events and the `Ui` does not need updating, wait for the next event.
+        // If there are no events and the `Ui` does not need updating, wait for the next event or
+        // until the `Ui`'s requested deadline, whichever comes first.
         if events.is_empty() && !self.ui_needs_update {
-            events_loop.run_forever(|event| {
-                events.push(event);
-                glium::glutin::ControlFlow::Break
-            });
+            match self.maybe_update_deadline {
+                None => events_loop.run_forever(|event| {
+                    events.push(event);
+                    glium::glutin::ControlFlow::Break
+                }),
+                Some(deadline) => loop {
+                    let now = std::time::Instant::now();
+                    if deadline <= now {
+                        break;
+                    }
+                    std::thread::sleep(std::cmp::min(deadline - now, sixteen_ms));
+                    events_loop.poll_events(|event| events.push(event));
+                    if !events.is_empty() {
+                        break;
+                    }
+                },
+            }
         }
 
         self.ui_needs_update = false;
+        self.maybe_update_deadline = None;
         self.last_update = std::time::Instant::now();
 
         events
@@ -70,6 +87,18 @@ impl EventLoop {
     pub fn needs_update(&mut self) {
         self.ui_needs_update = true;
     }
+
+    /// Notifies the event loop that the `Ui` requires another update at the given deadline
+    /// whether or not there are any pending events.
+    ///
+    /// This is normally called with the result of `Ui::next_redraw_deadline` after updating the
+    /// `Ui`.
+    pub fn needs_update_at(&mut self, deadline: std::time::Instant) {
+        self.maybe_update_deadline = match self.maybe_update_deadline {
+            Some(current) if current <= deadline => Some(current),
+            _ => Some(deadline),
+        };
+    }
 }
 
 // Conversion functions for converting between types from glium's version of `winit` and
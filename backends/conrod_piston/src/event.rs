@@ -65,5 +65,17 @@ pub fn convert<E>(event: E, win_w: Scalar, win_h: Scalar) -> Option<event::Input
         return Some(event::Input::Focus(b));
     }
 
+    // Files dragged onto the window from other applications.
+    let file_drag = event.with_args(|args| {
+        args.downcast_ref::<::piston_input::FileDrag>().cloned()
+    });
+    if let Some(file_drag) = file_drag {
+        return Some(match file_drag {
+            ::piston_input::FileDrag::Hover(path) => event::Input::HoveredFile(path),
+            ::piston_input::FileDrag::Drop(path) => event::Input::DroppedFile(path),
+            ::piston_input::FileDrag::Cancel => event::Input::HoveredFileCancelled,
+        });
+    }
+
     None
 }
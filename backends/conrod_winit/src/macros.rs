@@ -247,6 +247,15 @@ macro_rules! convert_window_event {
                 Some(conrod_core::event::Input::Redraw)
             },
 
+            winit::WindowEvent::HoveredFile(path) =>
+                Some(conrod_core::event::Input::HoveredFile(path)),
+
+            winit::WindowEvent::DroppedFile(path) =>
+                Some(conrod_core::event::Input::DroppedFile(path)),
+
+            winit::WindowEvent::HoveredFileCancelled =>
+                Some(conrod_core::event::Input::HoveredFileCancelled),
+
             _ => None,
         }
     }};
@@ -0,0 +1,378 @@
+//! Types and functionality related to animating widget values over time.
+//!
+//! Conrod is an immediate mode GUI, so widgets have no natural place to store a value that moves
+//! towards some target over many frames. The **Animations** type stored within the **Ui** fills
+//! this gap. Widgets describe the value they would like to end up at along with a **Transition**
+//! describing how to get there, and the **Ui** returns the value for the current frame.
+//!
+//! ```ignore
+//! // Within some `Widget::update` implementation.
+//! let target = if is_open { 1.0 } else { 0.0 };
+//! let openness = ui.animate(id, "openness", target, style.transition(&ui.theme));
+//! ```
+//!
+//! While any animation is still in progress, the **Ui** keeps requesting redraws and
+//! **Ui::is_animating** returns `true`. Applications that only update their **Ui** in response to
+//! events should keep updating while this is the case.
+
+use fnv;
+use instant::Instant;
+use std::time::Duration;
+use utils;
+use widget;
+use Scalar;
+
+
+/// The distance from the target below which an animation is considered to have settled.
+pub const REST_DELTA: Scalar = 0.001;
+
+/// The longest time step used when integrating a **Spring**.
+///
+/// Larger steps are divided into smaller ones to keep the simulation stable after long frames.
+pub const MAX_SPRING_STEP_SECS: Scalar = 1.0 / 120.0;
+
+/// Describes how an animated value moves from its current value towards its target.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Transition {
+    /// Jump straight to the target.
+    Immediate,
+    /// Move towards the target over a fixed duration following some **Easing** curve.
+    Tween {
+        /// The time taken to reach the target.
+        duration: Duration,
+        /// The curve describing progress over the duration.
+        easing: Easing,
+    },
+    /// Move towards the target using a simulated damped spring.
+    Spring(Spring),
+}
+
+/// Curves that map the linear progress of a **Tween** to eased progress.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Quadratic, accelerating from zero velocity.
+    QuadIn,
+    /// Quadratic, decelerating to zero velocity.
+    QuadOut,
+    /// Quadratic, accelerating until halfway, then decelerating.
+    QuadInOut,
+    /// Cubic, accelerating from zero velocity.
+    CubicIn,
+    /// Cubic, decelerating to zero velocity.
+    CubicOut,
+    /// Cubic, accelerating until halfway, then decelerating.
+    CubicInOut,
+    /// Sinusoidal, accelerating from zero velocity.
+    SineIn,
+    /// Sinusoidal, decelerating to zero velocity.
+    SineOut,
+    /// Sinusoidal, accelerating until halfway, then decelerating.
+    SineInOut,
+    /// Exponential, decelerating to zero velocity.
+    ExpoOut,
+}
+
+/// The parameters for a damped spring simulation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spring {
+    /// The strength with which the spring pulls the value towards its target.
+    pub stiffness: Scalar,
+    /// The strength of the force opposing the velocity of the value.
+    pub damping: Scalar,
+    /// The mass of the value. Heavier values accelerate more slowly.
+    pub mass: Scalar,
+}
+
+/// A single value being animated towards some target.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Animation {
+    /// The value for the current frame.
+    pub value: Scalar,
+    /// The value towards which the animation is moving.
+    pub target: Scalar,
+    /// The velocity of the value in units per second.
+    ///
+    /// Only **Spring** transitions track velocity.
+    pub velocity: Scalar,
+    /// The transition used to move towards the target.
+    pub transition: Transition,
+    /// The value at the moment the target last changed.
+    from: Scalar,
+    /// The moment at which the target last changed.
+    start: Instant,
+    /// The moment at which the value was last stepped.
+    last_step: Instant,
+    /// Whether or not the animation was requested during the current frame.
+    is_updated: bool,
+}
+
+/// All animations within a **Ui**, keyed by the `widget::Id` that requested them along with the
+/// name of the animated property, allowing a single widget to animate many values at once.
+///
+/// Animations that are not requested during a call to `Ui::set_widgets` are removed at the end
+/// of it, so a widget that is no longer instantiated does not keep the **Ui** animating.
+#[derive(Clone, Debug)]
+pub struct Animations {
+    map: fnv::FnvHashMap<(widget::Id, &'static str), Animation>,
+    frame_time: Instant,
+}
+
+
+impl Transition {
+
+    /// A **Tween** transition over the given duration.
+    pub fn tween(duration: Duration, easing: Easing) -> Self {
+        Transition::Tween { duration: duration, easing: easing }
+    }
+
+    /// A **Spring** transition with the default spring parameters.
+    pub fn spring() -> Self {
+        Transition::Spring(Spring::default())
+    }
+
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::Immediate
+    }
+}
+
+impl Easing {
+
+    /// Map the given linear progress `t` (in the range `0.0..=1.0`) to eased progress.
+    pub fn ease(&self, t: Scalar) -> Scalar {
+        use std::f64::consts::PI;
+        let t = utils::clamp(t, 0.0, 1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2.0 - t),
+            Easing::QuadInOut => if t < 0.5 {
+                2.0 * t * t
+            } else {
+                -1.0 + (4.0 - 2.0 * t) * t
+            },
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let t = t - 1.0;
+                t * t * t + 1.0
+            },
+            Easing::CubicInOut => if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                let t = 2.0 * t - 2.0;
+                0.5 * t * t * t + 1.0
+            },
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -0.5 * ((PI * t).cos() - 1.0),
+            Easing::ExpoOut => if t == 1.0 { 1.0 } else { 1.0 - (2.0 as Scalar).powf(-10.0 * t) },
+        }
+    }
+
+}
+
+impl Spring {
+
+    /// A critically damped spring with the given stiffness and a mass of `1.0`.
+    ///
+    /// Critically damped springs reach their target as quickly as possible without overshooting.
+    pub fn critically_damped(stiffness: Scalar) -> Self {
+        Spring {
+            stiffness: stiffness,
+            damping: 2.0 * stiffness.sqrt(),
+            mass: 1.0,
+        }
+    }
+
+    /// Step the given `value` and `velocity` towards `target` by `secs` seconds.
+    ///
+    /// Returns the new value and velocity respectively.
+    pub fn step(&self, mut value: Scalar, mut velocity: Scalar, target: Scalar, secs: Scalar)
+        -> (Scalar, Scalar)
+    {
+        let mut remaining = secs;
+        while remaining > 0.0 {
+            let dt = remaining.min(MAX_SPRING_STEP_SECS);
+            let force = -self.stiffness * (value - target) - self.damping * velocity;
+            velocity += force / self.mass * dt;
+            value += velocity * dt;
+            remaining -= dt;
+        }
+        (value, velocity)
+    }
+
+}
+
+impl Default for Spring {
+    /// A critically damped spring, so that values such as the openness of a menu never overshoot
+    /// their target.
+    fn default() -> Self {
+        Spring::critically_damped(170.0)
+    }
+}
+
+impl Animation {
+
+    /// An animation resting at the given value.
+    pub fn at_rest(value: Scalar, transition: Transition, now: Instant) -> Self {
+        Animation {
+            value: value,
+            target: value,
+            velocity: 0.0,
+            transition: transition,
+            from: value,
+            start: now,
+            last_step: now,
+            is_updated: true,
+        }
+    }
+
+    /// Whether or not the value has settled at its target.
+    pub fn is_at_rest(&self) -> bool {
+        self.value == self.target && self.velocity == 0.0
+    }
+
+    /// Set a new target for the animation.
+    ///
+    /// The animation continues from its current value (and velocity in the case of a **Spring**).
+    pub fn set_target(&mut self, target: Scalar, now: Instant) {
+        if self.target != target {
+            self.target = target;
+            self.from = self.value;
+            self.start = now;
+        }
+    }
+
+    /// Step the animation forward to the given moment in time.
+    pub fn step(&mut self, now: Instant) {
+        if now < self.last_step {
+            return;
+        }
+        let elapsed = now.duration_since(self.last_step);
+        self.last_step = now;
+
+        if self.is_at_rest() {
+            return;
+        }
+
+        match self.transition {
+            Transition::Immediate => {
+                self.value = self.target;
+                self.velocity = 0.0;
+            },
+            Transition::Tween { duration, easing } => {
+                self.velocity = 0.0;
+                let since_start = secs(now.duration_since(self.start));
+                let duration = secs(duration);
+                if since_start >= duration {
+                    self.value = self.target;
+                } else {
+                    let t = easing.ease(since_start / duration);
+                    self.value = self.from + (self.target - self.from) * t;
+                }
+            },
+            Transition::Spring(spring) => {
+                let (value, velocity) =
+                    spring.step(self.value, self.velocity, self.target, secs(elapsed));
+                if (value - self.target).abs() < REST_DELTA && velocity.abs() < REST_DELTA {
+                    self.value = self.target;
+                    self.velocity = 0.0;
+                } else {
+                    self.value = value;
+                    self.velocity = velocity;
+                }
+            },
+        }
+    }
+
+}
+
+impl Animations {
+
+    /// An empty collection of animations.
+    pub fn new() -> Self {
+        Animations {
+            map: fnv::FnvHashMap::default(),
+            frame_time: Instant::now(),
+        }
+    }
+
+    /// The moment in time used to step all animations during the current frame.
+    pub fn frame_time(&self) -> Instant {
+        self.frame_time
+    }
+
+    /// Begin a new frame at the given moment in time.
+    ///
+    /// This is called by `Ui::set_widgets`.
+    pub fn begin_frame(&mut self, now: Instant) {
+        self.frame_time = now;
+        for animation in self.map.values_mut() {
+            animation.is_updated = false;
+        }
+    }
+
+    /// Finish the current frame, removing all animations that were not requested during it.
+    ///
+    /// This is called when the `UiCell` returned by `Ui::set_widgets` is dropped.
+    pub fn end_frame(&mut self) {
+        self.map.retain(|_, animation| animation.is_updated);
+    }
+
+    /// Animate the given property of the given widget towards `target`.
+    ///
+    /// Returns the value for the current frame.
+    ///
+    /// The first time a property is requested for some widget, it starts at rest at the target. If
+    /// the same property is requested more than once during a frame, only the first request moves
+    /// the animation and later requests return the same value.
+    pub fn animate(&mut self,
+                   id: widget::Id,
+                   property: &'static str,
+                   target: Scalar,
+                   transition: Transition) -> Scalar
+    {
+        let now = self.frame_time;
+        let animation = self.map.entry((id, property))
+            .or_insert_with(|| Animation::at_rest(target, transition, now));
+        if !animation.is_updated {
+            animation.transition = transition;
+            animation.set_target(target, now);
+            animation.step(now);
+            animation.is_updated = true;
+        }
+        animation.value
+    }
+
+    /// The animation of the given property of the given widget if there is one.
+    pub fn get(&self, id: widget::Id, property: &'static str) -> Option<&Animation> {
+        self.map.get(&(id, property))
+    }
+
+    /// The current value of the given property of the given widget if it is animated.
+    pub fn value(&self, id: widget::Id, property: &'static str) -> Option<Scalar> {
+        self.get(id, property).map(|animation| animation.value)
+    }
+
+    /// Whether or not any animation has yet to reach its target.
+    pub fn is_active(&self) -> bool {
+        self.map.values().any(|animation| !animation.is_at_rest())
+    }
+
+}
+
+impl Default for Animations {
+    fn default() -> Self {
+        Animations::new()
+    }
+}
+
+
+/// Convert the given `Duration` to seconds.
+pub fn secs(duration: Duration) -> Scalar {
+    duration.as_secs() as Scalar + duration.subsec_nanos() as Scalar * 1e-9
+}
@@ -12,7 +12,7 @@
 //!
 
 use std::f32::consts::PI;
-use utils::{degrees, fmod, turns};
+use utils::{clamp, degrees, fmod, turns};
 
 /// Color supporting RGB and HSL variants.
 #[derive(PartialEq, Copy, Clone, Debug)]
@@ -241,6 +241,19 @@ impl Color {
         rgba((r - 1.0).abs(), (g - 1.0).abs(), (b - 1.0).abs(), a)
     }
 
+    /// Linearly interpolate between this Color and the `other` Color in RGBA space.
+    ///
+    /// `t` is clamped to the range `0.0..=1.0` where `0.0` returns `self` and `1.0` returns `other`.
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = clamp(t, 0.0, 1.0);
+        let Rgba(r1, g1, b1, a1) = self.to_rgb();
+        let Rgba(r2, g2, b2, a2) = other.to_rgb();
+        rgba(r1 + (r2 - r1) * t,
+             g1 + (g2 - g1) * t,
+             b1 + (b2 - b1) * t,
+             a1 + (a2 - a1) * t)
+    }
+
     /// Return the red value.
     pub fn red(&self) -> f32 {
         let Rgba(r, _, _, _) = self.to_rgb();
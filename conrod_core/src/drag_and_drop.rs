@@ -0,0 +1,170 @@
+//! Types and functionality related to dragging typed payloads from one widget onto another.
+//!
+//! A drag begins when some *source* widget calls **UiCell::start_drag** with a payload of any
+//! type, usually upon receiving a mouse `Drag` event. While the left mouse button remains down,
+//! the **Ui** tracks the top-most widget under the cursor that *accepts* the payload. Widgets
+//! accept the payload by calling **UiCell::accept_drop** during each update in which they would
+//! like to receive it, normally after checking its type via **DragAndDrop::payload**.
+//!
+//! The accepting widget under the cursor is known as the *drop target*. It receives `DragEnter`,
+//! `DragOver` and `DragLeave` events via its **input::Widget** as the cursor moves, followed by a
+//! `Drop` event once the mouse button is released over it. The payload remains available for the
+//! duration of the update in which the `Drop` event is delivered.
+//!
+//! ```ignore
+//! // Within the source widget's update.
+//! if ui.drag_and_drop().is_none() && ui.widget_input(id).drags().left().next().is_some() {
+//!     ui.start_drag(id, NodeKind::Oscillator);
+//! }
+//!
+//! // Within the target widget's update.
+//! if ui.drag_and_drop().and_then(|drag| drag.payload::<NodeKind>()).is_some() {
+//!     ui.accept_drop(id);
+//! }
+//! for drop in ui.widget_input(id).drops() {
+//!     if let Some(kind) = ui.take_drag_payload::<NodeKind>() {
+//!         // Add a node of the given kind at `drop.xy`.
+//!     }
+//! }
+//! ```
+//!
+//! A preview of the payload may be drawn by instantiating any widget at the mouse position and
+//! passing its id to **UiCell::set_drag_preview** so that it is never mistaken for a drop target.
+
+use daggy::Walker;
+use fnv;
+use graph::{self, Graph};
+use position::Point;
+use std::any::Any;
+use theme::Theme;
+use widget;
+
+
+/// A payload that is being dragged from one widget onto another.
+///
+/// The drag in progress, if any, may be retrieved via **Ui::drag_and_drop**.
+#[derive(Debug)]
+pub struct DragAndDrop {
+    /// The widget from which the payload is being dragged.
+    source: widget::Id,
+    /// The payload, or `None` if it has already been taken by the drop target.
+    maybe_payload: Option<Box<Any + Send>>,
+    /// The widget used to preview the payload at the cursor, if any.
+    maybe_preview: Option<widget::Id>,
+    /// All widgets that have accepted the payload during the current update.
+    accepted_by: fnv::FnvHashSet<widget::Id>,
+    /// Whether or not the payload has been dropped onto a target.
+    is_dropped: bool,
+}
+
+impl DragAndDrop {
+
+    /// Begin dragging the given `payload` from the `source` widget.
+    pub fn new<T>(source: widget::Id, payload: T) -> Self
+        where T: Any + Send,
+    {
+        DragAndDrop {
+            source: source,
+            maybe_payload: Some(Box::new(payload)),
+            maybe_preview: None,
+            accepted_by: fnv::FnvHashSet::default(),
+            is_dropped: false,
+        }
+    }
+
+    /// The widget from which the payload is being dragged.
+    pub fn source(&self) -> widget::Id {
+        self.source
+    }
+
+    /// A reference to the payload if it is of type `T`.
+    ///
+    /// Returns `None` if the payload is of some other type or has already been taken.
+    pub fn payload<T>(&self) -> Option<&T>
+        where T: Any,
+    {
+        self.maybe_payload.as_ref().and_then(|payload| payload.downcast_ref())
+    }
+
+    /// Take the payload if it is of type `T`.
+    pub fn take_payload<T>(&mut self) -> Option<T>
+        where T: Any,
+    {
+        match self.maybe_payload.take() {
+            Some(payload) => match payload.downcast() {
+                Ok(payload) => Some(*payload),
+                Err(payload) => {
+                    self.maybe_payload = Some(payload);
+                    None
+                },
+            },
+            None => None,
+        }
+    }
+
+    /// The widget used to preview the payload at the cursor, if any.
+    pub fn preview(&self) -> Option<widget::Id> {
+        self.maybe_preview
+    }
+
+    /// Specify the widget used to preview the payload at the cursor.
+    ///
+    /// The preview and its children are ignored when determining the drop target.
+    pub fn set_preview(&mut self, preview: widget::Id) {
+        self.maybe_preview = Some(preview);
+    }
+
+    /// Indicate that the given widget accepts the payload during the current update.
+    pub fn accept(&mut self, target: widget::Id) {
+        self.accepted_by.insert(target);
+    }
+
+    /// Whether or not the given widget accepted the payload during the last update.
+    pub fn is_accepted_by(&self, target: widget::Id) -> bool {
+        self.accepted_by.contains(&target)
+    }
+
+    /// Whether or not the payload has been dropped onto a target.
+    pub fn is_dropped(&self) -> bool {
+        self.is_dropped
+    }
+
+    /// Mark the payload as dropped onto the current drop target.
+    pub fn set_dropped(&mut self) {
+        self.is_dropped = true;
+    }
+
+    /// Begin a new update, clearing the set of widgets that accept the payload.
+    ///
+    /// This is called by `Ui::set_widgets`.
+    pub fn begin_update(&mut self) {
+        self.accepted_by.clear();
+    }
+
+    /// Determine the widget onto which the payload would be dropped at the given position.
+    ///
+    /// This is the top-most widget under `xy` (ignoring the preview) or its nearest ancestor that
+    /// accepts the payload. Returns `None` if none of them accept it.
+    pub fn pick_target(
+        &self,
+        graph: &Graph,
+        depth_order: &[widget::Id],
+        theme: &Theme,
+        xy: Point,
+    ) -> Option<widget::Id> {
+        let mut picked = graph::algo::pick_widgets(depth_order, xy);
+        while let Some(id) = picked.next(graph, depth_order, theme) {
+            if let Some(preview) = self.maybe_preview {
+                if id == preview || graph.does_recursive_depth_edge_exist(preview, id) {
+                    continue;
+                }
+            }
+            let is_accepted_by = |id: &widget::Id| self.is_accepted_by(*id);
+            return Some(id).into_iter()
+                .chain(graph.depth_parent_recursion(id).iter(graph).nodes())
+                .find(is_accepted_by);
+        }
+        None
+    }
+
+}
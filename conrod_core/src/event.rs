@@ -37,6 +37,7 @@
 
 use input;
 use position::{Dimensions, Point};
+use std::path::PathBuf;
 use utils::vec2_sub;
 use widget;
 
@@ -74,6 +75,16 @@ pub enum Input {
     Focus(bool),
     /// The backed requested to redraw.
     Redraw,
+    /// A file is being dragged over the window from some other application.
+    ///
+    /// When multiple files are dragged at once, one event is received for each file.
+    HoveredFile(PathBuf),
+    /// A file was dropped onto the window.
+    ///
+    /// When multiple files are dropped at once, one event is received for each file.
+    DroppedFile(PathBuf),
+    /// The files being dragged over the window left it or the drag was cancelled.
+    HoveredFileCancelled,
 }
 
 
@@ -129,6 +140,23 @@ pub enum Ui {
     WidgetCapturesInputSource(widget::Id, input::Source),
     /// Indicates that the given widget has released the given user input source.
     WidgetUncapturesInputSource(widget::Id, input::Source),
+    /// A drag-and-drop payload was dragged over the given widget, which accepts it.
+    DragEnter(widget::Id),
+    /// A drag-and-drop payload was dragged away from the given widget or the drag was cancelled.
+    DragLeave(widget::Id),
+    /// A drag-and-drop payload was moved over the given widget, which accepts it.
+    DragOver(widget::Id, DragOver),
+    /// A drag-and-drop payload was dropped onto the given widget.
+    Drop(widget::Id, Dropped),
+    /// A file from some other application is being dragged over the window, along with the
+    /// widget that was under the mouse at the time.
+    HoveredFile(Option<widget::Id>, File),
+    /// A file from some other application was dropped onto the window, along with the widget that
+    /// was under the mouse at the time.
+    DroppedFile(Option<widget::Id>, File),
+    /// The files being dragged over the window left it or the drag was cancelled, along with the
+    /// widget that was under the mouse at the time.
+    HoveredFileCancelled(Option<widget::Id>),
 }
 
 
@@ -169,6 +197,20 @@ pub enum Widget {
     CapturesInputSource(input::Source),
     /// The widget has released the input source from capturing.
     UncapturesInputSource(input::Source),
+    /// A drag-and-drop payload accepted by the widget was dragged over it.
+    DragEnter,
+    /// A drag-and-drop payload was dragged away from the widget or the drag was cancelled.
+    DragLeave,
+    /// A drag-and-drop payload accepted by the widget was moved over it.
+    DragOver(DragOver),
+    /// A drag-and-drop payload was dropped onto the widget.
+    Drop(Dropped),
+    /// A file from some other application is being dragged over the widget.
+    HoveredFile(File),
+    /// A file from some other application was dropped onto the widget.
+    DroppedFile(File),
+    /// The files being dragged over the widget left the window or the drag was cancelled.
+    HoveredFileCancelled,
 }
 
 /// Contains all relevant information for a Text event.
@@ -279,6 +321,42 @@ pub struct Drag {
     pub modifiers: input::keyboard::ModifierKey,
 }
 
+/// Contains all the relevant information for the movement of a drag-and-drop payload over the
+/// widget that accepts it.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DragOver {
+    /// The widget from which the payload is being dragged.
+    pub source: widget::Id,
+    /// The position of the mouse.
+    pub xy: Point,
+    /// Which modifier keys are being held during the drag.
+    pub modifiers: input::keyboard::ModifierKey,
+}
+
+/// Contains all the relevant information for a drag-and-drop payload dropped onto a widget.
+///
+/// The payload itself may be retrieved via `UiCell::take_drag_payload` during the update in which
+/// this event is received.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Dropped {
+    /// The widget from which the payload was dragged.
+    pub source: widget::Id,
+    /// The position at which the payload was dropped.
+    pub xy: Point,
+    /// Which modifier keys were being held when the payload was dropped.
+    pub modifiers: input::keyboard::ModifierKey,
+}
+
+/// Contains all the relevant information for a file dragged over or dropped onto the window from
+/// some other application.
+#[derive(Clone, PartialEq, Debug)]
+pub struct File {
+    /// The path to the file.
+    pub path: PathBuf,
+    /// The position of the mouse at the time.
+    pub xy: Point,
+}
+
 /// Contains all the relevant information for a mouse click.
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Click {
@@ -464,6 +542,36 @@ impl Drag {
     }
 }
 
+impl DragOver {
+    /// Returns a copy of the DragOver relative to the given `xy`
+    pub fn relative_to(&self, xy: Point) -> DragOver {
+        DragOver {
+            xy: vec2_sub(self.xy, xy),
+            ..*self
+        }
+    }
+}
+
+impl Dropped {
+    /// Returns a copy of the Dropped relative to the given `xy`
+    pub fn relative_to(&self, xy: Point) -> Dropped {
+        Dropped {
+            xy: vec2_sub(self.xy, xy),
+            ..*self
+        }
+    }
+}
+
+impl File {
+    /// Returns a copy of the File relative to the given `xy`
+    pub fn relative_to(&self, xy: Point) -> File {
+        File {
+            path: self.path.clone(),
+            xy: vec2_sub(self.xy, xy),
+        }
+    }
+}
+
 
 impl From<input::Motion> for Input {
     fn from(motion: input::Motion) -> Self {
@@ -548,3 +656,15 @@ impl From<Drag> for Widget {
         Widget::Drag(drag)
     }
 }
+
+impl From<DragOver> for Widget {
+    fn from(drag_over: DragOver) -> Self {
+        Widget::DragOver(drag_over)
+    }
+}
+
+impl From<Dropped> for Widget {
+    fn from(drop: Dropped) -> Self {
+        Widget::Drop(drop)
+    }
+}
@@ -36,6 +36,8 @@ pub struct State {
     /// If the mouse is currently over multiple widgets, this index will represent the top-most,
     /// non-graphic-child widget.
     pub widget_under_mouse: Option<widget::Id>,
+    /// The widget onto which the current drag-and-drop payload would be dropped, if any.
+    pub drop_target: Option<widget::Id>,
     /// Which modifier keys are being held down.
     pub modifiers: ModifierKey,
 }
@@ -50,6 +52,7 @@ impl State {
             widget_capturing_keyboard: None,
             widget_capturing_mouse: None,
             widget_under_mouse: None,
+            drop_target: None,
             modifiers: ModifierKey::NO_MODIFIER,
         }
     }
@@ -158,6 +158,18 @@ pub struct Scrolls<'a> {
     events: Events<'a>,
 }
 
+/// An iterator that yields all drag-and-drop `Drop` events yielded by the given `Events` iterator.
+#[derive(Clone)]
+pub struct Drops<'a> {
+    events: Events<'a>,
+}
+
+/// An iterator that yields all `DroppedFile` events yielded by the given `Events` iterator.
+#[derive(Clone)]
+pub struct DroppedFiles<'a> {
+    events: Events<'a>,
+}
+
 
 impl<'a> Widget<'a> {
 
@@ -253,6 +265,26 @@ impl<'a> Widget<'a> {
         Scrolls { events: self.events() }
     }
 
+    /// Produce an iterator that yields only the drag-and-drop `Drop` events yielded by the
+    /// `Events` iterator.
+    ///
+    /// Only widgets that accept the payload via `UiCell::accept_drop` receive `Drop` events.
+    pub fn drops(&self) -> Drops<'a> {
+        Drops { events: self.events() }
+    }
+
+    /// Produce an iterator that yields only the files dropped onto the widget from some other
+    /// application.
+    pub fn dropped_files(&self) -> DroppedFiles<'a> {
+        DroppedFiles { events: self.events() }
+    }
+
+    /// Whether or not the widget is the target onto which the current drag-and-drop payload would
+    /// be dropped.
+    pub fn is_drop_target(&self) -> bool {
+        self.global.current.drop_target == Some(self.idx)
+    }
+
 }
 
 impl<'a> Mouse<'a> {
@@ -480,6 +512,27 @@ impl<'a> Iterator for Events<'a> {
                 event::Ui::Scroll(idx, ref scroll) if idx == Some(self.idx) =>
                     return Some(scroll.clone().into()),
 
+                event::Ui::DragEnter(idx) if idx == self.idx =>
+                    return Some(event::Widget::DragEnter),
+
+                event::Ui::DragLeave(idx) if idx == self.idx =>
+                    return Some(event::Widget::DragLeave),
+
+                event::Ui::DragOver(idx, ref drag_over) if idx == self.idx =>
+                    return Some(drag_over.relative_to(self.rect.xy()).into()),
+
+                event::Ui::Drop(idx, ref drop) if idx == self.idx =>
+                    return Some(drop.relative_to(self.rect.xy()).into()),
+
+                event::Ui::HoveredFile(idx, ref file) if idx == Some(self.idx) =>
+                    return Some(event::Widget::HoveredFile(file.relative_to(self.rect.xy()))),
+
+                event::Ui::DroppedFile(idx, ref file) if idx == Some(self.idx) =>
+                    return Some(event::Widget::DroppedFile(file.relative_to(self.rect.xy()))),
+
+                event::Ui::HoveredFileCancelled(idx) if idx == Some(self.idx) =>
+                    return Some(event::Widget::HoveredFileCancelled),
+
                 _ => (),
                 
             }
@@ -669,3 +722,27 @@ impl<'a> Iterator for Scrolls<'a> {
         None
     }
 }
+
+impl<'a> Iterator for Drops<'a> {
+    type Item = event::Dropped;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(event) = self.events.next() {
+            if let event::Widget::Drop(drop) = event {
+                return Some(drop);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for DroppedFiles<'a> {
+    type Item = event::File;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(event) = self.events.next() {
+            if let event::Widget::DroppedFile(file) = event {
+                return Some(file);
+            }
+        }
+        None
+    }
+}
@@ -15,6 +15,7 @@ extern crate num;
 extern crate input as piston_input;
 extern crate rusttype;
 extern crate copypasta;
+extern crate instant;
 
 pub use color::{Color, Colorable};
 pub use conrod_derive::*;
@@ -25,8 +26,10 @@ pub use theme::Theme;
 pub use ui::{Ui, UiCell, UiBuilder};
 pub use widget::{scroll, Widget};
 
+pub mod animation;
 mod border;
 pub mod color;
+pub mod drag_and_drop;
 pub mod event;
 pub mod graph;
 pub mod guide;
@@ -139,6 +139,21 @@ pub enum Dimension {
     KidAreaOf(widget::Id, Option<Scalar>),
 }
 
+/// Optional minimum and maximum lengths for a **Widget** along a single axis.
+///
+/// These limits are applied after the axis' **Dimension** has been resolved to an absolute length,
+/// allowing a widget to follow the size of some other widget while never becoming smaller than
+/// `min` or larger than `max`.
+///
+/// See the **Sizeable** trait's `min_w`, `max_w`, `min_h` and `max_h` methods.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DimensionLimits {
+    /// The minimum length if there is one.
+    pub min: Option<Scalar>,
+    /// The maximum length if there is one.
+    pub max: Option<Scalar>,
+}
+
 /// Widgets that are positionable.
 ///
 /// A **Position** is stored internally within the **widget::CommonBuilder** type, allowing all
@@ -687,6 +702,36 @@ pub trait Sizeable: Sized {
 
     // Provided defaults.
 
+    /// Set the limits for the length along the x axis.
+    ///
+    /// By default the limits are ignored, as there is nowhere to store them. Types that store
+    /// their limits should override this along with `get_x_dimension_limits`.
+    fn x_dimension_limits(self, _limits: DimensionLimits) -> Self {
+        self
+    }
+
+    /// Set the limits for the length along the y axis.
+    ///
+    /// By default the limits are ignored, as there is nowhere to store them. Types that store
+    /// their limits should override this along with `get_y_dimension_limits`.
+    fn y_dimension_limits(self, _limits: DimensionLimits) -> Self {
+        self
+    }
+
+    /// The limits for the widget's length along the x axis.
+    ///
+    /// By default there are no limits, so `get_w` yields the length of the `x_dimension` as is.
+    fn get_x_dimension_limits(&self) -> DimensionLimits {
+        DimensionLimits::default()
+    }
+
+    /// The limits for the widget's length along the y axis.
+    ///
+    /// By default there are no limits, so `get_h` yields the length of the `y_dimension` as is.
+    fn get_y_dimension_limits(&self) -> DimensionLimits {
+        DimensionLimits::default()
+    }
+
     /// Set the absolute width for the widget.
     fn w(self, w: Scalar) -> Self {
         self.x_dimension(Dimension::Absolute(w))
@@ -773,26 +818,66 @@ pub trait Sizeable: Sized {
         self.padded_kid_area_w_of(idx, pad).padded_kid_area_h_of(idx, pad)
     }
 
+    /// Set the minimum width for the widget.
+    fn min_w(self, w: Scalar) -> Self {
+        let limits = DimensionLimits { min: Some(w), ..self.get_x_dimension_limits() };
+        self.x_dimension_limits(limits)
+    }
+
+    /// Set the maximum width for the widget.
+    fn max_w(self, w: Scalar) -> Self {
+        let limits = DimensionLimits { max: Some(w), ..self.get_x_dimension_limits() };
+        self.x_dimension_limits(limits)
+    }
+
+    /// Set the minimum height for the widget.
+    fn min_h(self, h: Scalar) -> Self {
+        let limits = DimensionLimits { min: Some(h), ..self.get_y_dimension_limits() };
+        self.y_dimension_limits(limits)
+    }
+
+    /// Set the maximum height for the widget.
+    fn max_h(self, h: Scalar) -> Self {
+        let limits = DimensionLimits { max: Some(h), ..self.get_y_dimension_limits() };
+        self.y_dimension_limits(limits)
+    }
+
+    /// Set the minimum dimensions for the widget.
+    fn min_wh(self, wh: Dimensions) -> Self {
+        self.min_w(wh[0]).min_h(wh[1])
+    }
+
+    /// Set the maximum dimensions for the widget.
+    fn max_wh(self, wh: Dimensions) -> Self {
+        self.max_w(wh[0]).max_h(wh[1])
+    }
+
     /// Get the absolute width of the widget as a Scalar value.
+    ///
+    /// The width is clamped to the widget's x `DimensionLimits`.
     fn get_w(&self, ui: &Ui) -> Option<Scalar> {
-        match self.get_x_dimension(ui) {
+        let w = match self.get_x_dimension(ui) {
             Dimension::Absolute(width) => Some(width),
             Dimension::Of(idx, None) => ui.w_of(idx),
             Dimension::Of(idx, Some(pad)) => ui.w_of(idx).map(|w| w - pad * 2.0),
             Dimension::KidAreaOf(idx, None) => ui.kid_area_of(idx).map(|r| r.w()),
             Dimension::KidAreaOf(idx, Some(pad)) => ui.kid_area_of(idx).map(|r| r.w() - pad * 2.0),
-        }
+        };
+        w.map(|w| self.get_x_dimension_limits().clamp(w))
     }
 
     /// Get the height of the widget.
+    ///
+    /// The height is clamped to the widget's y `DimensionLimits`.
     fn get_h(&self, ui: &Ui) -> Option<Scalar> {
-        match self.get_y_dimension(ui) {
+        let h = match self.get_y_dimension(ui) {
             Dimension::Absolute(height) => Some(height),
             Dimension::Of(idx, None) => ui.h_of(idx),
             Dimension::Of(idx, Some(pad)) => ui.h_of(idx).map(|w| w - pad * 2.0),
             Dimension::KidAreaOf(idx, None) => ui.kid_area_of(idx).map(|r| r.h()),
             Dimension::KidAreaOf(idx, Some(pad)) => ui.kid_area_of(idx).map(|r| r.h() - pad * 2.0),
-        }
+        };
+        h.map(|h| self.get_y_dimension_limits().clamp(h))
     }
 
     /// The dimensions for the widget.
@@ -802,6 +887,27 @@ pub trait Sizeable: Sized {
 
 }
 
+impl DimensionLimits {
+    /// Clamp the given length to the limits.
+    ///
+    /// If `min` is greater than `max`, `min` takes precedence.
+    pub fn clamp(&self, length: Scalar) -> Scalar {
+        let length = match self.max {
+            Some(max) if length > max => max,
+            _ => length,
+        };
+        match self.min {
+            Some(min) if length < min => min,
+            _ => length,
+        }
+    }
+
+    /// Whether or not the given length lies within the limits.
+    pub fn contains(&self, length: Scalar) -> bool {
+        self.clamp(length) == length
+    }
+}
+
 /// The distance between the inner edge of a border and the outer edge of the inner content.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Padding {
@@ -36,6 +36,8 @@ pub struct Primitives<'a> {
     theme: &'a Theme,
     fonts: &'a text::font::Map,
     window_rect: Rect,
+    /// Whether or not primitives are cropped by their ancestors and culled outside the window.
+    is_cropped: bool,
     /// A buffer to use for triangulating polygons and lines for the `Triangles`.
     triangles: Vec<Triangle<Point>>,
     /// The slice of rusttype `PositionedGlyph`s to re-use for the `Text` primitive.
@@ -300,11 +302,22 @@ impl<'a> Primitives<'a> {
             theme: theme,
             fonts: fonts,
             window_rect: Rect::from_xy_dim([0.0, 0.0], window_dim),
+            is_cropped: true,
             triangles: Vec::new(),
             positioned_glyphs: Vec::new(),
         }
     }
 
+    /// Yield every primitive, regardless of whether it is cropped by an ancestor or lies outside
+    /// of the window.
+    ///
+    /// The `scizzor` of each primitive becomes its own `rect`. This is useful for producing an
+    /// overview of scrolled content, e.g. the `Minimap` widget.
+    pub fn uncropped(mut self) -> Self {
+        self.is_cropped = false;
+        self
+    }
+
     /// Yield the next `Primitive` for rendering.
     pub fn next(&mut self) -> Option<Primitive> {
         let Primitives {
@@ -316,9 +329,11 @@ impl<'a> Primitives<'a> {
             theme,
             fonts,
             window_rect,
+            is_cropped,
         } = *self;
 
-        while let Some(widget) = next_widget(depth_order, graph, crop_stack, window_rect) {
+        while let Some(widget) = next_widget(depth_order, graph, crop_stack, window_rect,
+                                             is_cropped) {
             use widget::primitive::point_path::{State as PointPathState, Style as PointPathStyle};
             use widget::primitive::shape::polygon::{State as PolygonState};
             use widget::primitive::shape::Style as ShapeStyle;
@@ -888,7 +903,8 @@ fn new_primitive(id: widget::Id, kind: PrimitiveKind, scizzor: Rect, rect: Rect)
 fn next_widget<'a>(depth_order: &mut std::slice::Iter<widget::Id>,
                    graph: &'a Graph,
                    crop_stack: &mut Vec<(widget::Id, Rect)>,
-                   window_rect: Rect) -> Option<(widget::Id, Rect, &'a graph::Container)>
+                   window_rect: Rect,
+                   is_cropped: bool) -> Option<(widget::Id, Rect, &'a graph::Container)>
 {
     while let Some(&id) = depth_order.next() {
         let container = match graph.widget(id) {
@@ -896,6 +912,11 @@ fn next_widget<'a>(depth_order: &mut std::slice::Iter<widget::Id>,
             None => continue,
         };
 
+        // When uncropped, every widget is visible within its own bounds.
+        if !is_cropped {
+            return Some((id, container.rect, container));
+        }
+
         // If we're currently using a cropped context and the current `crop_parent_idx` is
         // *not* a depth-wise parent of the widget at the current `idx`, we should pop that
         // cropped context from the stack as we are done with it.
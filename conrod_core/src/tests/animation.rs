@@ -0,0 +1,103 @@
+use animation::{Animations, Easing, Transition};
+use instant::Instant;
+use std::time::Duration;
+use widget;
+
+#[test]
+fn tween_should_reach_target_after_duration_and_then_rest() {
+    let id = widget::Id::new(0);
+    let start = Instant::now();
+    let transition = Transition::tween(Duration::from_millis(100), Easing::Linear);
+    let mut animations = Animations::new();
+
+    // The first request starts at rest at the target.
+    animations.begin_frame(start);
+    assert_eq!(animations.animate(id, "value", 0.0, transition), 0.0);
+    animations.end_frame();
+    assert!(!animations.is_active());
+
+    // Changing the target begins the tween from the current value.
+    animations.begin_frame(start);
+    assert_eq!(animations.animate(id, "value", 1.0, transition), 0.0);
+    animations.end_frame();
+    assert!(animations.is_active());
+
+    animations.begin_frame(start + Duration::from_millis(50));
+    let value = animations.animate(id, "value", 1.0, transition);
+    assert!((value - 0.5).abs() < 1e-6);
+
+    animations.begin_frame(start + Duration::from_millis(150));
+    assert_eq!(animations.animate(id, "value", 1.0, transition), 1.0);
+    animations.end_frame();
+    assert!(!animations.is_active());
+
+    // Animations that are not requested during a frame are removed.
+    animations.begin_frame(start + Duration::from_millis(200));
+    animations.end_frame();
+    assert_eq!(animations.value(id, "value"), None);
+}
+
+#[test]
+fn spring_should_settle_at_target() {
+    let id = widget::Id::new(0);
+    let start = Instant::now();
+    let transition = Transition::spring();
+    let mut animations = Animations::new();
+
+    animations.begin_frame(start);
+    animations.animate(id, "value", 0.0, transition);
+    let mut settled = false;
+    for i in 1..300 {
+        animations.begin_frame(start + Duration::from_millis(i * 16));
+        animations.animate(id, "value", 10.0, transition);
+        animations.end_frame();
+        if !animations.is_active() {
+            settled = true;
+            break;
+        }
+    }
+    assert!(settled);
+    assert_eq!(animations.value(id, "value"), Some(10.0));
+}
+
+#[test]
+fn properties_of_the_same_widget_should_animate_independently() {
+    let id = widget::Id::new(0);
+    let start = Instant::now();
+    let transition = Transition::tween(Duration::from_millis(100), Easing::Linear);
+    let mut animations = Animations::new();
+
+    animations.begin_frame(start);
+    animations.animate(id, "x", 0.0, transition);
+    animations.animate(id, "y", 0.0, transition);
+    animations.end_frame();
+
+    animations.begin_frame(start);
+    animations.animate(id, "x", 1.0, transition);
+    animations.animate(id, "y", -1.0, transition);
+    animations.end_frame();
+
+    animations.begin_frame(start + Duration::from_millis(150));
+    assert_eq!(animations.animate(id, "x", 1.0, transition), 1.0);
+    assert_eq!(animations.animate(id, "y", -1.0, transition), -1.0);
+    animations.end_frame();
+    assert!(!animations.is_active());
+}
+
+#[test]
+fn default_spring_should_not_overshoot_its_target() {
+    let id = widget::Id::new(0);
+    let start = Instant::now();
+    let transition = Transition::spring();
+    let mut animations = Animations::new();
+
+    animations.begin_frame(start);
+    animations.animate(id, "value", 0.0, transition);
+    for i in 1..300 {
+        animations.begin_frame(start + Duration::from_millis(i * 16));
+        let value = animations.animate(id, "value", 1.0, transition);
+        assert!(value <= 1.0);
+        animations.end_frame();
+    }
+    assert_eq!(animations.value(id, "value"), Some(1.0));
+}
@@ -0,0 +1,22 @@
+use widget::chart::axis::{self, Axis};
+
+#[test]
+fn nice_ticks_should_fall_on_round_values_within_the_range() {
+    assert_eq!(axis::nice_ticks(0.0, 10.0, 6), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    assert_eq!(axis::nice_ticks(-0.3, 0.3, 4), vec![-0.2, 0.0, 0.2]);
+    assert_eq!(axis::nice_ticks(3.0, 97.0, 5), vec![20.0, 40.0, 60.0, 80.0]);
+}
+
+#[test]
+fn log_ticks_should_fall_on_powers_of_ten() {
+    assert_eq!(axis::log_ticks(1.0, 1000.0, 6), vec![1.0, 10.0, 100.0, 1000.0]);
+    assert_eq!(axis::log_ticks(0.5, 1e6, 3), vec![1.0, 1e3, 1e6]);
+}
+
+#[test]
+fn tick_labels_should_show_as_many_decimals_as_the_step_requires() {
+    let labels: Vec<String> = Axis::new().ticks(0.0, 1.0).into_iter().map(|(_, s)| s).collect();
+    assert_eq!(labels, vec!["0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+    assert_eq!(axis::format_tick(2500.0, 500.0), "2500");
+    assert_eq!(axis::format_tick(2e7, 1e7), "2e7");
+}
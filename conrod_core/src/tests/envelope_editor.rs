@@ -0,0 +1,29 @@
+use widget::envelope_editor::{can_store_curve, curve_from_midpoint_y, curve_point};
+
+#[test]
+fn curve_point_should_bend_the_midpoint_towards_the_corner_of_the_curve_depth() {
+    let (a, b) = ([0.0, 0.0], [4.0, 4.0]);
+    assert_eq!(curve_point(a, b, 0.5, 0.0), a);
+    assert_eq!(curve_point(a, b, 0.5, 1.0), b);
+    assert_eq!(curve_point(a, b, 0.0, 0.5), [2.0, 2.0]);
+    assert_eq!(curve_point(a, b, 1.0, 0.5), [3.0, 1.0]);
+    assert_eq!(curve_point(a, b, -1.0, 0.5), [1.0, 3.0]);
+}
+
+#[test]
+fn curve_from_midpoint_y_should_invert_curve_point_and_clamp_the_depth() {
+    let (a, b) = ([0.0, 0.0], [4.0, 4.0]);
+    assert_eq!(curve_from_midpoint_y(a, b, 2.0), Some(0.0));
+    assert_eq!(curve_from_midpoint_y(a, b, 1.0), Some(1.0));
+    assert_eq!(curve_from_midpoint_y(a, b, 3.0), Some(-1.0));
+    assert_eq!(curve_from_midpoint_y(a, b, -10.0), Some(1.0));
+    assert_eq!(curve_from_midpoint_y(a, b, 10.0), Some(-1.0));
+    let curve = curve_from_midpoint_y(a, b, 1.5).unwrap();
+    assert_eq!(curve_point(a, b, curve, 0.5)[1], 1.5);
+    assert_eq!(curve_from_midpoint_y([0.0, 1.0], [4.0, 1.0], 3.0), None);
+}
+
+#[test]
+fn points_should_only_store_a_curve_if_they_override_set_curve() {
+    assert!(!can_store_curve(&[0.0, 0.0]));
+}
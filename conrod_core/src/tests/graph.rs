@@ -0,0 +1,42 @@
+use widget::graph::layout::{self, ForceDirected, Layered};
+use widget::graph::{Layout, NodeSocket};
+
+fn edge(a: u32, b: u32) -> (NodeSocket<u32>, NodeSocket<u32>) {
+    (NodeSocket { id: a, socket_index: 0 }, NodeSocket { id: b, socket_index: 0 })
+}
+
+#[test]
+fn layered_layout_should_place_nodes_after_their_longest_path() {
+    // 0 -> 1 -> 2, 0 -> 2 and the cycle 2 -> 3 -> 2.
+    let edges = vec![edge(0, 1), edge(1, 2), edge(0, 2), edge(2, 3), edge(3, 2)];
+    let layered = Layered { layer_spacing: 100.0, node_spacing: 50.0, sweeps: 4 };
+    let layout = layered.layout(0..4, edges);
+    let xs: Vec<f64> = (0..4).map(|n| layout[&n][0]).collect();
+    assert_eq!(xs, vec![-150.0, -50.0, 50.0, 150.0]);
+    // Node 1 shares its layer with the virtual node routing the edge 0 -> 2.
+    assert_eq!(layout[&1][1].abs(), 25.0);
+    assert!([0, 2, 3].iter().all(|n| layout[n][1] == 0.0));
+}
+
+#[test]
+fn force_directed_layout_should_separate_nodes_and_settle() {
+    let edges = || vec![edge(0, 1), edge(1, 2), edge(2, 0), edge(3, 0)];
+    let mut force = ForceDirected::default();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    let mut steps = 0;
+    while force.step(&mut layout, 0..4, edges()) {
+        steps += 1;
+        assert!(steps < 1_000, "the layout failed to settle");
+    }
+    for a in 0..4 {
+        for b in a + 1..4 {
+            let (pa, pb) = (layout[&a], layout[&b]);
+            assert!((pa[0] - pb[0]).hypot(pa[1] - pb[1]) > force.ideal_length * 0.25);
+        }
+    }
+
+    // Animating towards the settled layout from the origin eventually reaches it.
+    let mut animated = Layout::from(::std::collections::HashMap::new());
+    while layout::animate_towards(&mut animated, &layout, 0.5) {}
+    assert_eq!(animated, layout);
+}
@@ -1,3 +1,7 @@
+mod animation;
+mod chart;
+mod envelope_editor;
 mod global_input;
+mod graph;
 mod widget_input;
 mod ui;
@@ -131,3 +131,1713 @@ fn high_level_scroll_event_should_be_created_from_a_raw_mouse_scroll() {
         assert_eq!(expected_scroll, scroll);
     }
 }
+
+#[test]
+fn touch_movement_should_update_the_position_of_the_touch() {
+    let ui = &mut windowless_ui();
+    let id = input::touch::Id::new(0);
+    let touch = |phase, xy| Input::Touch(input::Touch { phase: phase, id: id, xy: xy });
+    ui.handle_event(touch(input::touch::Phase::Start, [10.0, 20.0]));
+    ui.handle_event(touch(input::touch::Phase::Move, [30.0, -40.0]));
+    assert_eq!(ui.global_input().current.touch[&id].xy, [30.0, -40.0]);
+}
+
+#[test]
+fn dimension_limits_should_clamp_widgets_and_canvas_splits() {
+    let ui = &mut windowless_ui();
+
+    let (canvas, left, right, rect) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next(), id_generator.next(), id_generator.next())
+    };
+
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .pad(0.0)
+            .flow_right(&[
+                (left, widget::Canvas::new().max_w(200.0)),
+                (right, widget::Canvas::new()),
+            ])
+            .set(canvas, ui);
+        widget::Rectangle::fill([0.0, 0.0])
+            .w_of(canvas)
+            .min_h(50.0)
+            .max_w(300.0)
+            .set(rect, ui);
+    }
+
+    assert_eq!(ui.w_of(left), Some(200.0));
+    assert_eq!(ui.w_of(right), Some(600.0));
+    assert_eq!(ui.wh_of(rect), Some([300.0, 50.0]));
+}
+
+#[test]
+fn redraw_deadlines_should_be_exposed_until_the_next_update() {
+    use instant::Instant;
+    use std::time::Duration;
+
+    let ui = &mut windowless_ui();
+    assert_eq!(ui.next_redraw_deadline(), None);
+
+    let soon = Instant::now() + Duration::from_secs(60);
+    let later = soon + Duration::from_secs(60);
+    {
+        let ui = &mut ui.set_widgets();
+        ui.redraw_at(later);
+        ui.redraw_at(soon);
+    }
+    assert_eq!(ui.next_redraw_deadline(), Some(soon));
+
+    // Deadlines must be requested again each time the widgets are set.
+    ui.set_widgets();
+    assert_eq!(ui.next_redraw_deadline(), None);
+}
+
+#[test]
+fn smooth_scrolling_should_animate_the_offset_towards_its_target() {
+    use instant::Instant;
+    use std::time::Duration;
+    use widget::scroll;
+
+    let ui = &mut windowless_ui();
+    let (canvas, rect) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    fn set_widgets(ui: &mut Ui, canvas: widget::Id, rect: widget::Id) {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .middle()
+            .scroll_kids_vertically_with(scroll::Scroll::new().smooth())
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+    }
+
+    let y_offset = |ui: &Ui| {
+        ui.widget_graph().widget(canvas).unwrap().maybe_y_scroll_state.unwrap().offset
+    };
+
+    set_widgets(ui, canvas, rect);
+    set_widgets(ui, canvas, rect);
+    let start_offset = y_offset(ui);
+    let (kid_area, at_rest) = {
+        let container = ui.widget_graph().widget(canvas).unwrap();
+        (container.kid_area, container.maybe_y_scroll_state)
+    };
+
+    // The first update after a scroll only sets the target.
+    ui.handle_event(Input::Motion(Motion::MouseCursor { x: 0.0, y: 0.0 }));
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 100.0 }));
+    set_widgets(ui, canvas, rect);
+    assert_eq!(y_offset(ui), start_offset);
+    assert!(ui.next_redraw_deadline().is_some());
+
+    // Step the same scroll over explicit moments in time.
+    let args = scroll::Scroll::new().smooth();
+    let start = Instant::now();
+    let step = |prev: Option<scroll::StateY>, scroll: f64, millis: u64| {
+        let motion = scroll::Motion {
+            scroll: scroll,
+            maybe_drag: None,
+            time: start + Duration::from_millis(millis),
+        };
+        scroll::State::update_animated(ui, canvas, &kid_area, &args, prev, motion)
+    };
+    let state = step(at_rest, 100.0, 0);
+    assert_eq!(state.offset, start_offset);
+    assert_eq!(state.target_offset(), start_offset + 100.0);
+
+    // Following updates move the offset towards the target.
+    let mut state = step(Some(state), 0.0, 20);
+    assert!(state.offset != start_offset);
+    assert!((state.offset - start_offset).abs() < 100.0);
+
+    // Until it comes to rest upon the target.
+    let mut millis = 20;
+    while state.is_animating() {
+        assert!(millis < 2_000, "the offset did not come to rest");
+        millis += 16;
+        state = step(Some(state), 0.0, millis);
+    }
+    assert_eq!(state.offset, start_offset + 100.0);
+}
+
+#[test]
+fn kinetic_scrolling_should_follow_drags_that_begin_over_a_descendant() {
+    use widget::scroll;
+
+    let ui = &mut windowless_ui();
+    let (canvas, rect) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    fn set_widgets(ui: &mut Ui, canvas: widget::Id, rect: widget::Id) {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .middle()
+            .scroll_kids_vertically_with(scroll::Scroll::new().kinetic())
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+    }
+
+    let y_offset = |ui: &Ui| {
+        ui.widget_graph().widget(canvas).unwrap().maybe_y_scroll_state.unwrap().offset
+    };
+
+    set_widgets(ui, canvas, rect);
+    set_widgets(ui, canvas, rect);
+    let start_offset = y_offset(ui);
+
+    // Press the rectangle within the canvas and drag it upwards.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, canvas, rect);
+    assert_eq!(y_offset(ui), start_offset);
+    move_mouse_to_abs_coordinates(0.0, 50.0, ui);
+    set_widgets(ui, canvas, rect);
+    assert_eq!((y_offset(ui) - start_offset).abs(), 50.0);
+    release_mouse_button(MouseButton::Left, ui);
+}
+
+#[test]
+fn scroll_to_widget_should_bring_the_widget_into_view() {
+    use widget::scroll;
+
+    let ui = &mut windowless_ui();
+    let (canvas, rect, target) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next(), id_generator.next())
+    };
+
+    fn set_widgets(ui: &mut Ui, canvas: widget::Id, rect: widget::Id, target: widget::Id) {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .middle()
+            .scroll_kids_vertically()
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+        widget::Rectangle::fill([50.0, 50.0])
+            .mid_bottom_of(rect)
+            .parent(canvas)
+            .set(target, ui);
+    }
+
+    let is_visible = |ui: &Ui| {
+        let kid_area = ui.kid_area_of(canvas).unwrap();
+        let target = ui.rect_of(target).unwrap();
+        kid_area.bottom() <= target.bottom() && target.top() <= kid_area.top()
+    };
+
+    set_widgets(ui, canvas, rect, target);
+    set_widgets(ui, canvas, rect, target);
+    assert!(!is_visible(ui));
+
+    ui.scroll_to_widget(target, scroll::Alignment::Nearest);
+    set_widgets(ui, canvas, rect, target);
+    assert!(is_visible(ui));
+    assert_eq!(ui.kid_area_of(canvas).unwrap().bottom(), ui.rect_of(target).unwrap().bottom());
+}
+
+#[test]
+fn arrow_keys_and_delete_should_nudge_and_remove_the_selected_envelope_points() {
+    let ui = &mut windowless_ui();
+    let editor = ui.widget_id_generator().next();
+    let mut env: Vec<Point> = vec![[0.25, 0.25], [0.5, 0.5], [0.75, 0.75]];
+
+    fn set_widgets(ui: &mut Ui, editor: widget::Id, env: &mut Vec<Point>) {
+        let ui = &mut ui.set_widgets();
+        let events = widget::EnvelopeEditor::new(env, 0.0, 1.0, 0.0, 1.0)
+            .w_h(200.0, 200.0)
+            .middle()
+            .set(editor, ui);
+        for event in events {
+            event.update(env);
+        }
+    }
+
+    set_widgets(ui, editor, &mut env);
+
+    // Select the middle point.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    set_widgets(ui, editor, &mut env);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, editor, &mut env);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, editor, &mut env);
+    assert_eq!(env.len(), 3);
+
+    // Nudge it to the right by a coarse step.
+    ui.handle_event(Input::Press(Button::Keyboard(Key::LShift)));
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Right)));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::Right)));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::LShift)));
+    set_widgets(ui, editor, &mut env);
+    assert!((env[1][0] - 0.6).abs() < 1e-9);
+    assert_eq!(env[1][1], 0.5);
+
+    // Delete it.
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Delete)));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::Delete)));
+    set_widgets(ui, editor, &mut env);
+    assert_eq!(env, vec![[0.25, 0.25], [0.75, 0.75]]);
+}
+
+#[test]
+fn dragging_a_curve_handle_should_set_the_curve_of_its_segment() {
+    use widget::envelope_editor::{EnvelopePoint, Event};
+
+    // A point that stores the curve of the segment that follows it.
+    #[derive(Clone, Debug, PartialEq)]
+    struct CurvePoint {
+        xy: Point,
+        curve: f32,
+    }
+
+    impl EnvelopePoint for CurvePoint {
+        type X = f64;
+        type Y = f64;
+        fn get_x(&self) -> f64 { self.xy[0] }
+        fn get_y(&self) -> f64 { self.xy[1] }
+        fn set_x(&mut self, x: f64) { self.xy[0] = x }
+        fn set_y(&mut self, y: f64) { self.xy[1] = y }
+        fn get_curve(&self) -> f32 { self.curve }
+        fn set_curve(&mut self, curve: f32) { self.curve = curve }
+        fn new(x: f64, y: f64) -> Self { CurvePoint { xy: [x, y], curve: 0.0 } }
+    }
+
+    // Press the midpoint of the segment, drag it upwards and return the resulting events.
+    fn drag_midpoint<E>(env: &[E]) -> Vec<Event<E>>
+        where E: EnvelopePoint<X=f64, Y=f64>,
+    {
+        fn set_widgets<E>(ui: &mut Ui, editor: widget::Id, env: &[E]) -> Vec<Event<E>>
+            where E: EnvelopePoint<X=f64, Y=f64>,
+        {
+            let ui = &mut ui.set_widgets();
+            widget::EnvelopeEditor::new(env, 0.0, 1.0, 0.0, 1.0)
+                .w_h(200.0, 200.0)
+                .middle()
+                .set(editor, ui)
+        }
+
+        let ui = &mut windowless_ui();
+        let editor = ui.widget_id_generator().next();
+        set_widgets(ui, editor, env);
+        move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+        set_widgets(ui, editor, env);
+        press_mouse_button(MouseButton::Left, ui);
+        set_widgets(ui, editor, env);
+        move_mouse_to_abs_coordinates(0.0, 20.0, ui);
+        set_widgets(ui, editor, env)
+    }
+
+    fn set_curves<E>(events: &[Event<E>]) -> Vec<(usize, f32)>
+        where E: EnvelopePoint,
+    {
+        events.iter().filter_map(|event| match *event {
+            Event::SetCurve { i, curve } => Some((i, curve)),
+            _ => None,
+        }).collect()
+    }
+
+    // Raising the midpoint bends the segment towards the corner at the first point's x.
+    let env = vec![CurvePoint::new(0.25, 0.25), CurvePoint::new(0.75, 0.75)];
+    let events = drag_midpoint(&env);
+    let curves = set_curves(&events);
+    assert_eq!(curves.len(), 1);
+    assert_eq!(curves[0].0, 0);
+    assert!(curves[0].1 < 0.0 && curves[0].1 > -1.0);
+    assert_eq!(curves.len(), events.len());
+
+    // Points that cannot store a curve have no curve handles to drag.
+    let env: Vec<Point> = vec![[0.25, 0.25], [0.75, 0.75]];
+    assert_eq!(set_curves(&drag_midpoint(&env)), vec![]);
+}
+
+#[test]
+fn scrolling_over_a_chart_should_zoom_its_view_until_it_is_reset() {
+    use widget::chart::{Series, View};
+
+    let ui = &mut windowless_ui();
+    let chart = ui.widget_id_generator().next();
+    let points = [[0.0, 0.0], [10.0, 10.0]];
+
+    fn set_widgets(ui: &mut Ui, chart: widget::Id, points: &[Point], reset: bool) -> Option<View> {
+        let ui = &mut ui.set_widgets();
+        let series = [Series::line("line", points)];
+        let mut builder = widget::Chart::new(&series).w_h(400.0, 300.0).middle();
+        if reset {
+            builder = builder.reset_view();
+        }
+        builder.set(chart, ui)
+    }
+
+    assert_eq!(set_widgets(ui, chart, &points, false), None);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    assert_eq!(set_widgets(ui, chart, &points, false), None);
+
+    // Scrolling up zooms out around the mouse.
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 100.0 }));
+    let view = set_widgets(ui, chart, &points, false).expect("expected the view to change");
+    assert!(view.x.0 < 0.0 && view.x.1 > 10.0);
+    assert!(view.y.0 < 0.0 && view.y.1 > 10.0);
+
+    // The view persists until it is reset to the fitted range of each axis.
+    assert_eq!(set_widgets(ui, chart, &points, false), None);
+    let fitted = View { x: (0.0, 10.0), y: (0.0, 10.0) };
+    assert_eq!(set_widgets(ui, chart, &points, true), Some(fitted));
+    assert_eq!(set_widgets(ui, chart, &points, false), None);
+}
+
+// The range of each axis fitted to the given series, retrieved by double-clicking the chart.
+fn fitted_chart_view(series: &[widget::chart::Series], y_axis: widget::chart::Axis)
+    -> widget::chart::View
+{
+    let ui = &mut windowless_ui();
+    let chart = ui.widget_id_generator().next();
+    let set = |ui: &mut Ui| {
+        widget::Chart::new(series)
+            .y_axis(y_axis)
+            .w_h(400.0, 300.0)
+            .middle()
+            .set(chart, &mut ui.set_widgets())
+    };
+    set(ui);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    left_click_mouse(ui);
+    set(ui).expect("expected double-clicking to reset the view")
+}
+
+#[test]
+fn fitted_chart_ranges_should_include_zero_for_bars_and_areas() {
+    use widget::chart::{Axis, Series};
+    let points = [[1.0, 5.0], [2.0, 8.0]];
+
+    let view = fitted_chart_view(&[Series::line("line", &points)], Axis::new());
+    assert_eq!(view.y, (5.0, 8.0));
+
+    let view = fitted_chart_view(&[Series::area("area", &points)], Axis::new());
+    assert_eq!(view.y.0, 0.0);
+    assert!(view.y.1 >= 8.0);
+
+    // Bars also leave room either side for half of each group of bars.
+    let view = fitted_chart_view(&[Series::bar("bar", &points)], Axis::new());
+    assert_eq!(view.y.0, 0.0);
+    assert!(view.y.1 >= 8.0);
+    assert!(view.x.0 <= 0.6 && view.x.1 >= 2.4);
+}
+
+#[test]
+fn fitted_log_chart_ranges_should_ignore_values_that_are_not_positive() {
+    use widget::chart::{Axis, Series};
+    let points = [[1.0, -5.0], [2.0, 0.0], [3.0, 20.0], [4.0, 300.0]];
+    let series = [Series::line("line", &points), Series::area("area", &points)];
+    let view = fitted_chart_view(&series, Axis::new().log());
+    assert!((view.y.0 - 10.0).abs() < 1e-9);
+    assert!((view.y.1 - 1000.0).abs() < 1e-9);
+}
+
+#[test]
+fn bars_of_multiple_chart_series_should_be_placed_side_by_side() {
+    use color;
+    use render::PrimitiveKind;
+    use widget::chart::{Series, BAR_FILL};
+
+    let ui = &mut windowless_ui();
+    let chart = ui.widget_id_generator().next();
+    let (a, b) = ([[1.0, 5.0], [2.0, 8.0]], [[1.0, 4.0], [2.0, 6.0]]);
+    {
+        let series = [
+            Series::bar("a", &a).color(color::RED),
+            Series::bar("b", &b).color(color::BLUE),
+        ];
+        let ui = &mut ui.set_widgets();
+        widget::Chart::new(&series).w_h(400.0, 300.0).middle().set(chart, ui);
+    }
+
+    // The left and right edges of each bar of the given color, in order.
+    fn bar_edges(ui: &Ui, color: Color) -> Vec<(f64, f64)> {
+        let color: color::Rgba = color.into();
+        let mut primitives = ui.draw();
+        let mut edges = vec![];
+        while let Some(primitive) = primitives.next() {
+            match primitive.kind {
+                PrimitiveKind::TrianglesSingleColor { color: c, triangles } if c == color => {
+                    for bar in triangles.chunks(2) {
+                        let xs = || bar.iter().flat_map(|tri| tri.0.iter().map(|p| p[0]));
+                        edges.push((xs().fold(::std::f64::MAX, f64::min),
+                                    xs().fold(::std::f64::MIN, f64::max)));
+                    }
+                },
+                _ => (),
+            }
+        }
+        edges
+    }
+
+    let (a, b) = (bar_edges(ui, color::RED), bar_edges(ui, color::BLUE));
+    assert_eq!((a.len(), b.len()), (2, 2));
+    let approx_eq = |x: f64, y: f64| (x - y).abs() < 1e-6;
+    for i in 0..2 {
+        // Each bar of the second series sits directly to the right of that of the first.
+        assert!(approx_eq(a[i].1, b[i].0));
+        assert!(approx_eq(a[i].1 - a[i].0, b[i].1 - b[i].0));
+    }
+    // Each group of bars fills its share of the distance between neighbouring groups.
+    let group_w = b[0].1 - a[0].0;
+    let group_distance = a[1].0 - a[0].0;
+    assert!(approx_eq(group_w, group_distance * BAR_FILL));
+}
+
+#[test]
+fn charts_too_small_for_their_plot_or_legend_should_omit_them() {
+    use color;
+    use render::PrimitiveKind;
+    use widget::chart::Series;
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(env!("CARGO_MANIFEST_DIR"),
+                            "/../assets/fonts/NotoSans/NotoSans-Regular.ttf");
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let chart = ui.widget_id_generator().next();
+    let points = [[0.0, 0.0], [10.0, 10.0]];
+
+    // Returns the number of primitives drawn for the chart's kids and whether or not the legend's
+    // swatch was drawn.
+    fn set_widgets(ui: &mut Ui, chart: widget::Id, points: &[Point], w: f64, h: f64)
+        -> (usize, bool)
+    {
+        {
+            let series = [Series::line("a rather long series label", points).color(color::RED)];
+            let ui = &mut ui.set_widgets();
+            assert_eq!(widget::Chart::new(&series).w_h(w, h).middle().set(chart, ui), None);
+        }
+        let mut primitives = ui.draw();
+        let (mut n, mut has_swatch) = (0, false);
+        while let Some(primitive) = primitives.next() {
+            if !ui.widget_graph().does_recursive_depth_edge_exist(chart, primitive.id) {
+                continue;
+            }
+            n += 1;
+            if let PrimitiveKind::Rectangle { color } = primitive.kind {
+                has_swatch |= color == color::RED;
+            }
+        }
+        (n, has_swatch)
+    }
+
+    let (n, has_swatch) = set_widgets(ui, chart, &points, 400.0, 300.0);
+    assert!(n > 0 && has_swatch);
+
+    // The plot fits but the legend does not.
+    let (n, has_swatch) = set_widgets(ui, chart, &points, 150.0, 100.0);
+    assert!(n > 0 && !has_swatch);
+
+    // There is no room for the plot after the tick labels.
+    assert_eq!(set_widgets(ui, chart, &points, 30.0, 30.0), (0, false));
+}
+
+#[test]
+fn pressing_a_minimap_should_scroll_its_target_to_the_point_under_the_mouse() {
+    let ui = &mut windowless_ui();
+    let (canvas, rect, minimap) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next(), id_generator.next())
+    };
+
+    fn set_widgets(ui: &mut Ui, canvas: widget::Id, rect: widget::Id, minimap: widget::Id) {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .middle()
+            .scroll_kids_vertically()
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+        widget::Minimap::new(canvas)
+            .w_h(100.0, 500.0)
+            .x_y(300.0, 0.0)
+            .parent(ui.window)
+            .set(minimap, ui);
+    }
+
+    set_widgets(ui, canvas, rect, minimap);
+    set_widgets(ui, canvas, rect, minimap);
+    let kid_area = ui.kid_area_of(canvas).unwrap();
+    assert!(ui.rect_of(rect).unwrap().bottom() < kid_area.bottom());
+
+    // Press near the bottom of the minimap, over the bottom of the rectangle.
+    move_mouse_to_abs_coordinates(300.0, -240.0, ui);
+    set_widgets(ui, canvas, rect, minimap);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, canvas, rect, minimap);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, canvas, rect, minimap);
+    assert_eq!(ui.rect_of(rect).unwrap().bottom(), kid_area.bottom());
+}
+
+#[test]
+fn a_minimap_placed_within_its_target_should_not_display_itself() {
+    use render::PrimitiveKind;
+
+    // Returns the number of triangles displayed by the minimap.
+    fn n_contents_triangles(is_within_target: bool) -> usize {
+        let ui = &mut windowless_ui();
+        let (canvas, rect, minimap) = {
+            let mut id_generator = ui.widget_id_generator();
+            (id_generator.next(), id_generator.next(), id_generator.next())
+        };
+        for _ in 0..2 {
+            let ui = &mut ui.set_widgets();
+            widget::Canvas::new()
+                .w_h(200.0, 200.0)
+                .middle()
+                .scroll_kids_vertically()
+                .set(canvas, ui);
+            widget::Rectangle::fill([100.0, 1000.0]).mid_top_of(canvas).set(rect, ui);
+            // Without an explicit parent, the minimap is placed within the canvas.
+            let mut builder = widget::Minimap::new(canvas).w_h(50.0, 50.0).x_y(50.0, 0.0);
+            if !is_within_target {
+                builder = builder.parent(ui.window);
+            }
+            builder.set(minimap, ui);
+        }
+        let graph = ui.widget_graph();
+        assert_eq!(graph.does_recursive_depth_edge_exist(canvas, minimap), is_within_target);
+        let mut primitives = ui.draw();
+        let mut n_triangles = 0;
+        while let Some(primitive) = primitives.next() {
+            if let PrimitiveKind::TrianglesMultiColor { triangles } = primitive.kind {
+                n_triangles += triangles.len();
+            }
+        }
+        n_triangles
+    }
+
+    assert_eq!(n_contents_triangles(true), n_contents_triangles(false));
+}
+
+#[test]
+fn pressing_a_minimap_of_a_graph_should_pan_it_once_to_the_point_under_the_mouse() {
+    use widget::graph::{Layout, NodeSocket};
+    let ui = &mut windowless_ui();
+    let (graph, minimap) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [0.0, 0.0]);
+    layout.insert(1u32, [600.0, 0.0]);
+
+    fn set_widgets(
+        ui: &mut Ui,
+        graph: widget::Id,
+        minimap: widget::Id,
+        layout: &Layout<u32>,
+        maybe_pan: Option<Point>,
+    ) -> Option<Point> {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let mut widget = widget::Graph::new(0..2, edges, layout)
+            .w_h(200.0, 200.0)
+            .middle();
+        if let Some(offset) = maybe_pan {
+            widget = widget.pan(offset);
+        }
+        let session = widget.set(graph, ui);
+        let origin = session.camera().view_point([0.0, 0.0]);
+        let mut session = session.next();
+        for node in session.nodes() {
+            node.widget(widget::Rectangle::fill([20.0, 20.0])).set(ui);
+        }
+        widget::Minimap::new(graph)
+            .contents_offset(origin)
+            .w_h(400.0, 100.0)
+            .x_y(0.0, -200.0)
+            .parent(ui.window)
+            .set(minimap, ui)
+    }
+
+    set_widgets(ui, graph, minimap, &layout, None);
+    set_widgets(ui, graph, minimap, &layout, None);
+    let node_rect = |node_id: u32, ui: &Ui| {
+        let w = widget::graph::node_widget_id(node_id, graph, ui).unwrap();
+        ui.rect_of(w).unwrap()
+    };
+    let area = ui.rect_of(minimap).unwrap().pad(ui.theme.border_width);
+    let mapping = widget::minimap::mapping(ui, graph, area, [0.0, 0.0]).unwrap();
+    let over_node = mapping.map_point(node_rect(1, ui).xy());
+
+    // Pressing over the second node should pan it to the centre of the graph exactly once.
+    move_mouse_to_abs_coordinates(over_node[0], over_node[1], ui);
+    set_widgets(ui, graph, minimap, &layout, None);
+    press_mouse_button(MouseButton::Left, ui);
+    let pan = set_widgets(ui, graph, minimap, &layout, None);
+    let pan = pan.expect("expected the minimap to request a pan");
+    assert!((pan[0] + 600.0).abs() < 1e-6 && pan[1].abs() < 1e-6);
+    let mut maybe_pan = Some(pan);
+    for _ in 0..3 {
+        maybe_pan = set_widgets(ui, graph, minimap, &layout, maybe_pan);
+        assert_eq!(maybe_pan, None);
+    }
+    let xy = node_rect(1, ui).xy();
+    assert!(xy[0].abs() < 1e-6 && xy[1].abs() < 1e-6);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, graph, minimap, &layout, None);
+
+    // Pan the graph some other way, after which pressing over the first node should centre it.
+    set_widgets(ui, graph, minimap, &layout, Some([50.0, 30.0]));
+    // The first node lies at the graph's origin, so its position is the offset of the contents.
+    let offset = node_rect(0, ui).xy();
+    let mapping = widget::minimap::mapping(ui, graph, area, offset).unwrap();
+    let over_node = mapping.map_point(node_rect(0, ui).xy());
+    move_mouse_to_abs_coordinates(over_node[0], over_node[1], ui);
+    set_widgets(ui, graph, minimap, &layout, None);
+    press_mouse_button(MouseButton::Left, ui);
+    let mut maybe_pan = set_widgets(ui, graph, minimap, &layout, None);
+    let pan = maybe_pan.expect("expected the minimap to request a pan");
+    assert!((pan[0] - 600.0 + 50.0).abs() < 1e-6 && (pan[1] + 30.0).abs() < 1e-6);
+    for _ in 0..3 {
+        maybe_pan = set_widgets(ui, graph, minimap, &layout, maybe_pan);
+        assert_eq!(maybe_pan, None);
+    }
+    let xy = node_rect(0, ui).xy();
+    assert!(xy[0].abs() < 1e-6 && xy[1].abs() < 1e-6);
+}
+
+#[test]
+fn snapping_should_settle_on_the_nearest_interval_once_scrolling_ends() {
+    use position::Align;
+    use widget::scroll;
+
+    let ui = &mut windowless_ui();
+    let (canvas, rect) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    fn set_widgets(ui: &mut Ui, canvas: widget::Id, rect: widget::Id) {
+        let ui = &mut ui.set_widgets();
+        let snap = scroll::Snap::Interval(100.0, Align::End);
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .middle()
+            .scroll_kids_vertically_with(scroll::Scroll::new().snap(snap))
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+    }
+
+    let y_offset = |ui: &Ui| {
+        ui.widget_graph().widget(canvas).unwrap().maybe_y_scroll_state.unwrap().offset
+    };
+
+    set_widgets(ui, canvas, rect);
+    set_widgets(ui, canvas, rect);
+
+    // Scrolling moves the offset directly.
+    ui.handle_event(Input::Motion(Motion::MouseCursor { x: 0.0, y: 0.0 }));
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 130.0 }));
+    set_widgets(ui, canvas, rect);
+    assert_eq!(y_offset(ui), 130.0);
+    assert!(ui.next_redraw_deadline().is_some());
+
+    // Once scrolling ends, the offset settles on the nearest snap point.
+    for _ in 0..200 {
+        ::std::thread::sleep(::std::time::Duration::from_millis(10));
+        set_widgets(ui, canvas, rect);
+        if ui.next_redraw_deadline().is_none() {
+            break;
+        }
+    }
+    assert_eq!(y_offset(ui), 100.0);
+}
+
+#[test]
+fn estimated_list_items_should_be_measured_and_only_visible_items_instantiated() {
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+
+    // Returns the indices of the items that were instantiated.
+    fn set_widgets(ui: &mut Ui, list: widget::Id) -> Vec<usize> {
+        let ui = &mut ui.set_widgets();
+        let (mut items, _) = widget::List::flow_down(1000)
+            .estimated_item_size(20.0)
+            .scrollbar_on_top()
+            .w_h(100.0, 200.0)
+            .middle()
+            .set(list, ui);
+        let mut indices = vec![];
+        while let Some(item) = items.next(ui) {
+            indices.push(item.i);
+            item.set(widget::Rectangle::fill([100.0, 40.0]), ui);
+        }
+        indices
+    }
+
+    // Before measuring, the estimate is used to determine the visible items.
+    assert_eq!(set_widgets(ui, list), (0..10).collect::<Vec<_>>());
+    assert!(ui.next_redraw_deadline().is_some());
+
+    // Once measured, the items that no longer fit are no longer instantiated.
+    assert_eq!(set_widgets(ui, list), (0..5).collect::<Vec<_>>());
+    assert_eq!(set_widgets(ui, list), (0..5).collect::<Vec<_>>());
+    assert_eq!(ui.next_redraw_deadline(), None);
+}
+
+#[test]
+fn dragging_a_reorderable_list_item_should_produce_a_move() {
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+
+    // Returns the `Move` produced during the update, if any.
+    fn set_widgets(ui: &mut Ui, list: widget::Id) -> Option<widget::list::Move> {
+        let ui = &mut ui.set_widgets();
+        let (mut items, _) = widget::List::flow_down(10)
+            .item_size(20.0)
+            .reorderable()
+            .w_h(100.0, 200.0)
+            .middle()
+            .set(list, ui);
+        while let Some(item) = items.next(ui) {
+            item.set(widget::Rectangle::fill([100.0, 20.0]), ui);
+        }
+        items.take_move()
+    }
+
+    // Press the first item and drag it over the middle of the fourth.
+    assert_eq!(set_widgets(ui, list), None);
+    move_mouse_to_abs_coordinates(0.0, 90.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, list), None);
+    move_mouse_to_abs_coordinates(0.0, 30.0, ui);
+    assert_eq!(set_widgets(ui, list), None);
+    release_mouse_button(MouseButton::Left, ui);
+    let move_ = set_widgets(ui, list).expect("no `Move` was produced");
+    assert_eq!(move_, widget::list::Move { from: vec![0], to: 3 });
+
+    let mut values = vec!['a', 'b', 'c', 'd'];
+    move_.apply(&mut values);
+    assert_eq!(values, vec!['b', 'c', 'a', 'd']);
+
+    // Dropping the second item either side of itself leaves the order unchanged.
+    for &drop_y in &[85.0, 75.0, 65.0, 55.0] {
+        move_mouse_to_abs_coordinates(0.0, 70.0, ui);
+        press_mouse_button(MouseButton::Left, ui);
+        assert_eq!(set_widgets(ui, list), None);
+        move_mouse_to_abs_coordinates(0.0, drop_y, ui);
+        assert_eq!(set_widgets(ui, list), None);
+        release_mouse_button(MouseButton::Left, ui);
+        assert_eq!(set_widgets(ui, list), None);
+    }
+
+    // Dropping it after the third does not.
+    move_mouse_to_abs_coordinates(0.0, 70.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, list);
+    move_mouse_to_abs_coordinates(0.0, 45.0, ui);
+    set_widgets(ui, list);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, list), Some(widget::list::Move { from: vec![1], to: 3 }));
+
+    // Releasing it outside of the list does not.
+    move_mouse_to_abs_coordinates(0.0, 70.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, list);
+    move_mouse_to_abs_coordinates(0.0, 45.0, ui);
+    set_widgets(ui, list);
+    move_mouse_to_abs_coordinates(80.0, 45.0, ui);
+    set_widgets(ui, list);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, list), None);
+}
+
+#[test]
+fn dragging_a_selected_list_select_item_should_move_the_whole_selection() {
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+
+    // Returns the `Move` produced during the update, if any.
+    fn set_widgets(ui: &mut Ui, list: widget::Id, selection: &[usize])
+        -> Option<widget::list::Move>
+    {
+        let ui = &mut ui.set_widgets();
+        let (mut events, _) = widget::ListSelect::multiple(10)
+            .flow_down()
+            .item_size(20.0)
+            .reorderable()
+            .w_h(100.0, 200.0)
+            .middle()
+            .set(list, ui);
+        let mut maybe_move = None;
+        while let Some(event) = events.next(ui, |i| selection.contains(&i)) {
+            match event {
+                widget::list_select::Event::Item(item) =>
+                    item.set(widget::Rectangle::fill([100.0, 20.0]), ui),
+                widget::list_select::Event::Move(move_) => maybe_move = Some(move_),
+                _ => (),
+            }
+        }
+        maybe_move
+    }
+
+    // Drags the item at `from_y` and drops it at `to_y`.
+    fn drag(ui: &mut Ui, list: widget::Id, selection: &[usize], from_y: f64, to_y: f64)
+        -> Option<widget::list::Move>
+    {
+        set_widgets(ui, list, selection);
+        move_mouse_to_abs_coordinates(0.0, from_y, ui);
+        press_mouse_button(MouseButton::Left, ui);
+        assert_eq!(set_widgets(ui, list, selection), None);
+        move_mouse_to_abs_coordinates(0.0, to_y, ui);
+        assert_eq!(set_widgets(ui, list, selection), None);
+        release_mouse_button(MouseButton::Left, ui);
+        set_widgets(ui, list, selection)
+    }
+
+    // Dropping the second item just after itself gathers the fourth along with it.
+    let expected = widget::list::Move { from: vec![1, 3], to: 2 };
+    assert_eq!(drag(ui, list, &[1, 3], 70.0, 55.0), Some(expected));
+
+    // Dropping a contiguous selection either side of itself leaves the order unchanged.
+    assert_eq!(drag(ui, list, &[1, 2], 70.0, 85.0), None);
+    assert_eq!(drag(ui, list, &[1, 2], 70.0, 35.0), None);
+
+    // Dropping it after the next item does not.
+    let expected = widget::list::Move { from: vec![1, 2], to: 4 };
+    assert_eq!(drag(ui, list, &[1, 2], 70.0, 25.0), Some(expected));
+}
+
+#[test]
+fn dragged_payload_should_be_dropped_onto_the_accepting_widget_under_the_mouse() {
+    let ui = &mut windowless_ui();
+    let (source, target) = {
+        let mut id_gen = ui.widget_id_generator();
+        (id_gen.next(), id_gen.next())
+    };
+
+    // Returns the events received by the target along with any payload that it took.
+    fn set_widgets(ui: &mut Ui, source: widget::Id, target: widget::Id)
+        -> (Vec<event::Widget>, Option<u32>)
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([50.0, 50.0]).x_y(-100.0, 0.0).set(source, ui);
+        widget::Rectangle::fill([50.0, 50.0]).x_y(100.0, 0.0).set(target, ui);
+        if ui.widget_input(source).drags().left().next().is_some() {
+            ui.start_drag(source, 42u32);
+        }
+        if ui.drag_and_drop().and_then(|drag| drag.payload::<u32>()).is_some() {
+            ui.accept_drop(target);
+        }
+        let events: Vec<_> = ui.widget_input(target).events()
+            .filter(|event| match *event {
+                event::Widget::DragEnter | event::Widget::DragLeave |
+                event::Widget::DragOver(_) | event::Widget::Drop(_) => true,
+                _ => false,
+            })
+            .collect();
+        let mut payload = None;
+        if ui.widget_input(target).drops().next().is_some() {
+            payload = ui.take_drag_payload::<u32>();
+        }
+        (events, payload)
+    }
+
+    set_widgets(ui, source, target);
+    move_mouse_to_abs_coordinates(-100.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(100.0, 0.0, ui);
+    assert_eq!(set_widgets(ui, source, target), (vec![], None));
+
+    // Once accepted, the target under the mouse receives the `DragEnter` event.
+    assert_eq!(set_widgets(ui, source, target), (vec![event::Widget::DragEnter], None));
+    assert!(ui.widget_input(target).is_drop_target());
+
+    release_mouse_button(MouseButton::Left, ui);
+    let (events, payload) = set_widgets(ui, source, target);
+    match events[..] {
+        [event::Widget::Drop(drop)] => assert_eq!(drop.source, source),
+        _ => panic!("unexpected events: {:?}", events),
+    }
+    assert_eq!(payload, Some(42));
+    assert!(ui.drag_and_drop().is_none());
+}
+
+#[test]
+fn cancelling_a_drag_should_send_drag_leave_to_the_drop_target() {
+    let ui = &mut windowless_ui();
+    let (source, target) = {
+        let mut id_gen = ui.widget_id_generator();
+        (id_gen.next(), id_gen.next())
+    };
+
+    // Returns the drag-and-drop events received by the target.
+    fn set_widgets(ui: &mut Ui, source: widget::Id, target: widget::Id, cancel: bool)
+        -> Vec<event::Widget>
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([50.0, 50.0]).x_y(-100.0, 0.0).set(source, ui);
+        widget::Rectangle::fill([50.0, 50.0]).x_y(100.0, 0.0).set(target, ui);
+        if ui.widget_input(source).drags().left().next().is_some() {
+            ui.start_drag(source, 42u32);
+        }
+        if ui.drag_and_drop().is_some() {
+            ui.accept_drop(target);
+        }
+        if cancel {
+            ui.cancel_drag();
+        }
+        ui.widget_input(target).events()
+            .filter(|event| match *event {
+                event::Widget::DragEnter | event::Widget::DragLeave |
+                event::Widget::DragOver(_) | event::Widget::Drop(_) => true,
+                _ => false,
+            })
+            .collect()
+    }
+
+    set_widgets(ui, source, target, false);
+    move_mouse_to_abs_coordinates(-100.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(100.0, 0.0, ui);
+    set_widgets(ui, source, target, false);
+    assert_eq!(set_widgets(ui, source, target, true), vec![event::Widget::DragEnter]);
+    assert!(ui.drag_and_drop().is_none());
+    assert_eq!(set_widgets(ui, source, target, false), vec![event::Widget::DragLeave]);
+    assert!(!ui.widget_input(target).is_drop_target());
+}
+
+#[test]
+fn dropped_files_should_be_received_by_the_widget_under_the_mouse() {
+    let ui = &mut windowless_ui();
+    let rect = ui.widget_id_generator().next();
+
+    fn set_widgets(ui: &mut Ui, rect: widget::Id) -> Vec<event::File> {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([50.0, 50.0]).x_y(100.0, 0.0).set(rect, ui);
+        ui.widget_input(rect).dropped_files().collect()
+    }
+
+    set_widgets(ui, rect);
+    move_mouse_to_abs_coordinates(110.0, 0.0, ui);
+    let path = std::path::PathBuf::from("image.png");
+    ui.handle_event(Input::HoveredFile(path.clone()));
+    ui.handle_event(Input::DroppedFile(path.clone()));
+    let files = set_widgets(ui, rect);
+    assert_eq!(files, vec![event::File { path: path, xy: [10.0, 0.0] }]);
+}
+
+#[test]
+fn drop_down_list_items_should_be_selectable_while_the_list_animates_closed() {
+    use animation::{Easing, Transition};
+    use std::time::Duration;
+
+    let ui = &mut windowless_ui();
+    let ddl = ui.widget_id_generator().next();
+    let items = ["a", "b", "c"];
+
+    fn set_widgets(ui: &mut Ui, ddl: widget::Id, items: &[&str]) -> Option<usize> {
+        let ui = &mut ui.set_widgets();
+        widget::DropDownList::new(items, None)
+            .w_h(100.0, 20.0)
+            .middle()
+            .set(ddl, ui)
+    }
+
+    // Open the list immediately.
+    ui.theme.transition = Transition::Immediate;
+    set_widgets(ui, ddl, &items);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui, ddl, &items);
+    set_widgets(ui, ddl, &items);
+
+    // Close it by clicking elsewhere, slowly animating it closed.
+    ui.theme.transition = Transition::tween(Duration::from_secs(60), Easing::Linear);
+    move_mouse_to_abs_coordinates(300.0, 200.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, ddl, &items);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, ddl, &items);
+    assert!(ui.is_animating());
+
+    // Click the last item while the list is still visible.
+    move_mouse_to_abs_coordinates(0.0, -40.0, ui);
+    set_widgets(ui, ddl, &items);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, ddl, &items), Some(2));
+}
+
+#[test]
+fn typing_should_select_the_next_list_select_item_starting_with_the_text() {
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+    let labels = ["apple", "banana", "blueberry", "cherry"];
+    let mut selected = None;
+
+    fn set_widgets(ui: &mut Ui, list: widget::Id, labels: &[&str], selected: &mut Option<usize>) {
+        let ui = &mut ui.set_widgets();
+        let (mut events, _) = widget::ListSelect::single(labels.len())
+            .flow_down()
+            .item_size(20.0)
+            .type_ahead(|i| labels[i].to_string())
+            .w_h(100.0, 80.0)
+            .middle()
+            .set(list, ui);
+        while let Some(event) = events.next_scrolled(ui, |i| Some(i) == *selected) {
+            match event {
+                widget::list_select::Event::Item(item) =>
+                    item.set(widget::Rectangle::fill([100.0, 20.0]), ui),
+                widget::list_select::Event::Selection(i) => *selected = Some(i),
+                _ => (),
+            }
+        }
+    }
+
+    // Click the first item so that the list captures the keyboard.
+    set_widgets(ui, list, &labels, &mut selected);
+    move_mouse_to_abs_coordinates(0.0, 30.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui, list, &labels, &mut selected);
+    assert_eq!(selected, Some(0));
+
+    ui.handle_event(Input::Text("b".to_string()));
+    set_widgets(ui, list, &labels, &mut selected);
+    assert_eq!(selected, Some(1));
+
+    ui.handle_event(Input::Text("l".to_string()));
+    set_widgets(ui, list, &labels, &mut selected);
+    assert_eq!(selected, Some(2));
+}
+
+#[test]
+fn arrow_keys_should_scroll_the_newly_selected_list_select_item_into_view() {
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+    let mut selected = None;
+
+    // Returns the rect of each instantiated item.
+    fn set_widgets(ui: &mut Ui, list: widget::Id, selected: &mut Option<usize>)
+        -> Vec<(usize, ::Rect)>
+    {
+        let ui = &mut ui.set_widgets();
+        let (mut events, _) = widget::ListSelect::single(20)
+            .flow_down()
+            .item_size(20.0)
+            .scrollbar_next_to()
+            .w_h(100.0, 80.0)
+            .middle()
+            .set(list, ui);
+        let mut item_rects = vec![];
+        while let Some(event) = events.next_scrolled(ui, |i| Some(i) == *selected) {
+            match event {
+                widget::list_select::Event::Item(item) => {
+                    let (i, id) = (item.i, item.widget_id);
+                    item.set(widget::Rectangle::fill([100.0, 20.0]), ui);
+                    item_rects.push((i, ui.rect_of(id).unwrap()));
+                },
+                widget::list_select::Event::Selection(i) => *selected = Some(i),
+                _ => (),
+            }
+        }
+        item_rects
+    }
+
+    // Click the first item so that the list captures the keyboard.
+    set_widgets(ui, list, &mut selected);
+    move_mouse_to_abs_coordinates(0.0, 30.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui, list, &mut selected);
+    assert_eq!(selected, Some(0));
+
+    for _ in 0..4 {
+        ui.handle_event(Input::Press(Button::Keyboard(Key::Down)));
+        set_widgets(ui, list, &mut selected);
+    }
+    assert_eq!(selected, Some(4));
+    let item_rects = set_widgets(ui, list, &mut selected);
+    let bottom = ui.rect_of(list).unwrap().bottom();
+    let &(_, rect) = item_rects.iter().find(|&&(i, _)| i == 4).expect("item 4 is not visible");
+    assert_eq!(rect.bottom(), bottom);
+}
+
+#[test]
+fn combo_box_should_suggest_items_matching_the_text() {
+    use widget::combo_box::{suggestions, Matching};
+    let items = ["Banana", "apple", "Pineapple", "grape"];
+    assert_eq!(suggestions(&items, "ap", Matching::Prefix), vec![1]);
+    assert_eq!(suggestions(&items, "ap", Matching::Substring), vec![1, 3, 2]);
+    assert_eq!(suggestions(&items, "pe", Matching::Fuzzy), vec![2, 1, 3]);
+    assert_eq!(suggestions(&items, "", Matching::Fuzzy), vec![0, 1, 2, 3]);
+}
+
+// Set a `ComboBox` of fruit, returning its events and whether or not its suggestions are open.
+fn set_combo_box(ui: &mut Ui, combo: widget::Id, text: &str, free_text: bool)
+    -> (Vec<widget::combo_box::Event>, bool)
+{
+    use graph::Walker;
+    let items = ["apple", "apricot", "banana"];
+    let ui = &mut ui.set_widgets();
+    let events = widget::ComboBox::new(&items, text)
+        .free_text(free_text)
+        .w_h(200.0, 30.0)
+        .middle()
+        .set(combo, ui);
+    // The suggestions list is only set alongside the rectangle and text while open.
+    let graph = ui.widget_graph();
+    let num_updated_kids = graph.depth_children(combo).iter(graph).nodes()
+        .filter(|kid| ui.updated_widgets().contains(kid))
+        .count();
+    (events, num_updated_kids > 2)
+}
+
+fn press_key(key: Key, ui: &mut Ui) {
+    ui.handle_event(Input::Press(Button::Keyboard(key)));
+    ui.handle_event(Input::Release(Button::Keyboard(key)));
+}
+
+#[test]
+fn combo_box_arrow_keys_should_navigate_the_suggestions_and_return_should_select() {
+    use widget::combo_box::Event;
+    let ui = &mut windowless_ui();
+    let combo = ui.widget_id_generator().next();
+
+    // Click the text so that it captures the keyboard.
+    set_combo_box(ui, combo, "", false);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![], false));
+
+    // `Down` opens the suggestions, highlighting the first.
+    press_key(Key::Down, ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![], true));
+
+    // Move past the last suggestion and then back up to the second.
+    for &key in &[Key::Down, Key::Down, Key::Down, Key::Up] {
+        press_key(key, ui);
+        set_combo_box(ui, combo, "", false);
+    }
+
+    press_key(Key::Return, ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![Event::Select(1)], false));
+}
+
+#[test]
+fn combo_box_return_should_select_the_highlighted_suggestion_or_submit_free_text() {
+    use widget::combo_box::Event;
+    let ui = &mut windowless_ui();
+    let combo = ui.widget_id_generator().next();
+
+    set_combo_box(ui, combo, "AP", true);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_combo_box(ui, combo, "AP", true);
+
+    // The first suggestion matching the text is chosen once highlighted.
+    press_key(Key::Down, ui);
+    set_combo_box(ui, combo, "AP", true);
+    press_key(Key::Return, ui);
+    assert_eq!(set_combo_box(ui, combo, "AP", true), (vec![Event::Select(0)], false));
+
+    // With nothing highlighted, the text itself is submitted.
+    press_key(Key::Return, ui);
+    let expected = vec![Event::Submit("AP".to_string())];
+    assert_eq!(set_combo_box(ui, combo, "AP", true), (expected, false));
+
+    // Text matching no suggestion is submitted as is.
+    press_key(Key::Down, ui);
+    set_combo_box(ui, combo, "kiwi", true);
+    press_key(Key::Return, ui);
+    let expected = vec![Event::Submit("kiwi".to_string())];
+    assert_eq!(set_combo_box(ui, combo, "kiwi", true), (expected, false));
+}
+
+#[test]
+fn combo_box_escape_should_close_the_suggestions_without_selecting() {
+    let ui = &mut windowless_ui();
+    let combo = ui.widget_id_generator().next();
+
+    set_combo_box(ui, combo, "", false);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_combo_box(ui, combo, "", false);
+
+    press_key(Key::Down, ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![], true));
+
+    press_key(Key::Escape, ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![], false));
+
+    // The highlight was cleared along with the list, so `Return` has nothing to select.
+    press_key(Key::Return, ui);
+    assert_eq!(set_combo_box(ui, combo, "", false), (vec![], false));
+}
+
+#[test]
+fn toggling_a_checkbox_should_yield_its_new_check_state() {
+    use widget::checkbox::CheckState;
+    let ui = &mut windowless_ui();
+    let checkbox = ui.widget_id_generator().next();
+
+    fn set_widgets(ui: &mut Ui, checkbox: widget::Id, value: CheckState) -> Option<CheckState> {
+        let ui = &mut ui.set_widgets();
+        widget::Checkbox::new(value).w_h(30.0, 30.0).middle().set(checkbox, ui)
+    }
+
+    set_widgets(ui, checkbox, CheckState::Indeterminate);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, checkbox, CheckState::Indeterminate), Some(CheckState::Checked));
+
+    // `Space` toggles the checkbox while it captures the keyboard.
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Space)));
+    assert_eq!(set_widgets(ui, checkbox, CheckState::Checked), Some(CheckState::Unchecked));
+    assert_eq!(set_widgets(ui, checkbox, CheckState::Unchecked), None);
+}
+
+#[test]
+fn radio_group_should_select_options_via_the_mouse_and_arrow_keys() {
+    let ui = &mut windowless_ui();
+    let radio = ui.widget_id_generator().next();
+    let options = ["low", "medium", "high"];
+    let mut selected = Some(0);
+
+    fn set_widgets(ui: &mut Ui, radio: widget::Id, options: &[&str], selected: &mut Option<usize>) {
+        let ui = &mut ui.set_widgets();
+        if let Some(i) = widget::RadioGroup::new(options, *selected)
+            .w_h(100.0, 90.0)
+            .middle()
+            .set(radio, ui)
+        {
+            *selected = Some(i);
+        }
+    }
+
+    set_widgets(ui, radio, &options, &mut selected);
+    move_mouse_to_abs_coordinates(0.0, -30.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui, radio, &options, &mut selected);
+    assert_eq!(selected, Some(2));
+
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Up)));
+    set_widgets(ui, radio, &options, &mut selected);
+    assert_eq!(selected, Some(1));
+}
+
+#[test]
+fn dragging_a_knob_upwards_should_increase_its_value() {
+    let ui = &mut windowless_ui();
+    let knob = ui.widget_id_generator().next();
+    let mut value = 0.5;
+
+    fn set_widgets(ui: &mut Ui, knob: widget::Id, value: &mut f64) {
+        let ui = &mut ui.set_widgets();
+        if let Some(new_value) = widget::Knob::new(*value, 0.0, 1.0)
+            .drag_distance(200.0)
+            .w_h(100.0, 100.0)
+            .middle()
+            .set(knob, ui)
+        {
+            *value = new_value;
+        }
+    }
+
+    set_widgets(ui, knob, &mut value);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(0.0, 50.0, ui);
+    set_widgets(ui, knob, &mut value);
+    release_mouse_button(MouseButton::Left, ui);
+    assert!((value - 0.75).abs() < 1e-9, "unexpected value: {}", value);
+
+    let log = widget::knob::Mapping::Logarithmic;
+    assert!((log.perc_from_value(100.0, 10.0, 1000.0) - 0.5).abs() < 1e-9);
+    assert!((log.value_from_perc(0.5, 10.0, 1000.0) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn knob_arc_triangles_should_span_the_band_between_the_radii() {
+    use std::f64::consts::PI;
+    use widget::knob::arc_triangles;
+
+    let triangles = arc_triangles([10.0, 20.0], 5.0, 8.0, 0.0, PI);
+    assert_eq!(triangles.len(), 64);
+    for triangle in triangles {
+        for p in triangle.0.iter() {
+            let r = (p[0] - 10.0).hypot(p[1] - 20.0);
+            assert!((r - 5.0).abs() < 1e-9 || (r - 8.0).abs() < 1e-9);
+            assert!(p[1] >= 20.0 - 1e-9);
+        }
+    }
+
+    // Empty arcs still yield a single degenerate segment.
+    assert_eq!(arc_triangles([0.0, 0.0], 5.0, 8.0, 1.0, 1.0).count(), 2);
+}
+
+#[test]
+fn scrolling_over_a_graph_should_zoom_around_the_cursor() {
+    use widget::graph::{Camera, Layout, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let layout = Layout::from(::std::collections::HashMap::new());
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>) -> Camera {
+        let ui = &mut ui.set_widgets();
+        let nodes = ::std::iter::empty::<u32>();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        widget::Graph::new(nodes, edges, layout)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui)
+            .camera()
+    }
+
+    set_widgets(ui, graph, &layout);
+    move_mouse_to_abs_coordinates(100.0, 50.0, ui);
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: -50.0 }));
+    let camera = set_widgets(ui, graph, &layout);
+    assert!(camera.zoom() > 1.0);
+    let view_point = camera.view_point([100.0, 50.0]);
+    assert!((view_point[0] - 100.0).abs() < 1e-9 && (view_point[1] - 50.0).abs() < 1e-9);
+}
+
+#[test]
+fn scrolling_over_a_graph_at_its_maximum_zoom_should_not_move_the_camera() {
+    use widget::graph::{Camera, Layout, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let layout = Layout::from(::std::collections::HashMap::new());
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>) -> Camera {
+        let ui = &mut ui.set_widgets();
+        let nodes = ::std::iter::empty::<u32>();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        widget::Graph::new(nodes, edges, layout)
+            .zoom_range(0.5, 2.0)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui)
+            .camera()
+    }
+
+    set_widgets(ui, graph, &layout);
+    move_mouse_to_abs_coordinates(100.0, 50.0, ui);
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: -500.0 }));
+    let camera = set_widgets(ui, graph, &layout);
+    assert_eq!(camera.zoom(), 2.0);
+    move_mouse_to_abs_coordinates(-120.0, -80.0, ui);
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: -50.0 }));
+    assert_eq!(set_widgets(ui, graph, &layout), camera);
+}
+
+#[test]
+fn rubber_band_should_select_the_nodes_it_touches() {
+    use widget::graph::{Event, Layout, NodeEvent, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [-100.0, 0.0]);
+    layout.insert(1u32, [0.0, 0.0]);
+    layout.insert(2u32, [100.0, 100.0]);
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>) -> (Vec<u32>, Vec<u32>) {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let session = widget::Graph::new(0..3, edges, layout)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui);
+        let removed = session.events()
+            .filter_map(|event| match event {
+                Event::Node(NodeEvent::Remove(node_id)) => Some(node_id),
+                _ => None,
+            })
+            .collect();
+        let mut session = session.next();
+        for node in session.nodes() {
+            node.widget(widget::Rectangle::fill([20.0, 20.0])).set(ui);
+        }
+        (session.selection(), removed)
+    }
+
+    set_widgets(ui, graph, &layout);
+    move_mouse_to_abs_coordinates(-150.0, 50.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(5.0, -50.0, ui);
+    release_mouse_button(MouseButton::Left, ui);
+    let (selection, _) = set_widgets(ui, graph, &layout);
+    assert_eq!(selection, vec![0, 1]);
+
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Delete)));
+    let (_, removed) = set_widgets(ui, graph, &layout);
+    assert_eq!(removed, vec![0, 1]);
+}
+
+#[test]
+fn keys_and_scrolls_within_a_node_and_socket_presses_should_leave_the_selection_alone() {
+    use widget::graph::{node, Camera, Event, Layout, Node, NodeEvent, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [-100.0, 0.0]);
+    layout.insert(1u32, [100.0, 0.0]);
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>)
+        -> (Vec<u32>, Vec<u32>, Camera)
+    {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let session = widget::Graph::new(0..2, edges, layout)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui);
+        let camera = session.camera();
+        let removed = session.events()
+            .filter_map(|event| match event {
+                Event::Node(NodeEvent::Remove(node_id)) => Some(node_id),
+                _ => None,
+            })
+            .collect();
+        let mut session = session.next();
+        for node in session.nodes() {
+            let widget = Node::new(widget::Rectangle::fill([10.0, 10.0]))
+                .inputs(1)
+                .outputs(1)
+                .w_h(60.0, 40.0);
+            node.widget(widget).set(ui);
+        }
+        (session.selection(), removed, camera)
+    }
+
+    let (_, _, camera) = set_widgets(ui, graph, &layout);
+
+    // Pressing the widget within the first node selects it and captures the keyboard.
+    move_mouse_to_abs_coordinates(-100.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, graph, &layout), (vec![0], vec![], camera));
+
+    // Keys and scrolls received by the widget within the node are left to that widget.
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Delete)));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::Delete)));
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Escape)));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::Escape)));
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: -50.0 }));
+    assert_eq!(set_widgets(ui, graph, &layout), (vec![0], vec![], camera));
+
+    // Pressing a socket of the second node begins an edge without selecting the node.
+    let w = widget::graph::node_widget_id(1u32, graph, ui).unwrap();
+    let (mut inputs, _) = node::socket_rects(w, ui).unwrap();
+    let input = inputs.next().unwrap().xy();
+    move_mouse_to_abs_coordinates(input[0], input[1], ui);
+    set_widgets(ui, graph, &layout);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, graph, &layout).0, vec![0]);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, graph, &layout).0, vec![0]);
+}
+
+#[test]
+fn dragging_from_an_output_to_an_input_socket_should_add_an_edge() {
+    use widget::graph::{node, EdgeEvent, Event, Layout, Node, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [-100.0, 0.0]);
+    layout.insert(1u32, [100.0, 0.0]);
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>) -> Vec<EdgeEvent<u32>> {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let session = widget::Graph::new(0..2, edges, layout)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui);
+        let events = session.events()
+            .filter_map(|event| match event {
+                Event::Edge(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        let mut session = session.next();
+        for node in session.nodes() {
+            let widget = Node::new(widget::Rectangle::fill([10.0, 10.0]))
+                .inputs(1)
+                .outputs(1)
+                .w_h(60.0, 40.0);
+            node.widget(widget).set(ui);
+        }
+        events
+    }
+
+    set_widgets(ui, graph, &layout);
+    let socket_xy = |node_id: u32, ui: &Ui| {
+        let w = widget::graph::node_widget_id(node_id, graph, ui).unwrap();
+        let (mut inputs, mut outputs) = node::socket_rects(w, ui).unwrap();
+        (inputs.next().unwrap().xy(), outputs.next().unwrap().xy())
+    };
+    let (_, output) = socket_xy(0, ui);
+    let (input, _) = socket_xy(1, ui);
+
+    move_mouse_to_abs_coordinates(output[0], output[1], ui);
+    set_widgets(ui, graph, &layout);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(input[0] - 5.0, input[1] + 5.0, ui);
+    let start = NodeSocket { id: 0, socket_index: 0 };
+    let end = NodeSocket { id: 1, socket_index: 0 };
+    assert_eq!(
+        set_widgets(ui, graph, &layout),
+        vec![EdgeEvent::AddStart(start), EdgeEvent::TargetStart { start, end }]
+    );
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, graph, &layout), vec![EdgeEvent::Add { start, end }]);
+}
+
+#[test]
+fn dragging_between_sockets_of_different_kinds_should_not_add_an_edge() {
+    use widget::graph::{node, EdgeEvent, Event, Layout, Node, NodeSocket, Socket, SocketType};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [-100.0, 0.0]);
+    layout.insert(1u32, [100.0, 0.0]);
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>)
+        -> (Vec<EdgeEvent<u32>>, Vec<(SocketType, usize)>)
+    {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let session = widget::Graph::new(0..2, edges, layout)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui);
+        let events = session.events()
+            .filter_map(|event| match event {
+                Event::Edge(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        let mut session = session.next();
+        let mut invalid_sockets = vec![];
+        for node in session.nodes() {
+            if node.node_id() == 1 {
+                invalid_sockets.extend(node.invalid_sockets().iter().cloned());
+            }
+            let widget = Node::new(widget::Rectangle::fill([10.0, 10.0]))
+                .input_sockets(vec![Socket::new().kind("color")])
+                .output_sockets(vec![Socket::new().kind("number")])
+                .w_h(60.0, 40.0);
+            node.widget(widget).set(ui);
+        }
+        (events, invalid_sockets)
+    }
+
+    set_widgets(ui, graph, &layout);
+    let socket_xy = |node_id: u32, ui: &Ui| {
+        let w = widget::graph::node_widget_id(node_id, graph, ui).unwrap();
+        let (mut inputs, mut outputs) = node::socket_rects(w, ui).unwrap();
+        (inputs.next().unwrap().xy(), outputs.next().unwrap().xy())
+    };
+    let (_, output) = socket_xy(0, ui);
+    let (input, _) = socket_xy(1, ui);
+
+    move_mouse_to_abs_coordinates(output[0], output[1], ui);
+    set_widgets(ui, graph, &layout);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(input[0], input[1], ui);
+    let start = NodeSocket { id: 0, socket_index: 0 };
+    let (events, invalid_sockets) = set_widgets(ui, graph, &layout);
+    assert_eq!(events, vec![EdgeEvent::AddStart(start)]);
+    assert_eq!(invalid_sockets, vec![(SocketType::Input, 0)]);
+    release_mouse_button(MouseButton::Left, ui);
+    let (events, _) = set_widgets(ui, graph, &layout);
+    assert_eq!(events, vec![EdgeEvent::Cancelled(start)]);
+}
+
+#[test]
+fn sockets_rejected_by_validate_edge_should_be_greyed_out_and_never_targeted() {
+    use color;
+    use render::PrimitiveKind;
+    use widget::graph::{node, Connection, EdgeEvent, Event, Layout, Node, NodeSocket};
+    let ui = &mut windowless_ui();
+    let graph = ui.widget_id_generator().next();
+    let mut layout = Layout::from(::std::collections::HashMap::new());
+    layout.insert(0u32, [-100.0, 0.0]);
+    layout.insert(1u32, [100.0, 0.0]);
+
+    fn set_widgets(ui: &mut Ui, graph: widget::Id, layout: &Layout<u32>) -> Vec<EdgeEvent<u32>> {
+        let ui = &mut ui.set_widgets();
+        let edges = ::std::iter::empty::<(NodeSocket<u32>, NodeSocket<u32>)>();
+        let session = widget::Graph::new(0..2, edges, layout)
+            .validate_edge(|connection: &Connection<u32>| connection.end.id != 1)
+            .w_h(400.0, 400.0)
+            .middle()
+            .set(graph, ui);
+        let events = session.events()
+            .filter_map(|event| match event {
+                Event::Edge(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        let mut session = session.next();
+        for node in session.nodes() {
+            let widget = Node::new(widget::Rectangle::fill([10.0, 10.0]))
+                .inputs(1)
+                .outputs(1)
+                .invalid_sockets(node.invalid_sockets().iter().cloned())
+                .invalid_socket_color(color::RED)
+                .w_h(60.0, 40.0);
+            node.widget(widget).set(ui);
+        }
+        events
+    }
+
+    fn n_greyed_out_triangles(ui: &Ui) -> usize {
+        let red: color::Rgba = color::RED.into();
+        let mut primitives = ui.draw();
+        let mut n = 0;
+        while let Some(primitive) = primitives.next() {
+            if let PrimitiveKind::TrianglesMultiColor { triangles } = primitive.kind {
+                n += triangles.iter().filter(|tri| tri[0].1 == red).count();
+            }
+        }
+        n
+    }
+
+    set_widgets(ui, graph, &layout);
+    assert_eq!(n_greyed_out_triangles(ui), 0);
+    let socket_xy = |node_id: u32, ui: &Ui| {
+        let w = widget::graph::node_widget_id(node_id, graph, ui).unwrap();
+        let (mut inputs, mut outputs) = node::socket_rects(w, ui).unwrap();
+        (inputs.next().unwrap().xy(), outputs.next().unwrap().xy())
+    };
+    let (_, output) = socket_xy(0, ui);
+    let (input, _) = socket_xy(1, ui);
+
+    move_mouse_to_abs_coordinates(output[0], output[1], ui);
+    set_widgets(ui, graph, &layout);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(input[0], input[1], ui);
+    let start = NodeSocket { id: 0, socket_index: 0 };
+    assert_eq!(set_widgets(ui, graph, &layout), vec![EdgeEvent::AddStart(start)]);
+    set_widgets(ui, graph, &layout);
+    // Only the two triangles of node 1's input socket are greyed out.
+    assert_eq!(n_greyed_out_triangles(ui), 2);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, graph, &layout), vec![EdgeEvent::Cancelled(start)]);
+    set_widgets(ui, graph, &layout);
+    assert_eq!(n_greyed_out_triangles(ui), 0);
+}
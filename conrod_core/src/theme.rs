@@ -3,6 +3,7 @@
 //!
 
 use Scalar;
+use animation;
 use color::{Color, BLACK, WHITE};
 use position::{Align, Direction, Padding, Position, Relative};
 use fnv;
@@ -54,6 +55,20 @@ pub struct Theme {
     /// Once the `Duration` that separates two consecutive `Click`s is greater than this value, a
     /// `DoubleClick` event will no longer be generated.
     pub double_click_threshold: std::time::Duration,
+    /// The interval at which the text cursor (caret) of text editing widgets alternates between
+    /// visible and hidden.
+    ///
+    /// If `None`, the cursor is always visible while the widget captures the keyboard.
+    ///
+    /// Defaults to `None`, as a blinking cursor is only redrawn by applications that update the
+    /// `Ui` again by its `next_redraw_deadline`.
+    pub text_cursor_blink_interval: Option<std::time::Duration>,
+    /// The default transition used by widgets that animate changes in their state, e.g. the
+    /// opening of a `CollapsibleArea` or `DropDownList`.
+    ///
+    /// Defaults to `Transition::Immediate`, so that applications that do not keep updating the
+    /// `Ui` while `Ui::is_animating` returns `true` behave as they always have.
+    pub transition: animation::Transition,
 }
 
 /// The defaults for a specific widget.
@@ -105,6 +120,8 @@ impl Theme {
             widget_styling: fnv::FnvHashMap::default(),
             mouse_drag_threshold: 0.0,
             double_click_threshold: std::time::Duration::from_millis(500),
+            text_cursor_blink_interval: None,
+            transition: animation::Transition::Immediate,
         }
     }
 
@@ -1,4 +1,6 @@
+use animation;
 use color::Color;
+use drag_and_drop::DragAndDrop;
 use event;
 use graph::{self, Graph};
 use input;
@@ -84,8 +86,23 @@ pub struct Ui {
     /// the end of the `Ui::set_widgets` method. This ensures that the events are received by the
     /// target widgets during the next call to `Ui::set_widgets`.
     pending_scroll_events: Vec<event::Ui>,
+    /// Drag-and-drop events that have been emitted during a call to `Ui::set_widgets`, e.g. the
+    /// `DragLeave` event produced by `UiCell::cancel_drag`.
+    ///
+    /// Like the `pending_scroll_events`, these are pushed onto the end of the `global_input` event
+    /// buffer at the end of the `Ui::set_widgets` method.
+    pending_drag_and_drop_events: Vec<event::Ui>,
     /// Mouse cursor
     mouse_cursor: cursor::MouseCursor,
+    /// Values animated by widgets via `UiCell::animate`.
+    animations: animation::Animations,
+    /// The payload currently being dragged from one widget onto another, if any.
+    maybe_drag_and_drop: Option<DragAndDrop>,
+    /// The earliest moment at which some widget has requested to be updated and redrawn.
+    ///
+    /// This is cleared at the beginning of each call to `set_widgets`, so widgets must request
+    /// their deadline again each time they are updated.
+    maybe_redraw_deadline: Option<instant::Instant>,
 
     // TODO: Remove the following fields as they should now be handled by `input::Global`.
 
@@ -204,7 +221,11 @@ impl Ui {
             prev_updated_widgets: prev_updated_widgets,
             global_input: input::Global::new(),
             pending_scroll_events: Vec::new(),
+            pending_drag_and_drop_events: Vec::new(),
             mouse_cursor: cursor::MouseCursor::Arrow,
+            animations: animation::Animations::new(),
+            maybe_drag_and_drop: None,
+            maybe_redraw_deadline: None,
         }
     }
 
@@ -315,6 +336,52 @@ impl Ui {
         }
     }
 
+    /// Scroll all scrollable ancestors of the widget at the given index so that the widget
+    /// becomes fully visible, aligned within each ancestor's kid area by the given `alignment`.
+    ///
+    /// The produced `Scroll` events will be applied upon the next call to `Ui::set_widgets`.
+    pub fn scroll_to_widget(&mut self,
+                            widget_id: widget::Id,
+                            alignment: widget::scroll::Alignment)
+    {
+        for (id, offset) in self.scrolls_to_widget(widget_id, alignment) {
+            self.scroll_widget(id, offset);
+        }
+    }
+
+    /// Scroll the widget at the given index along with all of its scrollable ancestors so that
+    /// the given `rect` (positioned within the widget's kid area) becomes fully visible.
+    ///
+    /// This is useful for bringing some area into view that is not occupied by a single widget,
+    /// e.g. an item of a `List` that is not currently instantiated.
+    ///
+    /// The produced `Scroll` events will be applied upon the next call to `Ui::set_widgets`.
+    pub fn scroll_to_rect(&mut self,
+                          widget_id: widget::Id,
+                          rect: Rect,
+                          alignment: widget::scroll::Alignment)
+    {
+        for (id, offset) in widget::scroll::scroll_into_view(self, widget_id, rect, alignment) {
+            self.scroll_widget(id, offset);
+        }
+    }
+
+    /// The offsets by which `scroll_to_widget` would scroll each of the widget's scrollable
+    /// ancestors.
+    fn scrolls_to_widget(&self,
+                         widget_id: widget::Id,
+                         alignment: widget::scroll::Alignment) -> Vec<(widget::Id, Point)>
+    {
+        let rect = match self.rect_of(widget_id) {
+            Some(rect) => rect,
+            None => return Vec::new(),
+        };
+        match self.widget_graph.depth_parent(widget_id) {
+            Some(parent_id) => widget::scroll::scroll_into_view(self, parent_id, rect, alignment),
+            None => Vec::new(),
+        }
+    }
+
     /// Determines which widget is currently under the mouse and sets it within the `Ui`'s
     /// `input::Global`'s `input::State`.
     ///
@@ -362,6 +429,62 @@ impl Ui {
                 }
             }
         }
+
+        self.update_drop_target();
+    }
+
+    /// Determines the widget onto which the current drag-and-drop payload would be dropped and
+    /// sets it within the `Ui`'s `input::Global`'s `input::State`.
+    ///
+    /// If the drop target has changed, `DragLeave` and `DragEnter` events are produced.
+    fn update_drop_target(&mut self) {
+        let maybe_target = match self.maybe_drag_and_drop {
+            Some(ref drag) if !drag.is_dropped() => {
+                drag.pick_target(&self.widget_graph,
+                                 &self.depth_order.indices,
+                                 &self.theme,
+                                 self.global_input.current.mouse.xy)
+            },
+            _ => None,
+        };
+        let prev_target = self.global_input.current.drop_target;
+        if maybe_target != prev_target {
+            if let Some(idx) = prev_target {
+                self.global_input.push_event(event::Ui::DragLeave(idx).into());
+            }
+            if let Some(idx) = maybe_target {
+                self.global_input.push_event(event::Ui::DragEnter(idx).into());
+            }
+            self.global_input.current.drop_target = maybe_target;
+        }
+    }
+
+    /// Drops the drag-and-drop payload onto the current drop target.
+    ///
+    /// The payload remains available throughout the next call to `set_widgets` so that the target
+    /// may take it upon receiving the `Drop` event. If there is no target, the drag is cancelled.
+    fn drop_drag_and_drop(&mut self) {
+        let maybe_target = self.global_input.current.drop_target.take();
+        let is_dropped = match self.maybe_drag_and_drop {
+            Some(ref drag) if drag.is_dropped() => true,
+            Some(ref mut drag) => match maybe_target {
+                Some(idx) => {
+                    drag.set_dropped();
+                    let drop = event::Dropped {
+                        source: drag.source(),
+                        xy: self.global_input.current.mouse.xy,
+                        modifiers: self.global_input.current.modifiers,
+                    };
+                    self.global_input.push_event(event::Ui::Drop(idx, drop).into());
+                    true
+                },
+                None => false,
+            },
+            None => return,
+        };
+        if !is_dropped {
+            self.maybe_drag_and_drop = None;
+        }
     }
 
     /// Handle raw window events and update the `Ui` state accordingly.
@@ -466,6 +589,14 @@ impl Ui {
                     // If `Esc` was pressed, check to see if we need to cancel a `Drag` or
                     // uncapture a widget.
                     if let Key::Escape = key {
+                        // Cancel any drag-and-drop that has yet to be dropped.
+                        let is_dragging = self.maybe_drag_and_drop.as_ref()
+                            .map_or(false, |drag| !drag.is_dropped());
+                        if is_dragging {
+                            self.maybe_drag_and_drop = None;
+                            self.update_drop_target();
+                        }
+
                         // TODO:
                         // 1. Cancel `Drag` if currently under way.
                         // 2. If mouse is captured due to pinning widget with left mouse button,
@@ -574,6 +705,11 @@ impl Ui {
                         }
                     }
 
+                    // Drop the drag-and-drop payload onto the drop target, if there is one.
+                    if let MouseButton::Left = mouse_button {
+                        self.drop_drag_and_drop();
+                    }
+
                     // Release the given mouse_button from the input::State.
                     self.global_input.current.mouse.buttons.release(mouse_button);
                 },
@@ -660,6 +796,19 @@ impl Ui {
                         self.global_input.current.mouse.xy = mouse_xy;
 
                         self.track_widget_under_mouse_and_update_capturing();
+
+                        // Inform the drop target of the movement of the dragged payload.
+                        if let Some(idx) = self.global_input.current.drop_target {
+                            if let Some(ref drag) = self.maybe_drag_and_drop {
+                                let drag_over = event::DragOver {
+                                    source: drag.source(),
+                                    xy: mouse_xy,
+                                    modifiers: self.global_input.current.modifiers,
+                                };
+                                let event = event::Ui::DragOver(idx, drag_over).into();
+                                self.global_input.push_event(event);
+                            }
+                        }
                     },
 
                     // Some scrolling occurred (e.g. mouse scroll wheel).
@@ -839,14 +988,14 @@ impl Ui {
 
                     // Update the widget under the touch and return the widget capturing the touch.
                     let widget = match self.global_input.current.touch.get_mut(&touch.id) {
-                        Some(touch) => {
-                            touch.widget =
+                        Some(touch_state) => {
+                            touch_state.widget =
                                 graph::algo::pick_widgets(&self.depth_order.indices, touch.xy)
                                     .next(&self.widget_graph,
                                           &self.depth_order.indices,
                                           &self.theme);
-                            touch.xy = touch.xy;
-                            touch.start.widget
+                            touch_state.xy = touch.xy;
+                            touch_state.start.widget
                         },
                         None => None,
                     };
@@ -902,6 +1051,24 @@ impl Ui {
             Input::Focus(_focused) => (),
 
             Input::Redraw => self.needs_redraw(),
+
+            // Files dragged from other applications are delivered to the widget under the mouse.
+            Input::HoveredFile(path) => {
+                let file = event::File { path: path, xy: self.global_input.current.mouse.xy };
+                let widget = self.global_input.current.widget_under_mouse;
+                self.global_input.push_event(event::Ui::HoveredFile(widget, file).into());
+            },
+
+            Input::DroppedFile(path) => {
+                let file = event::File { path: path, xy: self.global_input.current.mouse.xy };
+                let widget = self.global_input.current.widget_under_mouse;
+                self.global_input.push_event(event::Ui::DroppedFile(widget, file).into());
+            },
+
+            Input::HoveredFileCancelled => {
+                let widget = self.global_input.current.widget_under_mouse;
+                self.global_input.push_event(event::Ui::HoveredFileCancelled(widget).into());
+            },
         }
     }
 
@@ -1059,6 +1226,23 @@ impl Ui {
             updated_widgets.clear();
         }
 
+        // If some widget's requested deadline has passed, ensure that the `Ui` is redrawn. The
+        // deadline is cleared either way, as widgets must request it again while they are set.
+        let now = instant::Instant::now();
+        if let Some(deadline) = self.maybe_redraw_deadline.take() {
+            if deadline <= now {
+                self.needs_redraw();
+            }
+        }
+
+        // Step all animations to the same moment in time for this frame.
+        self.animations.begin_frame(now);
+
+        // Widgets must accept the drag-and-drop payload again each time they are set.
+        if let Some(ref mut drag) = self.maybe_drag_and_drop {
+            drag.begin_update();
+        }
+
         let mut ui_cell = UiCell { ui: self };
 
         // Instantiate the root `Window` `Widget`.
@@ -1165,6 +1349,16 @@ impl Ui {
     }
 
 
+    /// The order in which widgets are drawn from bottom to top, as of the end of the last call to
+    /// `Ui::set_widgets`.
+    ///
+    /// This may be used along with `render::Primitives::new` to produce the primitives for some
+    /// subset of the widget graph.
+    pub fn depth_order(&self) -> &[widget::Id] {
+        &self.depth_order.indices
+    }
+
+
     /// The **Rect** that represents the maximum fully visible area for the widget with the given
     /// index, including consideration of cropped scroll area.
     ///
@@ -1177,6 +1371,45 @@ impl Ui {
     pub fn mouse_cursor(&self) -> cursor::MouseCursor {
         self.mouse_cursor
     }
+
+    /// The current value of the given property animated by the widget with the given `id`.
+    ///
+    /// Returns `None` if the widget did not request an animation of the property via
+    /// `UiCell::animate` during the last call to `set_widgets`.
+    pub fn animation_value(&self, id: widget::Id, property: &'static str) -> Option<Scalar> {
+        self.animations.value(id, property)
+    }
+
+    /// The earliest moment at which the `Ui` should next be updated via `set_widgets`, if any.
+    ///
+    /// This is the earliest deadline requested by widgets via `UiCell::redraw_at` during the last
+    /// call to `set_widgets`. While animations are in progress, this returns the moment at which
+    /// the last frame began, indicating that the `Ui` should be updated again immediately.
+    ///
+    /// Event loops may use this to sleep until there is more work to do, e.g. by passing it to
+    /// winit's `ControlFlow::WaitUntil`.
+    pub fn next_redraw_deadline(&self) -> Option<instant::Instant> {
+        if self.animations.is_active() {
+            return Some(self.animations.frame_time());
+        }
+        self.maybe_redraw_deadline
+    }
+
+    /// The payload currently being dragged from one widget onto another, if any.
+    ///
+    /// See the [**drag_and_drop**](../drag_and_drop/index.html) module for details.
+    pub fn drag_and_drop(&self) -> Option<&DragAndDrop> {
+        self.maybe_drag_and_drop.as_ref()
+    }
+
+    /// Whether or not any widget animation has yet to reach its target.
+    ///
+    /// While this is `true`, the `Ui` will request a redraw at the end of each `set_widgets` stage.
+    /// Applications that only update in response to events should continue to call `set_widgets`
+    /// until this returns `false`.
+    pub fn is_animating(&self) -> bool {
+        self.animations.is_active()
+    }
 }
 
 
@@ -1241,10 +1474,141 @@ impl<'a> UiCell<'a> {
         }
     }
 
+    /// Scroll all scrollable ancestors of the widget at the given index so that the widget
+    /// becomes fully visible, aligned within each ancestor's kid area by the given `alignment`.
+    ///
+    /// The produced `Scroll` events will be applied during the next call to `Ui::set_widgets`.
+    pub fn scroll_to_widget(&mut self, id: widget::Id, alignment: widget::scroll::Alignment) {
+        let scrolls = self.ui.scrolls_to_widget(id, alignment);
+        self.scroll_widgets(scrolls);
+    }
+
+    /// Scroll the widget at the given index along with all of its scrollable ancestors so that
+    /// the given `rect` (positioned within the widget's kid area) becomes fully visible.
+    ///
+    /// The produced `Scroll` events will be applied during the next call to `Ui::set_widgets`, so
+    /// a redraw is requested to ensure that the next update occurs.
+    pub fn scroll_to_rect(&mut self,
+                          id: widget::Id,
+                          rect: Rect,
+                          alignment: widget::scroll::Alignment)
+    {
+        let scrolls = widget::scroll::scroll_into_view(self, id, rect, alignment);
+        self.scroll_widgets(scrolls);
+    }
+
+    /// Scroll each of the given widgets by its offset.
+    ///
+    /// A redraw is requested to ensure that the `Scroll` events are applied during the next update.
+    fn scroll_widgets(&mut self, scrolls: Vec<(widget::Id, Point)>) {
+        if scrolls.is_empty() {
+            return;
+        }
+        for (id, offset) in scrolls {
+            self.scroll_widget(id, offset);
+        }
+        self.redraw_at(instant::Instant::now());
+    }
+
     /// Sets the mouse cursor
     pub fn set_mouse_cursor(&mut self, cursor: cursor::MouseCursor) {
         self.ui.mouse_cursor = cursor;
     }
+
+    /// Request that the `Ui` be updated and redrawn at the given moment in time.
+    ///
+    /// This allows widgets whose appearance changes over time (e.g. a blinking caret or a delayed
+    /// tooltip) to avoid requesting continuous redraws. The earliest requested deadline may be
+    /// retrieved via `Ui::next_redraw_deadline`. Deadlines only last until the next call to
+    /// `Ui::set_widgets`, so widgets should request them each time they are updated.
+    pub fn redraw_at(&mut self, deadline: instant::Instant) {
+        let earliest = match self.ui.maybe_redraw_deadline {
+            Some(current) if current <= deadline => current,
+            _ => deadline,
+        };
+        self.ui.maybe_redraw_deadline = Some(earliest);
+    }
+
+    /// Request that the `Ui` be updated and redrawn once the given `Duration` has elapsed.
+    ///
+    /// See `redraw_at` for details.
+    pub fn redraw_after(&mut self, duration: std::time::Duration) {
+        let deadline = instant::Instant::now() + duration;
+        self.redraw_at(deadline);
+    }
+
+    /// Animate the named `property` of the widget at the given `id` towards `target`.
+    ///
+    /// Returns the value for the current frame. The first time a widget requests a property, it
+    /// starts at rest at the `target`. Each property is animated independently, so a widget may
+    /// animate many values at once. See the [**animation**](../animation/index.html) module for
+    /// details.
+    pub fn animate(&mut self,
+                   id: widget::Id,
+                   property: &'static str,
+                   target: Scalar,
+                   transition: animation::Transition) -> Scalar
+    {
+        self.ui.animations.animate(id, property, target, transition)
+    }
+
+    /// Begin dragging the given `payload` from the `source` widget.
+    ///
+    /// This should be called while the left mouse button is down, normally upon the source
+    /// receiving a `Drag` event. The drag ends once the button is released, dropping the payload
+    /// onto the widget under the cursor that accepts it, if any. Has no effect if some other
+    /// payload is already being dragged.
+    ///
+    /// See the [**drag_and_drop**](../drag_and_drop/index.html) module for details.
+    pub fn start_drag<T>(&mut self, source: widget::Id, payload: T)
+        where T: std::any::Any + Send,
+    {
+        if self.ui.maybe_drag_and_drop.is_none() {
+            self.ui.maybe_drag_and_drop = Some(DragAndDrop::new(source, payload));
+        }
+    }
+
+    /// Indicate that the given widget accepts the payload that is currently being dragged.
+    ///
+    /// This must be called during each update in which the widget wishes to act as a drop target.
+    /// Widgets that accept the payload receive `DragEnter`, `DragOver`, `DragLeave` and `Drop`
+    /// events via their `input::Widget`.
+    pub fn accept_drop(&mut self, target: widget::Id) {
+        if let Some(ref mut drag) = self.ui.maybe_drag_and_drop {
+            drag.accept(target);
+        }
+    }
+
+    /// Specify the widget used to preview the payload that is currently being dragged.
+    ///
+    /// The preview is ignored when determining the drop target, so it may be freely positioned
+    /// under the cursor.
+    pub fn set_drag_preview(&mut self, preview: widget::Id) {
+        if let Some(ref mut drag) = self.ui.maybe_drag_and_drop {
+            drag.set_preview(preview);
+        }
+    }
+
+    /// Take the payload that is currently being dragged if it is of type `T`.
+    ///
+    /// This is normally called by the drop target upon receiving a `Drop` event.
+    pub fn take_drag_payload<T>(&mut self) -> Option<T>
+        where T: std::any::Any,
+    {
+        self.ui.maybe_drag_and_drop.as_mut().and_then(|drag| drag.take_payload())
+    }
+
+    /// Cancel the drag that is currently in progress, if any.
+    ///
+    /// The current drop target receives a `DragLeave` event during the next call to
+    /// `Ui::set_widgets`.
+    pub fn cancel_drag(&mut self) {
+        if self.ui.maybe_drag_and_drop.take().is_some() {
+            if let Some(idx) = self.ui.global_input.current.drop_target.take() {
+                self.ui.pending_drag_and_drop_events.push(event::Ui::DragLeave(idx));
+            }
+        }
+    }
 }
 
 impl<'a> Drop for UiCell<'a> {
@@ -1255,6 +1619,13 @@ impl<'a> Drop for UiCell<'a> {
             self.ui.needs_redraw();
         }
 
+        // Drop animations for widgets that were not updated and keep redrawing while any remain
+        // in progress.
+        self.ui.animations.end_frame();
+        if self.ui.animations.is_active() {
+            self.ui.needs_redraw();
+        }
+
         // Update the **DepthOrder** so that it reflects the **Graph**'s current state.
         {
             let Ui {
@@ -1271,9 +1642,17 @@ impl<'a> Drop for UiCell<'a> {
         // Reset the global input state. Note that this is the **only** time this should be called.
         self.ui.global_input.clear_events_and_update_start_state();
 
+        // The `Drop` event has now been delivered, so the dropped payload is no longer needed.
+        let is_dropped = self.ui.maybe_drag_and_drop.as_ref().map_or(false, |d| d.is_dropped());
+        if is_dropped {
+            self.ui.maybe_drag_and_drop = None;
+        }
+
         // Update which widget is under the cursor.
         if changed {
             self.ui.track_widget_under_mouse_and_update_capturing();
+        } else {
+            self.ui.update_drop_target();
         }
 
         // Move all pending `Scroll` events that have been produced since the start of this method
@@ -1281,6 +1660,11 @@ impl<'a> Drop for UiCell<'a> {
         for scroll_event in self.ui.pending_scroll_events.drain(0..) {
             self.ui.global_input.push_event(scroll_event.into());
         }
+
+        // Likewise for drag-and-drop events.
+        for drag_and_drop_event in self.ui.pending_drag_and_drop_events.drain(0..) {
+            self.ui.global_input.push_event(drag_and_drop_event.into());
+        }
     }
 }
 
@@ -323,14 +323,6 @@ impl<'a> Widget for Canvas<'a> {
         // If we were given some child canvas splits, we should instantiate them.
         if let Some((direction, splits)) = maybe_splits {
 
-            let (total_abs, total_weight) =
-                splits.iter().fold((0.0, 0.0), |(abs, weight), &(_, split)| {
-                    match split.style.length(ui.theme()) {
-                        Length::Absolute(a) => (abs + a, weight),
-                        Length::Weight(w) => (abs, weight + w),
-                    }
-                });
-
             // No need to calculate kid_area again, we'll just get it from the graph.
             let kid_area = ui.kid_area_of(id).expect("No KidArea found");
             let kid_area_range = match direction {
@@ -338,16 +330,7 @@ impl<'a> Widget for Canvas<'a> {
                 Direction::Y(_) => kid_area.y,
             };
 
-            let total_length = kid_area_range.len();
-            let non_abs_length = (total_length - total_abs).max(0.0);
-            let weight_normaliser = 1.0 / total_weight;
-
-            let length = |split: &Self, ui: &UiCell| -> Scalar {
-                match split.style.length(ui.theme()) {
-                    Length::Absolute(length) => length,
-                    Length::Weight(weight) => weight * weight_normaliser * non_abs_length,
-                }
-            };
+            let lengths = split_lengths(direction, splits, kid_area_range.len(), ui.theme());
 
             let set_split = |split_id: widget::Id, split: Canvas<'a>, ui: &mut UiCell| {
                 split.parent(id).set(split_id, ui);
@@ -358,7 +341,7 @@ impl<'a> Widget for Canvas<'a> {
 
                 Direction::X(direction) => match direction {
                     Forwards => for (i, &(split_id, split)) in splits.iter().enumerate() {
-                        let w = length(&split, &ui);
+                        let w = lengths[i];
                         let split = match i {
                             0 => split.h(kid_area.h()).mid_left_of(id),
                             _ => split.right(0.0),
@@ -366,7 +349,7 @@ impl<'a> Widget for Canvas<'a> {
                         set_split(split_id, split, &mut ui);
                     },
                     Backwards => for (i, &(split_id, split)) in splits.iter().enumerate() {
-                        let w = length(&split, &ui);
+                        let w = lengths[i];
                         let split = match i {
                             0 => split.h(kid_area.h()).mid_right_of(id),
                             _ => split.left(0.0),
@@ -377,7 +360,7 @@ impl<'a> Widget for Canvas<'a> {
 
                 Direction::Y(direction) => match direction {
                     Forwards => for (i, &(split_id, split)) in splits.iter().enumerate() {
-                        let h = length(&split, &ui);
+                        let h = lengths[i];
                         let split = match i {
                             0 => split.w(kid_area.w()).mid_bottom_of(id),
                             _ => split.up(0.0),
@@ -385,7 +368,7 @@ impl<'a> Widget for Canvas<'a> {
                         set_split(split_id, split, &mut ui);
                     },
                     Backwards => for (i, &(split_id, split)) in splits.iter().enumerate() {
-                        let h = length(&split, &ui);
+                        let h = lengths[i];
                         let split = match i {
                             0 => split.w(kid_area.w()).mid_top_of(id),
                             _ => split.down(0.0),
@@ -401,6 +384,67 @@ impl<'a> Widget for Canvas<'a> {
 }
 
 
+/// Determine the length of each split in the given flow.
+///
+/// `Absolute` lengths are clamped to the split's `DimensionLimits` along the flow axis. The
+/// remaining length is then distributed between the `Weight`ed splits. Whenever a weighted split's
+/// share would violate its limits, its length is fixed at the nearest limit and the rest of the
+/// length is redistributed between the remaining weighted splits.
+fn split_lengths(direction: Direction,
+                 splits: ListOfSplits,
+                 total_length: Scalar,
+                 theme: &Theme) -> Vec<Scalar>
+{
+    let limits = |split: &Canvas| match direction {
+        Direction::X(_) => split.get_x_dimension_limits(),
+        Direction::Y(_) => split.get_y_dimension_limits(),
+    };
+
+    // `None` for each weighted split whose length is yet to be determined.
+    let mut lengths: Vec<Option<Scalar>> = splits.iter()
+        .map(|&(_, split)| match split.style.length(theme) {
+            Length::Absolute(length) => Some(limits(&split).clamp(length)),
+            Length::Weight(_) => None,
+        })
+        .collect();
+
+    loop {
+        let (fixed_length, total_weight) = splits.iter().zip(&lengths)
+            .fold((0.0, 0.0), |(fixed, total_weight), (&(_, split), length)| {
+                match (*length, split.style.length(theme)) {
+                    (Some(length), _) => (fixed + length, total_weight),
+                    (None, Length::Weight(weight)) => (fixed, total_weight + weight),
+                    (None, Length::Absolute(_)) => unreachable!(),
+                }
+            });
+
+        let non_fixed_length = (total_length - fixed_length).max(0.0);
+        let weighted_length = |split: &Canvas| match split.style.length(theme) {
+            Length::Weight(weight) if total_weight > 0.0 =>
+                weight / total_weight * non_fixed_length,
+            _ => 0.0,
+        };
+
+        // Fix the length of every weighted split whose share falls outside of its limits.
+        let mut is_resolved = true;
+        for (&(_, split), length) in splits.iter().zip(lengths.iter_mut()) {
+            if length.is_none() {
+                let weighted = weighted_length(&split);
+                if !limits(&split).contains(weighted) {
+                    *length = Some(limits(&split).clamp(weighted));
+                    is_resolved = false;
+                }
+            }
+        }
+
+        if is_resolved {
+            return splits.iter().zip(lengths)
+                .map(|(&(_, split), length)| length.unwrap_or_else(|| weighted_length(&split)))
+                .collect();
+        }
+    }
+}
+
 /// The height and relative y coordinate of a Canvas' title bar given some canvas height and font
 /// size for the title bar.
 fn title_bar_h_rel_y(canvas_h: Scalar, font_size: FontSize) -> (Scalar, Scalar) {
@@ -0,0 +1,227 @@
+//! Types and functions for describing the axes of a `Chart` and the ticks along them.
+
+use Scalar;
+
+/// Describes one of the two axes of a `Chart`.
+#[derive(Copy, Clone, Debug)]
+pub struct Axis<'a> {
+    /// The range of values visible along the axis.
+    ///
+    /// If `None`, the range is fitted to the data of every series and rounded out to the nearest
+    /// ticks.
+    pub maybe_range: Option<(Scalar, Scalar)>,
+    /// A title displayed alongside the axis.
+    pub maybe_title: Option<&'a str>,
+    /// The scale by which values are distributed along the axis.
+    pub scale: Scale,
+    /// The number of ticks that the axis should aim to display.
+    ///
+    /// The actual number of ticks may differ so that each falls on a "nice" value.
+    pub tick_count: usize,
+    /// Produces the label for each tick from its value and the distance between ticks.
+    ///
+    /// If `None`, `format_tick` is used.
+    pub maybe_format: Option<fn(Scalar, Scalar) -> String>,
+}
+
+/// The scale by which values are distributed along an `Axis`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Scale {
+    /// Values are distributed evenly.
+    Linear,
+    /// Each power of ten occupies the same distance.
+    ///
+    /// Values that are less than or equal to zero cannot be displayed.
+    Log,
+}
+
+/// The default number of ticks that an axis aims to display.
+pub const DEFAULT_TICK_COUNT: usize = 6;
+
+impl<'a> Axis<'a> {
+
+    /// Begin building a linear axis whose range is fitted to the data.
+    pub fn new() -> Self {
+        Axis {
+            maybe_range: None,
+            maybe_title: None,
+            scale: Scale::Linear,
+            tick_count: DEFAULT_TICK_COUNT,
+            maybe_format: None,
+        }
+    }
+
+    /// Specify the range of values visible along the axis.
+    pub fn range(mut self, min: Scalar, max: Scalar) -> Self {
+        self.maybe_range = Some((min, max));
+        self
+    }
+
+    /// Specify a title to display alongside the axis.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.maybe_title = Some(title);
+        self
+    }
+
+    /// Distribute values along the axis logarithmically.
+    pub fn log(mut self) -> Self {
+        self.scale = Scale::Log;
+        self
+    }
+
+    /// Specify the number of ticks that the axis should aim to display.
+    pub fn tick_count(mut self, count: usize) -> Self {
+        self.tick_count = count;
+        self
+    }
+
+    /// Specify a function for producing the label of each tick from its value and the distance
+    /// between ticks.
+    pub fn format(mut self, format: fn(Scalar, Scalar) -> String) -> Self {
+        self.maybe_format = Some(format);
+        self
+    }
+
+    /// Format the given value for display within a readout, e.g. while hovering a `Chart`.
+    ///
+    /// Values are displayed with one more decimal place than the ticks across the given range.
+    pub fn format_value(&self, value: Scalar, min: Scalar, max: Scalar) -> String {
+        let format = self.maybe_format.unwrap_or(format_tick);
+        let step = match self.scale {
+            Scale::Linear => nice_step(max - min, self.tick_count),
+            Scale::Log => value,
+        };
+        format(value, step / 10.0)
+    }
+
+    /// The values of the ticks within the given range, along with their labels.
+    pub fn ticks(&self, min: Scalar, max: Scalar) -> Vec<(Scalar, String)> {
+        let format = self.maybe_format.unwrap_or(format_tick);
+        match self.scale {
+            Scale::Linear => {
+                let step = nice_step(max - min, self.tick_count);
+                nice_ticks(min, max, self.tick_count)
+                    .into_iter()
+                    .map(|value| (value, format(value, step)))
+                    .collect()
+            },
+            Scale::Log => {
+                log_ticks(min, max, self.tick_count)
+                    .into_iter()
+                    .map(|value| (value, format(value, value)))
+                    .collect()
+            },
+        }
+    }
+
+}
+
+impl Scale {
+
+    /// Transform the given value into the space in which the scale is linear.
+    ///
+    /// Returns `None` if the value cannot be displayed on this scale.
+    pub fn transform(&self, value: Scalar) -> Option<Scalar> {
+        match *self {
+            Scale::Linear => Some(value),
+            Scale::Log if value > 0.0 => Some(value.log10()),
+            Scale::Log => None,
+        }
+    }
+
+    /// The inverse of `transform`.
+    pub fn untransform(&self, value: Scalar) -> Scalar {
+        match *self {
+            Scale::Linear => value,
+            Scale::Log => (10.0 as Scalar).powf(value),
+        }
+    }
+
+}
+
+/// Round the given distance to a "nice" value, i.e. 1, 2, 5 or 10 multiplied by a power of ten.
+///
+/// If `round` is `false`, the smallest nice value greater than or equal to `distance` is returned.
+pub fn nice_num(distance: Scalar, round: bool) -> Scalar {
+    if !(distance > 0.0) || !distance.is_finite() {
+        return 1.0;
+    }
+    let exponent = distance.log10().floor();
+    let magnitude = (10.0 as Scalar).powf(exponent);
+    let fraction = distance / magnitude;
+    let nice_fraction = match round {
+        true if fraction < 1.5 => 1.0,
+        true if fraction < 3.0 => 2.0,
+        true if fraction < 7.0 => 5.0,
+        false if fraction <= 1.0 => 1.0,
+        false if fraction <= 2.0 => 2.0,
+        false if fraction <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+    nice_fraction * magnitude
+}
+
+/// The "nice" distance between roughly `tick_count` ticks spread across the given distance.
+pub fn nice_step(distance: Scalar, tick_count: usize) -> Scalar {
+    let intervals = if tick_count > 1 { tick_count - 1 } else { 1 };
+    nice_num(distance.abs() / intervals as Scalar, true)
+}
+
+/// Roughly `tick_count` ticks at multiples of a "nice" step between `min` and `max` inclusive.
+pub fn nice_ticks(min: Scalar, max: Scalar, tick_count: usize) -> Vec<Scalar> {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let step = nice_step(max - min, tick_count);
+    // Allow for some floating point error at either end of the range.
+    let epsilon = step * 1e-9;
+    let first = ((min - epsilon) / step).ceil() as i64;
+    let last = ((max + epsilon) / step).floor() as i64;
+    (first..last + 1)
+        .map(|i| {
+            let value = i as Scalar * step;
+            if value.abs() < epsilon { 0.0 } else { value }
+        })
+        .collect()
+}
+
+/// Ticks at powers of ten between `min` and `max` inclusive for a logarithmic axis.
+///
+/// Every few powers of ten are skipped so that there are roughly at most `tick_count` ticks.
+/// Ranges that span less than a power of ten fall back to `nice_ticks`.
+pub fn log_ticks(min: Scalar, max: Scalar, tick_count: usize) -> Vec<Scalar> {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    if !(min > 0.0) {
+        return Vec::new();
+    }
+    let first = (min.log10() - 1e-9).ceil() as i32;
+    let last = (max.log10() + 1e-9).floor() as i32;
+    if last < first {
+        return nice_ticks(min, max, tick_count);
+    }
+    let n_powers = (last - first + 1) as usize;
+    let skip = (n_powers + tick_count.max(1) - 1) / tick_count.max(1);
+    (first..last + 1)
+        .filter(|exponent| (exponent - first) as usize % skip.max(1) == 0)
+        .map(|exponent| (10.0 as Scalar).powi(exponent))
+        .collect()
+}
+
+/// The default formatting for tick labels.
+///
+/// Values are displayed with enough decimal places to distinguish ticks that are `step` apart.
+/// Very large or very small values are displayed in scientific notation.
+pub fn format_tick(value: Scalar, step: Scalar) -> String {
+    let abs = value.abs();
+    if abs == 0.0 {
+        return "0".to_string();
+    }
+    if abs >= 1e6 || abs < 1e-4 {
+        return format!("{:e}", value);
+    }
+    let step = step.abs();
+    let decimals = if step > 0.0 && step < 1.0 {
+        (-step.log10().floor()) as usize
+    } else {
+        0
+    };
+    format!("{:.*}", decimals, value)
+}
@@ -0,0 +1,1022 @@
+//! A widget for plotting one or more series of data against a pair of labelled axes.
+
+use {Color, Colorable, Borderable, FontSize, Point, Positionable, Rect, Scalar, Sizeable, UiCell,
+     Widget};
+use color;
+use event;
+use input;
+use input::keyboard::ModifierKey;
+use std::cmp::Ordering;
+use text;
+use utils::map_range;
+use widget;
+use widget::triangles::Triangle;
+
+pub use self::axis::{Axis, Scale};
+
+pub mod axis;
+
+
+/// Plots one or more series of data as lines, scattered points, filled areas or bars.
+///
+/// Each axis is labelled with ticks at "nice" values along with an optional title, and a legend
+/// describing each of the series may be displayed in the top right corner of the plot.
+///
+/// Unlike the `PlotPath`, which samples a function, the **Chart** plots the given data points.
+///
+/// The view may be zoomed around the mouse with the scroll wheel, zoomed to a box by dragging
+/// while holding shift, panned by dragging and reset by double-clicking. While hovering the plot,
+/// a crosshair and readout display the values of the nearest data point.
+#[derive(WidgetCommon_)]
+pub struct Chart<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    style: Style,
+    series: &'a [Series<'a>],
+    x_axis: Axis<'a>,
+    y_axis: Axis<'a>,
+    show_legend: bool,
+    should_reset_view: bool,
+}
+
+/// The range of values visible along each axis of a `Chart`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct View {
+    /// The minimum and maximum visible values along the *x* axis.
+    pub x: (Scalar, Scalar),
+    /// The minimum and maximum visible values along the *y* axis.
+    pub y: (Scalar, Scalar),
+}
+
+/// A series of data points plotted by a `Chart`.
+#[derive(Copy, Clone, Debug)]
+pub struct Series<'a> {
+    /// The name of the series displayed within the legend.
+    pub label: &'a str,
+    /// The *x* and *y* value of each data point.
+    ///
+    /// `Line` and `Area` series join the points in the order given.
+    pub points: &'a [Point],
+    /// How the series is drawn.
+    pub kind: Kind,
+    /// The color of the series.
+    ///
+    /// If `None`, a color is chosen from the `PALETTE` in accordance with the series' index.
+    pub maybe_color: Option<Color>,
+}
+
+/// The different ways in which a `Series` may be drawn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A line joining each point.
+    Line,
+    /// A dot at each point.
+    Scatter,
+    /// A line joining each point with the area between it and the *x* axis filled.
+    Area,
+    /// A bar from the *x* axis to each point.
+    ///
+    /// Bars from multiple series are placed side by side.
+    Bar,
+}
+
+/// Unique styling for the **Chart** widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the area behind the plotted data.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The width of the border around the plotted data.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of the border around the plotted data.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the axis lines and ticks.
+    #[conrod(default = "theme.label_color")]
+    pub axis_color: Option<Color>,
+    /// The color of the grid lines drawn at each tick across the plotted data.
+    #[conrod(default = "theme.label_color.alpha(0.15)")]
+    pub grid_color: Option<Color>,
+    /// The color of the tick labels, axis titles and legend text.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the tick labels and legend text.
+    #[conrod(default = "theme.font_size_small")]
+    pub label_font_size: Option<FontSize>,
+    /// The font size of the axis titles.
+    #[conrod(default = "theme.font_size_medium")]
+    pub title_font_size: Option<FontSize>,
+    /// The length of the tick marks along each axis.
+    #[conrod(default = "4.0")]
+    pub tick_length: Option<Scalar>,
+    /// The thickness of the lines of `Line` and `Area` series.
+    #[conrod(default = "1.5")]
+    pub line_thickness: Option<Scalar>,
+    /// The radius of the dots of `Scatter` series.
+    #[conrod(default = "3.0")]
+    pub point_radius: Option<Scalar>,
+    /// The ID of the font used for all text.
+    #[conrod(default = "theme.font_id")]
+    pub font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        plot_area,
+        grid_lines[],
+        x_axis_line,
+        y_axis_line,
+        ticks[],
+        tick_labels[],
+        x_title,
+        y_title,
+        series_fills[],
+        series_lines[],
+        legend,
+        legend_swatches[],
+        legend_labels[],
+        zoom_box,
+        crosshair_x,
+        crosshair_y,
+        readout,
+    }
+}
+
+/// Unique state for the **Chart** retained between updates.
+pub struct State {
+    // The view if it has been zoomed or panned away from the axes' ranges.
+    maybe_view: Option<View>,
+    // The corners of the box being dragged out to zoom to, relative to the widget.
+    maybe_zoom_box: Option<(Point, Point)>,
+    ids: Ids,
+}
+
+/// The colors assigned to series that do not specify their own, in order of the series' index.
+pub const PALETTE: [Color; 6] = [
+    color::LIGHT_BLUE,
+    color::LIGHT_ORANGE,
+    color::LIGHT_GREEN,
+    color::LIGHT_RED,
+    color::LIGHT_PURPLE,
+    color::LIGHT_YELLOW,
+];
+
+/// The fraction of the distance between neighbouring bars occupied by each group of bars.
+pub const BAR_FILL: Scalar = 0.8;
+
+/// The rate at which the view zooms per unit of scroll.
+pub const ZOOM_PER_SCROLL: Scalar = 0.01;
+
+/// The minimum width and height of a box that may be zoomed to.
+pub const MIN_ZOOM_BOX: Scalar = 4.0;
+
+// The number of edges used to draw each dot of a `Scatter` series.
+const DOT_RESOLUTION: usize = 8;
+
+// The padding between the plot, its labels and the legend.
+const PAD: Scalar = 6.0;
+
+
+impl<'a> Series<'a> {
+
+    /// A series drawn in the given way.
+    pub fn new(label: &'a str, points: &'a [Point], kind: Kind) -> Self {
+        Series {
+            label: label,
+            points: points,
+            kind: kind,
+            maybe_color: None,
+        }
+    }
+
+    /// A series drawn as a line joining each point.
+    pub fn line(label: &'a str, points: &'a [Point]) -> Self {
+        Series::new(label, points, Kind::Line)
+    }
+
+    /// A series drawn as a dot at each point.
+    pub fn scatter(label: &'a str, points: &'a [Point]) -> Self {
+        Series::new(label, points, Kind::Scatter)
+    }
+
+    /// A series drawn as a line joining each point with the area beneath it filled.
+    pub fn area(label: &'a str, points: &'a [Point]) -> Self {
+        Series::new(label, points, Kind::Area)
+    }
+
+    /// A series drawn as a bar from the *x* axis to each point.
+    pub fn bar(label: &'a str, points: &'a [Point]) -> Self {
+        Series::new(label, points, Kind::Bar)
+    }
+
+    /// Specify the color of the series.
+    pub fn color(mut self, color: Color) -> Self {
+        self.maybe_color = Some(color);
+        self
+    }
+
+    /// The color of the series at the given index within a **Chart**.
+    pub fn color_at(&self, index: usize) -> Color {
+        self.maybe_color.unwrap_or(PALETTE[index % PALETTE.len()])
+    }
+
+}
+
+impl<'a> Chart<'a> {
+
+    /// Begin building a **Chart** plotting the given series.
+    ///
+    /// Both axes are linear and fitted to the data by default.
+    pub fn new(series: &'a [Series<'a>]) -> Self {
+        Chart {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            series: series,
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            show_legend: true,
+            should_reset_view: false,
+        }
+    }
+
+    /// Discard any zooming or panning so that each axis displays its own range again.
+    pub fn reset_view(mut self) -> Self {
+        self.should_reset_view = true;
+        self
+    }
+
+    /// Specify the font used for all text.
+    pub fn font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods!{
+        pub x_axis { x_axis = Axis<'a> }
+        pub y_axis { y_axis = Axis<'a> }
+        pub legend { show_legend = bool }
+        pub axis_color { style.axis_color = Some(Color) }
+        pub grid_color { style.grid_color = Some(Color) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+        pub title_font_size { style.title_font_size = Some(FontSize) }
+        pub tick_length { style.tick_length = Some(Scalar) }
+        pub line_thickness { style.line_thickness = Some(Scalar) }
+        pub point_radius { style.point_radius = Some(Scalar) }
+    }
+
+}
+
+impl<'a> Widget for Chart<'a> {
+    type State = State;
+    type Style = Style;
+    /// The new `View` if it was zoomed, panned or reset during this update.
+    type Event = Option<View>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            maybe_view: None,
+            maybe_zoom_box: None,
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Chart.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, rect, style, ui, .. } = args;
+        let Chart { series, x_axis, y_axis, show_legend, should_reset_view, .. } = self;
+
+        // The range of values visible along each axis unless the view is zoomed or panned.
+        let default_view = View {
+            x: x_range(series, &x_axis),
+            y: y_range(series, &y_axis),
+        };
+        let view = match state.maybe_view {
+            Some(view) if !should_reset_view => view,
+            _ => default_view,
+        };
+        let plot = match layout(rect, view, &x_axis, &y_axis, &style, ui).plot {
+            Some(plot) => plot,
+            None => return None,
+        };
+        let mapping_for = |view: View| Mapping::new(view, x_axis.scale, y_axis.scale, plot);
+
+        // Zoom and pan the view in response to the mouse.
+        let mut new_view = view;
+        let mut is_reset = should_reset_view && state.maybe_view.is_some();
+        let mut maybe_zoom_box = state.maybe_zoom_box;
+        for widget_event in ui.widget_input(id).events() {
+            let maybe_view = match widget_event {
+
+                // Dragging with shift held drags out a box to zoom to, otherwise the view pans.
+                event::Widget::Drag(drag) if drag.button == input::MouseButton::Left => {
+                    match maybe_zoom_box {
+                        Some((start, _)) => maybe_zoom_box = Some((start, drag.to)),
+                        None if drag.modifiers.contains(ModifierKey::SHIFT) =>
+                            maybe_zoom_box = Some((drag.origin, drag.to)),
+                        None => {
+                            let panned = plot.shift([-drag.delta_xy[0], -drag.delta_xy[1]]);
+                            new_view = mapping_for(new_view).view_of(panned);
+                        },
+                    }
+                    None
+                },
+
+                event::Widget::Release(release) => match release.button {
+                    event::Button::Mouse(input::MouseButton::Left, _) => {
+                        maybe_zoom_box.take().and_then(|(a, b)| {
+                            let zoom_box = Rect::from_corners(a, b).shift(rect.xy());
+                            let is_large_enough = zoom_box.w() >= MIN_ZOOM_BOX
+                                && zoom_box.h() >= MIN_ZOOM_BOX;
+                            match is_large_enough {
+                                true => Some(mapping_for(new_view).view_of(zoom_box)),
+                                false => None,
+                            }
+                        })
+                    },
+                    _ => None,
+                },
+
+                // Zoom around the mouse.
+                event::Widget::Scroll(scroll) => {
+                    ui.widget_input(id).mouse().map(|mouse| {
+                        let factor = (scroll.y * ZOOM_PER_SCROLL).exp();
+                        let zoomed = scale_rect(plot, mouse.abs_xy(), factor);
+                        mapping_for(new_view).view_of(zoomed)
+                    })
+                },
+
+                event::Widget::DoubleClick(click) if click.button == input::MouseButton::Left => {
+                    is_reset = true;
+                    maybe_zoom_box = None;
+                    Some(default_view)
+                },
+
+                _ => None,
+            };
+
+            // Ignore views that have collapsed or overflowed.
+            if let Some(view) = maybe_view {
+                if is_valid(view, x_axis.scale, y_axis.scale) {
+                    new_view = view;
+                }
+            }
+        }
+
+        let is_view_changed = new_view != view;
+        let maybe_view = if is_reset && new_view == default_view {
+            None
+        } else if is_view_changed {
+            Some(new_view)
+        } else if should_reset_view {
+            None
+        } else {
+            state.maybe_view
+        };
+        if state.maybe_view != maybe_view {
+            state.update(|state| state.maybe_view = maybe_view);
+        }
+        if state.maybe_zoom_box != maybe_zoom_box {
+            state.update(|state| state.maybe_zoom_box = maybe_zoom_box);
+        }
+        let event = if is_view_changed || is_reset { Some(new_view) } else { None };
+
+        // Lay out the plot for the updated view.
+        let view = new_view;
+        let Layout { x_ticks, y_ticks, x_label_ws, y_label_ws, plot } =
+            layout(rect, view, &x_axis, &y_axis, &style, ui);
+        let plot = match plot {
+            Some(plot) => plot,
+            None => return event,
+        };
+
+        let label_font_size = style.label_font_size(&ui.theme);
+        let title_font_size = style.title_font_size(&ui.theme);
+        let tick_length = style.tick_length(&ui.theme);
+        let maybe_font_id = style.font_id(&ui.theme).or(ui.fonts.ids().next());
+        let label_h = label_font_size as Scalar;
+        let title_h = title_font_size as Scalar;
+
+        let border = style.border(&ui.theme);
+        widget::BorderedRectangle::new(plot.dim())
+            .xy(plot.xy())
+            .color(style.color(&ui.theme))
+            .border(border)
+            .border_color(style.border_color(&ui.theme))
+            .crop_kids()
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.plot_area, ui);
+
+        let mapping = Mapping::new(view, x_axis.scale, y_axis.scale, plot);
+
+        // Grid lines across the plot at each tick.
+        let n_grid_lines = x_ticks.len() + y_ticks.len();
+        if state.ids.grid_lines.len() < n_grid_lines {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| state.ids.grid_lines.resize(n_grid_lines, id_gen));
+        }
+        let grid_color = style.grid_color(&ui.theme);
+        let grid_lines = x_ticks.iter()
+            .filter_map(|&(x, _)| mapping.map_x(x))
+            .map(|x| ([x, plot.bottom()], [x, plot.top()]))
+            .chain(y_ticks.iter()
+                .filter_map(|&(y, _)| mapping.map_y(y))
+                .map(|y| ([plot.left(), y], [plot.right(), y])));
+        for ((start, end), &line_id) in grid_lines.zip(state.ids.grid_lines.iter()) {
+            widget::Line::abs(start, end)
+                .color(grid_color)
+                .thickness(1.0)
+                .graphics_for(id)
+                .parent(state.ids.plot_area)
+                .set(line_id, ui);
+        }
+
+        // The series themselves.
+        let n_series = series.len();
+        if state.ids.series_lines.len() < n_series {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.series_fills.resize(n_series, id_gen);
+                state.ids.series_lines.resize(n_series, id_gen);
+            });
+        }
+        let line_thickness = style.line_thickness(&ui.theme);
+        let point_radius = style.point_radius(&ui.theme);
+        let n_bar_series = series.iter().filter(|s| s.kind == Kind::Bar).count();
+        let bar_slot_w = bar_slot(series, x_axis.scale) * plot.w() / mapping.x_span();
+        let bar_w = bar_slot_w * BAR_FILL / n_bar_series.max(1) as Scalar;
+        let baseline = mapping.baseline();
+        let mut bar_index = 0;
+        for (i, s) in series.iter().enumerate() {
+            let color = s.color_at(i);
+            let fill_id = state.ids.series_fills[i];
+            let line_id = state.ids.series_lines[i];
+            let points: Vec<Point> = s.points.iter()
+                .filter_map(|&p| mapping.map_point(p))
+                .collect();
+            match s.kind {
+                Kind::Line | Kind::Area => {
+                    if s.kind == Kind::Area {
+                        let triangles = area_triangles(&points, baseline);
+                        widget::Triangles::single_color(color.alpha(0.35), triangles)
+                            .with_bounding_rect(plot)
+                            .graphics_for(id)
+                            .parent(state.ids.plot_area)
+                            .set(fill_id, ui);
+                    }
+                    widget::PointPath::new(points)
+                        .wh(plot.dim())
+                        .xy(plot.xy())
+                        .color(color)
+                        .thickness(line_thickness)
+                        .graphics_for(id)
+                        .parent(state.ids.plot_area)
+                        .set(line_id, ui);
+                },
+                Kind::Scatter => {
+                    let triangles = points.iter()
+                        .flat_map(|&p| dot_triangles(p, point_radius))
+                        .collect::<Vec<_>>();
+                    widget::Triangles::single_color(color, triangles)
+                        .with_bounding_rect(plot)
+                        .graphics_for(id)
+                        .parent(state.ids.plot_area)
+                        .set(fill_id, ui);
+                },
+                Kind::Bar => {
+                    let centre_index = (n_bar_series - 1) as Scalar / 2.0;
+                    let offset = (bar_index as Scalar - centre_index) * bar_w;
+                    let triangles = points.iter()
+                        .flat_map(|&p| {
+                            let x = p[0] + offset;
+                            let bar = Rect::from_corners([x - bar_w / 2.0, baseline],
+                                                         [x + bar_w / 2.0, p[1]]);
+                            let (a, b) = widget::rectangle::triangles(bar);
+                            vec![a, b]
+                        })
+                        .collect::<Vec<_>>();
+                    widget::Triangles::single_color(color, triangles)
+                        .with_bounding_rect(plot)
+                        .graphics_for(id)
+                        .parent(state.ids.plot_area)
+                        .set(fill_id, ui);
+                    bar_index += 1;
+                },
+            }
+        }
+
+        // The axis lines along the bottom and left of the plot.
+        let axis_color = style.axis_color(&ui.theme);
+        widget::Line::abs(plot.bottom_left(), plot.bottom_right())
+            .color(axis_color)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.x_axis_line, ui);
+        widget::Line::abs(plot.bottom_left(), plot.top_left())
+            .color(axis_color)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.y_axis_line, ui);
+
+        // The tick marks and their labels.
+        let n_ticks = x_ticks.len() + y_ticks.len();
+        if state.ids.ticks.len() < n_ticks {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.ticks.resize(n_ticks, id_gen);
+                state.ids.tick_labels.resize(n_ticks, id_gen);
+            });
+        }
+        let label_color = style.label_color(&ui.theme);
+        let x_tick_labels = x_ticks.iter().zip(x_label_ws.iter()).filter_map(|(tick, &w)| {
+            mapping.map_x(tick.0).map(|x| {
+                let mark = ([x, plot.bottom()], [x, plot.bottom() - tick_length]);
+                let label_xy = [x, plot.bottom() - tick_length - PAD - label_h / 2.0];
+                (mark, &tick.1[..], label_xy, w)
+            })
+        });
+        let y_tick_labels = y_ticks.iter().zip(y_label_ws.iter()).filter_map(|(tick, &w)| {
+            mapping.map_y(tick.0).map(|y| {
+                let mark = ([plot.left(), y], [plot.left() - tick_length, y]);
+                let label_xy = [plot.left() - tick_length - PAD - w / 2.0, y];
+                (mark, &tick.1[..], label_xy, w)
+            })
+        });
+        let tick_ids = state.ids.ticks.iter().zip(state.ids.tick_labels.iter());
+        for ((mark, label, label_xy, _), (&tick_id, &label_id)) in
+            x_tick_labels.chain(y_tick_labels).zip(tick_ids)
+        {
+            widget::Line::abs(mark.0, mark.1)
+                .color(axis_color)
+                .graphics_for(id)
+                .parent(id)
+                .set(tick_id, ui);
+            if let Some(font_id) = maybe_font_id {
+                widget::Text::new(label)
+                    .font_id(font_id)
+                    .font_size(label_font_size)
+                    .color(label_color)
+                    .xy(label_xy)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(label_id, ui);
+            }
+        }
+
+        // The axis titles, beneath the *x* axis and above the *y* axis.
+        if let Some(font_id) = maybe_font_id {
+            if let Some(title) = x_axis.maybe_title {
+                widget::Text::new(title)
+                    .font_id(font_id)
+                    .font_size(title_font_size)
+                    .color(label_color)
+                    .x_y(plot.x(), rect.bottom() + title_h / 2.0)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.x_title, ui);
+            }
+            if let Some(title) = y_axis.maybe_title {
+                widget::Text::new(title)
+                    .font_id(font_id)
+                    .font_size(title_font_size)
+                    .color(label_color)
+                    .x_y(plot.left(), rect.top() - title_h / 2.0)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.y_title, ui);
+            }
+        }
+
+        // The box being dragged out to zoom to.
+        if let Some((a, b)) = maybe_zoom_box {
+            let zoom_box = Rect::from_corners(a, b).shift(rect.xy());
+            widget::Rectangle::outline(zoom_box.dim())
+                .xy(zoom_box.xy())
+                .color(axis_color)
+                .graphics_for(id)
+                .parent(state.ids.plot_area)
+                .set(state.ids.zoom_box, ui);
+        }
+
+        // A crosshair over the data point nearest to the mouse along with a readout of its values.
+        let maybe_nearest = ui.widget_input(id).mouse()
+            .map(|mouse| mouse.abs_xy())
+            .and_then(|xy| if plot.is_over(xy) { Some(xy) } else { None })
+            .and_then(|xy| nearest_point(series, &mapping, xy));
+        if let (Some((i, point, xy)), None) = (maybe_nearest, maybe_zoom_box) {
+            let crosshair_color = axis_color.alpha(0.5);
+            widget::Line::abs([xy[0], plot.bottom()], [xy[0], plot.top()])
+                .color(crosshair_color)
+                .graphics_for(id)
+                .parent(state.ids.plot_area)
+                .set(state.ids.crosshair_x, ui);
+            widget::Line::abs([plot.left(), xy[1]], [plot.right(), xy[1]])
+                .color(crosshair_color)
+                .graphics_for(id)
+                .parent(state.ids.plot_area)
+                .set(state.ids.crosshair_y, ui);
+            if let Some(font_id) = maybe_font_id {
+                let readout = format!("{}: {}, {}",
+                                      series[i].label,
+                                      x_axis.format_value(point[0], view.x.0, view.x.1),
+                                      y_axis.format_value(point[1], view.y.0, view.y.1));
+                let readout_w = text_width(ui, font_id, label_font_size, &readout);
+                widget::Text::new(&readout)
+                    .font_id(font_id)
+                    .font_size(label_font_size)
+                    .color(label_color)
+                    .x_y(plot.left() + PAD + readout_w / 2.0, plot.top() - PAD - label_h / 2.0)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.readout, ui);
+            }
+        }
+
+        // The legend within the top right corner of the plot.
+        let font_id = match maybe_font_id {
+            Some(font_id) if show_legend && n_series > 0 => font_id,
+            _ => return event,
+        };
+        let legend_label_ws: Vec<Scalar> = series.iter()
+            .map(|s| text_width(ui, font_id, label_font_size, s.label))
+            .collect();
+        let max_label_w = legend_label_ws.iter().fold(0.0, |max: Scalar, &w| max.max(w));
+        let legend_w = PAD * 3.0 + label_h + max_label_w;
+        let legend_h = PAD + n_series as Scalar * (label_h + PAD);
+
+        // The legend is omitted if it would not fit within the plot.
+        if legend_w > plot.w() - PAD * 2.0 || legend_h > plot.h() - PAD * 2.0 {
+            return event;
+        }
+
+        if state.ids.legend_labels.len() < n_series {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.legend_swatches.resize(n_series, id_gen);
+                state.ids.legend_labels.resize(n_series, id_gen);
+            });
+        }
+        let legend_top_right = [plot.right() - PAD, plot.top() - PAD];
+        let legend_bottom_left = [legend_top_right[0] - legend_w, legend_top_right[1] - legend_h];
+        let legend = Rect::from_corners(legend_bottom_left, legend_top_right);
+        widget::BorderedRectangle::new(legend.dim())
+            .xy(legend.xy())
+            .color(style.color(&ui.theme))
+            .border(border)
+            .border_color(style.border_color(&ui.theme))
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.legend, ui);
+        for (i, s) in series.iter().enumerate() {
+            let y = legend.top() - PAD - label_h / 2.0 - i as Scalar * (label_h + PAD);
+            let swatch_x = legend.left() + PAD + label_h / 2.0;
+            widget::Rectangle::fill([label_h, label_h])
+                .x_y(swatch_x, y)
+                .color(s.color_at(i))
+                .graphics_for(id)
+                .parent(state.ids.legend)
+                .set(state.ids.legend_swatches[i], ui);
+            let label_x = swatch_x + label_h / 2.0 + PAD + legend_label_ws[i] / 2.0;
+            widget::Text::new(s.label)
+                .font_id(font_id)
+                .font_size(label_font_size)
+                .color(label_color)
+                .x_y(label_x, y)
+                .graphics_for(id)
+                .parent(state.ids.legend)
+                .set(state.ids.legend_labels[i], ui);
+        }
+
+        event
+    }
+
+}
+
+impl<'a> Colorable for Chart<'a> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl<'a> Borderable for Chart<'a> {
+    builder_methods!{
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
+
+
+// The ticks along each axis and the area over which data is plotted, laid out for some view.
+struct Layout {
+    x_ticks: Vec<(Scalar, String)>,
+    y_ticks: Vec<(Scalar, String)>,
+    x_label_ws: Vec<Scalar>,
+    y_label_ws: Vec<Scalar>,
+    // `None` if the chart is too small for the ticks, their labels and the axis titles.
+    plot: Option<Rect>,
+}
+
+// Lay out the ticks and plot area of the given view within the given `rect`.
+//
+// Room is left around the plot for the ticks, their labels and the axis titles.
+fn layout(rect: Rect, view: View, x_axis: &Axis, y_axis: &Axis, style: &Style, ui: &UiCell)
+    -> Layout
+{
+    let x_ticks = x_axis.ticks(view.x.0, view.x.1);
+    let y_ticks = y_axis.ticks(view.y.0, view.y.1);
+
+    // Text is only measured if there is a font with which it may be drawn.
+    let label_font_size = style.label_font_size(&ui.theme);
+    let maybe_font_id = style.font_id(&ui.theme).or(ui.fonts.ids().next());
+    let width = |s: &str| match maybe_font_id {
+        Some(font_id) => text_width(ui, font_id, label_font_size, s),
+        None => 0.0,
+    };
+    let x_label_ws: Vec<Scalar> = x_ticks.iter().map(|t| width(t.1.as_str())).collect();
+    let y_label_ws: Vec<Scalar> = y_ticks.iter().map(|t| width(t.1.as_str())).collect();
+
+    let tick_length = style.tick_length(&ui.theme);
+    let label_h = label_font_size as Scalar;
+    let title_h = style.title_font_size(&ui.theme) as Scalar;
+    let max_y_label_w = y_label_ws.iter().fold(0.0, |max: Scalar, &w| max.max(w));
+    let last_x_label_w = x_label_ws.last().cloned().unwrap_or(0.0);
+    let left = tick_length + PAD + max_y_label_w;
+    let right = last_x_label_w / 2.0 + PAD;
+    let bottom = tick_length + PAD + label_h
+        + x_axis.maybe_title.map(|_| PAD + title_h).unwrap_or(0.0);
+    let top = label_h / 2.0 + y_axis.maybe_title.map(|_| PAD + title_h).unwrap_or(0.0);
+    let plot = if rect.w() > left + right && rect.h() > bottom + top {
+        Some(Rect::from_corners([rect.left() + left, rect.bottom() + bottom],
+                                [rect.right() - right, rect.top() - top]))
+    } else {
+        None
+    };
+
+    Layout {
+        x_ticks: x_ticks,
+        y_ticks: y_ticks,
+        x_label_ws: x_label_ws,
+        y_label_ws: y_label_ws,
+        plot: plot,
+    }
+}
+
+// The width of the given text.
+fn text_width(ui: &UiCell, font_id: text::font::Id, font_size: FontSize, s: &str) -> Scalar {
+    ui.fonts.get(font_id).map(|font| text::line::width(s, font, font_size)).unwrap_or(0.0)
+}
+
+/// Maps values along the axes of a **Chart** to absolute positions within its plot area.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mapping {
+    /// The scale and visible range of values along the *x* axis.
+    pub x: (Scale, Scalar, Scalar),
+    /// The scale and visible range of values along the *y* axis.
+    pub y: (Scale, Scalar, Scalar),
+    /// The area over which the data is plotted.
+    pub plot: Rect,
+}
+
+impl Mapping {
+
+    /// Map the given view onto the given plot area.
+    pub fn new(view: View, x_scale: Scale, y_scale: Scale, plot: Rect) -> Self {
+        Mapping {
+            x: (x_scale, view.x.0, view.x.1),
+            y: (y_scale, view.y.0, view.y.1),
+            plot: plot,
+        }
+    }
+
+    /// The view that is mapped onto the plot area.
+    pub fn view(&self) -> View {
+        View {
+            x: (self.x.1, self.x.2),
+            y: (self.y.1, self.y.2),
+        }
+    }
+
+    /// The view whose values would be mapped onto the given absolute rectangle.
+    ///
+    /// Mapping this view onto the plot area zooms and pans the plot to the rectangle.
+    pub fn view_of(&self, rect: Rect) -> View {
+        let min = self.unmap_point(rect.bottom_left());
+        let max = self.unmap_point(rect.top_right());
+        View {
+            x: (min[0], max[0]),
+            y: (min[1], max[1]),
+        }
+    }
+
+    /// The absolute *x* position of the given value.
+    ///
+    /// Returns `None` if the value cannot be displayed on the scale of the *x* axis.
+    pub fn map_x(&self, x: Scalar) -> Option<Scalar> {
+        map_value(self.x, x, self.plot.left(), self.plot.right())
+    }
+
+    /// The absolute *y* position of the given value.
+    ///
+    /// Returns `None` if the value cannot be displayed on the scale of the *y* axis.
+    pub fn map_y(&self, y: Scalar) -> Option<Scalar> {
+        map_value(self.y, y, self.plot.bottom(), self.plot.top())
+    }
+
+    /// The absolute position of the given data point.
+    pub fn map_point(&self, p: Point) -> Option<Point> {
+        match (self.map_x(p[0]), self.map_y(p[1])) {
+            (Some(x), Some(y)) => Some([x, y]),
+            _ => None,
+        }
+    }
+
+    /// The data point at the given absolute position.
+    pub fn unmap_point(&self, p: Point) -> Point {
+        [
+            unmap_value(self.x, p[0], self.plot.left(), self.plot.right()),
+            unmap_value(self.y, p[1], self.plot.bottom(), self.plot.top()),
+        ]
+    }
+
+    /// The absolute *y* position from which areas are filled and bars are drawn.
+    ///
+    /// This is zero on a linear scale clamped to the plot, or the bottom of a logarithmic one.
+    pub fn baseline(&self) -> Scalar {
+        match self.y.0 {
+            Scale::Linear => {
+                let y = self.map_y(0.0).unwrap_or(self.plot.bottom());
+                self.plot.y.clamp_value(y)
+            },
+            Scale::Log => self.plot.bottom(),
+        }
+    }
+
+    // The distance spanned by the *x* axis in the space in which its scale is linear.
+    fn x_span(&self) -> Scalar {
+        let (scale, min, max) = self.x;
+        match (scale.transform(min), scale.transform(max)) {
+            (Some(min), Some(max)) if min != max => (max - min).abs(),
+            _ => 1.0,
+        }
+    }
+
+}
+
+// Map the given value along an axis with the given scale and range to the given absolute range.
+fn map_value(axis: (Scale, Scalar, Scalar), value: Scalar, start: Scalar, end: Scalar)
+    -> Option<Scalar>
+{
+    let (scale, min, max) = axis;
+    match (scale.transform(value), scale.transform(min), scale.transform(max)) {
+        (Some(value), Some(min), Some(max)) if min != max =>
+            Some(map_range(value, min, max, start, end)),
+        _ => None,
+    }
+}
+
+// The inverse of `map_value`.
+fn unmap_value(axis: (Scale, Scalar, Scalar), pos: Scalar, start: Scalar, end: Scalar) -> Scalar {
+    let (scale, min, max) = axis;
+    match (scale.transform(min), scale.transform(max)) {
+        (Some(min), Some(max)) if start != end =>
+            scale.untransform(map_range(pos, start, end, min, max)),
+        _ => min,
+    }
+}
+
+// Scale the given rectangle around the given point by the given factor.
+fn scale_rect(rect: Rect, around: Point, factor: Scalar) -> Rect {
+    let scale = |p: Point| [
+        around[0] + (p[0] - around[0]) * factor,
+        around[1] + (p[1] - around[1]) * factor,
+    ];
+    Rect::from_corners(scale(rect.bottom_left()), scale(rect.top_right()))
+}
+
+// Whether or not the given view may be displayed with the given scales.
+fn is_valid(view: View, x_scale: Scale, y_scale: Scale) -> bool {
+    let is_valid_range = |(min, max): (Scalar, Scalar), scale: Scale| {
+        match (scale.transform(min), scale.transform(max)) {
+            (Some(min), Some(max)) => min.is_finite() && max.is_finite() && min != max,
+            _ => false,
+        }
+    };
+    is_valid_range(view.x, x_scale) && is_valid_range(view.y, y_scale)
+}
+
+// The index of the series and the value of the visible data point nearest to the given absolute
+// position, along with the point's absolute position.
+fn nearest_point(series: &[Series], mapping: &Mapping, xy: Point) -> Option<(usize, Point, Point)> {
+    let mut nearest = None;
+    let mut nearest_distance = ::std::f64::MAX;
+    for (i, s) in series.iter().enumerate() {
+        for &point in s.points {
+            let point_xy = match mapping.map_point(point) {
+                Some(point_xy) if mapping.plot.is_over(point_xy) => point_xy,
+                _ => continue,
+            };
+            let distance = (point_xy[0] - xy[0]).powi(2) + (point_xy[1] - xy[1]).powi(2);
+            if distance < nearest_distance {
+                nearest_distance = distance;
+                nearest = Some((i, point, point_xy));
+            }
+        }
+    }
+    nearest
+}
+
+// The visible range along the *x* axis.
+//
+// Fitted ranges leave room for half of the group of bars either side of the outermost bars.
+fn x_range(series: &[Series], axis: &Axis) -> (Scalar, Scalar) {
+    let has_bars = series.iter().any(|s| s.kind == Kind::Bar);
+    let pad = if has_bars { bar_slot(series, axis.scale) * BAR_FILL / 2.0 } else { 0.0 };
+    let values = series.iter().flat_map(|s| s.points.iter().map(|p| p[0]));
+    fit_range(axis, values, false, pad)
+}
+
+// The visible range along the *y* axis.
+//
+// Fitted ranges include zero if there are any bars or areas.
+fn y_range(series: &[Series], axis: &Axis) -> (Scalar, Scalar) {
+    let has_base = series.iter().any(|s| s.kind == Kind::Bar || s.kind == Kind::Area);
+    let values = series.iter().flat_map(|s| s.points.iter().map(|p| p[1]));
+    fit_range(axis, values, has_base, 0.0)
+}
+
+// The range of the given axis, or the range of the given values rounded out to the nearest ticks
+// if it has none.
+//
+// The `pad` is added to either side of the values in the space in which the scale is linear.
+fn fit_range<I>(axis: &Axis, values: I, include_zero: bool, pad: Scalar) -> (Scalar, Scalar)
+    where I: Iterator<Item=Scalar>,
+{
+    if let Some(range) = axis.maybe_range {
+        return range;
+    }
+    let scale = axis.scale;
+    let mut bounds = values
+        .filter_map(|v| scale.transform(v))
+        .filter(|t| t.is_finite())
+        .fold(None, |bounds, t| match bounds {
+            None => Some((t, t)),
+            Some((min, max)) => Some((t.min(min), t.max(max))),
+        });
+    if include_zero && scale == Scale::Linear {
+        bounds = bounds.map(|(min, max)| (min.min(0.0), max.max(0.0)));
+    }
+    let (min, max) = match bounds {
+        Some((min, max)) => (min - pad, max + pad),
+        None => (0.0, 1.0),
+    };
+    let (min, max) = if min == max { (min - 1.0, max + 1.0) } else { (min, max) };
+    match scale {
+        Scale::Linear => {
+            let step = axis::nice_step(max - min, axis.tick_count);
+            ((min / step).floor() * step, (max / step).ceil() * step)
+        },
+        Scale::Log => (scale.untransform(min.floor()), scale.untransform(max.ceil())),
+    }
+}
+
+// The smallest distance between the *x* values of neighbouring bars in the space in which the
+// given scale is linear.
+fn bar_slot(series: &[Series], scale: Scale) -> Scalar {
+    let mut xs: Vec<Scalar> = series.iter()
+        .filter(|s| s.kind == Kind::Bar)
+        .flat_map(|s| s.points.iter().filter_map(|p| scale.transform(p[0])))
+        .collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    xs.windows(2)
+        .map(|w| w[1] - w[0])
+        .filter(|&d| d > 0.0)
+        .fold(None, |min: Option<Scalar>, d| Some(min.map_or(d, |min| min.min(d))))
+        .unwrap_or(1.0)
+}
+
+// Triangles filling the area between the line joining the given points and the baseline.
+fn area_triangles(points: &[Point], baseline: Scalar) -> Vec<Triangle<Point>> {
+    points.windows(2)
+        .flat_map(|w| {
+            let (a, b) = (w[0], w[1]);
+            let (a_base, b_base) = ([a[0], baseline], [b[0], baseline]);
+            vec![Triangle([a, b, b_base]), Triangle([a, b_base, a_base])]
+        })
+        .collect()
+}
+
+// Triangles describing a dot of the given radius.
+fn dot_triangles(centre: Point, radius: Scalar) -> Vec<Triangle<Point>> {
+    use std::f64::consts::PI;
+    let vertex = |i: usize| {
+        let rad = i as Scalar / DOT_RESOLUTION as Scalar * 2.0 * PI;
+        [centre[0] + rad.cos() * radius, centre[1] + rad.sin() * radius]
+    };
+    (0..DOT_RESOLUTION)
+        .map(|i| Triangle([centre, vertex(i), vertex(i + 1)]))
+        .collect()
+}
@@ -0,0 +1,290 @@
+//! A box that may be checked, unchecked or marked as indeterminate, along with an optional label.
+
+use {Color, Colorable, FontSize, Borderable, Labelable, Positionable, Scalar, Widget};
+use input;
+use text;
+use widget;
+
+
+/// A pressable box displaying a check mark while checked.
+///
+/// Pressing the `Checkbox`, or pressing `Space` while it captures the keyboard, toggles its value.
+/// A `Checkbox` may also display an *indeterminate* state, commonly used to indicate that only
+/// some of a group of options are checked. Toggling an indeterminate `Checkbox` checks it.
+///
+/// Note that the Checkbox will not mutate the value for you, you should do this yourself using
+/// the returned event.
+#[derive(Clone, WidgetCommon_)]
+pub struct Checkbox<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    value: CheckState,
+    maybe_label: Option<&'a str>,
+    style: Style,
+    /// If true, will allow user inputs. If false, will disallow user inputs.
+    pub enabled: bool,
+}
+
+/// Styling for the Checkbox including coloring, bordering and labelling.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// Color of the box.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The width of the box's border.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of the box's border.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the check mark and indeterminate bar.
+    #[conrod(default = "theme.label_color")]
+    pub check_color: Option<Color>,
+    /// The color of the Checkbox's Text label.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size for the Checkbox's Text label.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the label.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+    /// The side of the box on which the label is displayed.
+    #[conrod(default = "LabelSide::Right")]
+    pub label_side: Option<LabelSide>,
+    /// The distance between the box and the label.
+    #[conrod(default = "6.0")]
+    pub label_padding: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        rectangle,
+        check,
+        indeterminate_bar,
+        label,
+    }
+}
+
+/// The state of the Checkbox.
+pub struct State {
+    ids: Ids,
+}
+
+/// The value displayed by a `Checkbox`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CheckState {
+    /// The box is empty.
+    Unchecked,
+    /// The box displays a check mark.
+    Checked,
+    /// The box displays a bar, indicating that it is neither checked nor unchecked.
+    Indeterminate,
+}
+
+/// The side of a glyph on which its label is displayed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LabelSide {
+    /// The label is displayed to the left of the glyph, which is aligned to the right edge.
+    Left,
+    /// The label is displayed to the right of the glyph, which is aligned to the left edge.
+    Right,
+}
+
+impl CheckState {
+
+    /// The state that results from toggling this one.
+    ///
+    /// Toggling an `Indeterminate` state produces `Checked`.
+    pub fn toggled(self) -> Self {
+        match self {
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Unchecked | CheckState::Indeterminate => CheckState::Checked,
+        }
+    }
+
+    /// Whether or not the state is `Checked`.
+    pub fn is_checked(self) -> bool {
+        self == CheckState::Checked
+    }
+
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked { CheckState::Checked } else { CheckState::Unchecked }
+    }
+}
+
+impl<'a> Checkbox<'a> {
+
+    /// Construct a new Checkbox widget displaying the given value.
+    ///
+    /// The value may be either a `bool` or a `CheckState`.
+    pub fn new<V>(value: V) -> Self
+        where V: Into<CheckState>,
+    {
+        Checkbox {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            maybe_label: None,
+            value: value.into(),
+            enabled: true,
+        }
+    }
+
+    /// Specify the font used for displaying the label.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    /// Display the label to the left of the box.
+    pub fn label_left(self) -> Self {
+        self.label_side(LabelSide::Left)
+    }
+
+    /// Display the label to the right of the box.
+    pub fn label_right(self) -> Self {
+        self.label_side(LabelSide::Right)
+    }
+
+    builder_methods!{
+        pub enabled { enabled = bool }
+        pub check_color { style.check_color = Some(Color) }
+        pub label_side { style.label_side = Some(LabelSide) }
+        pub label_padding { style.label_padding = Some(Scalar) }
+    }
+
+}
+
+impl<'a> Widget for Checkbox<'a> {
+    type State = State;
+    type Style = Style;
+    /// The new `CheckState` if the `Checkbox` was toggled during this update.
+    type Event = Option<CheckState>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Checkbox.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, style, rect, ui, .. } = args;
+        let Checkbox { value, enabled, maybe_label, .. } = self;
+
+        // Toggle the value once for each click, tap and press of `Space`.
+        let new_value = if enabled {
+            let input = ui.widget_input(id);
+            let spaces = input.presses().key().filter(|press| press.key == input::Key::Space);
+            let count = input.clicks().left().count() + input.taps().count() + spaces.count();
+            (0..count).fold(value, |value, _| value.toggled())
+        } else {
+            value
+        };
+
+        // The box is a square on the side of the `Checkbox` opposite the label.
+        let side = rect.h().min(rect.w());
+        let label_side = style.label_side(&ui.theme);
+        let box_x = match label_side {
+            LabelSide::Left => rect.w() / 2.0 - side / 2.0,
+            LabelSide::Right => side / 2.0 - rect.w() / 2.0,
+        };
+
+        let border = style.border(ui.theme());
+        let border_color = style.border_color(ui.theme());
+        let color = {
+            let color = style.color(ui.theme());
+            match ui.widget_input(id).mouse() {
+                Some(_) if !enabled => color,
+                Some(mouse) =>
+                    if mouse.buttons.left().is_down() { color.clicked() }
+                    else { color.highlighted() },
+                None => color,
+            }
+        };
+        widget::BorderedRectangle::new([side, side])
+            .x_y_relative_to(id, box_x, 0.0)
+            .graphics_for(id)
+            .parent(id)
+            .color(color)
+            .border(border)
+            .border_color(border_color)
+            .set(state.ids.rectangle, ui);
+
+        // The check mark or indeterminate bar.
+        let check_color = style.check_color(ui.theme());
+        let (x, y) = (rect.x() + box_x, rect.y());
+        match new_value {
+            CheckState::Checked => {
+                let points = [
+                    [x - side * 0.28, y],
+                    [x - side * 0.08, y - side * 0.2],
+                    [x + side * 0.28, y + side * 0.22],
+                ];
+                widget::PointPath::abs(points.iter().cloned())
+                    .thickness(side * 0.12)
+                    .color(check_color)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.check, ui);
+            },
+            CheckState::Indeterminate => {
+                widget::Rectangle::fill([side * 0.5, side * 0.12])
+                    .x_y(x, y)
+                    .color(check_color)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.indeterminate_bar, ui);
+            },
+            CheckState::Unchecked => (),
+        }
+
+        // Label widget.
+        if let Some(label) = maybe_label {
+            let color = style.label_color(ui.theme());
+            let font_size = style.label_font_size(ui.theme());
+            let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+            let padding = style.label_padding(ui.theme());
+            let text = widget::Text::new(label)
+                .and_then(font_id, widget::Text::font_id)
+                .align_middle_y_of(state.ids.rectangle)
+                .graphics_for(id)
+                .parent(id)
+                .color(color)
+                .font_size(font_size);
+            match label_side {
+                LabelSide::Left => text.left_from(state.ids.rectangle, padding),
+                LabelSide::Right => text.right_from(state.ids.rectangle, padding),
+            }.set(state.ids.label, ui);
+        }
+
+        if new_value != value { Some(new_value) } else { None }
+    }
+}
+
+
+impl<'a> Colorable for Checkbox<'a> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl<'a> Borderable for Checkbox<'a> {
+    builder_methods!{
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
+
+impl<'a> Labelable<'a> for Checkbox<'a> {
+    builder_methods!{
+        label { maybe_label = Some(&'a str) }
+        label_color { style.label_color = Some(Color) }
+        label_font_size { style.label_font_size = Some(FontSize) }
+    }
+}
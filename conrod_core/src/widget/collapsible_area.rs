@@ -2,6 +2,7 @@
 
 use {Borderable, Colorable, Labelable, Positionable, Sizeable, Widget};
 use {Color, FontSize, Scalar, UiCell};
+use animation;
 use position;
 use std;
 use text;
@@ -55,6 +56,9 @@ pub struct Style {
     /// The ID of the font used to display the label.
     #[conrod(default = "theme.font_id")]
     pub label_font_id: Option<Option<text::font::Id>>,
+    /// The transition used to animate the opening and closing of the area.
+    #[conrod(default = "theme.transition")]
+    pub transition: Option<animation::Transition>,
 }
 
 /// The event returned when the text bar or triangle is pressed.
@@ -66,7 +70,7 @@ pub enum Event {
     Close,
 }
 
-/// The area returned by the widget when the `CollapsibleArea` is open.
+/// The area returned by the widget when the `CollapsibleArea` is open or animating closed.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Area {
     /// A unique identifier for the user's widget.
@@ -74,7 +78,11 @@ pub struct Area {
     /// The widget::Id for the collapsible area that produced this `Area`.
     pub collapsible_area_id: widget::Id,
     /// The width of the `CollapsibleArea` that produced this `Area`.
-    pub width: Scalar
+    pub width: Scalar,
+    /// How far open the area is, where `0.0` is closed and `1.0` is fully open.
+    ///
+    /// This is only ever less than `1.0` while the area is animating open or closed.
+    pub openness: Scalar,
 }
 
 
@@ -108,6 +116,12 @@ impl<'a> CollapsibleArea<'a> {
         self
     }
 
+    /// Specify the transition used to animate the opening and closing of the area.
+    pub fn transition(mut self, transition: animation::Transition) -> Self {
+        self.style.transition = Some(transition);
+        self
+    }
+
 }
 
 impl<'a> Widget for CollapsibleArea<'a> {
@@ -168,22 +182,28 @@ impl<'a> Widget for CollapsibleArea<'a> {
                 if is_open { Event::Open } else { Event::Close }
             });
 
-        // The points for the triangle.
+        // How far open the area is, animated towards the new open state.
+        let openness = {
+            let target = if is_open { 1.0 } else { 0.0 };
+            let transition = style.transition(&ui.theme);
+            ui.animate(id, "openness", target, transition)
+        };
+
+        // The points for the triangle, pointing right when closed and rotating clockwise to
+        // point down when open.
         let side_offset = triangle_rect.w() / 10.0;
         let point_offset = triangle_rect.h() / 6.0;
         let triangle_x = triangle_rect.x();
         let triangle_y = triangle_rect.y();
-        let points = if is_open {
-            let a = [triangle_x, triangle_y - point_offset];
-            let b = [triangle_x + side_offset, triangle_y + point_offset];
-            let c = [triangle_x - side_offset, triangle_y + point_offset];
-            [a, b, c]
-        } else {
-            let a = [triangle_x + point_offset, triangle_y];
-            let b = [triangle_x - point_offset, triangle_y + side_offset];
-            let c = [triangle_x - point_offset, triangle_y - side_offset];
-            [a, b, c]
+        let angle = openness * std::f64::consts::PI / 2.0;
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let rotate = |x: Scalar, y: Scalar| {
+            [triangle_x + x * cos + y * sin, triangle_y - x * sin + y * cos]
         };
+        let a = rotate(point_offset, 0.0);
+        let b = rotate(-point_offset, side_offset);
+        let c = rotate(-point_offset, -side_offset);
+        let points = [a, b, c];
 
         // The triangle widget.
         widget::Polygon::fill(points.iter().cloned())
@@ -195,12 +215,13 @@ impl<'a> Widget for CollapsibleArea<'a> {
             .color(label_color)
             .set(state.ids.triangle, ui);
 
-        // The area on which the user can place their widgets if it is open.
-        let area = if is_open {
+        // The area on which the user can place their widgets if it is open or still closing.
+        let area = if is_open || openness > 0.0 {
             Some(Area {
                 id: state.ids.area,
                 collapsible_area_id: id,
                 width: w,
+                openness: openness,
             })
         } else {
             None
@@ -241,11 +262,18 @@ impl Event {
 impl Area {
     /// Set the user's given widget directly under the `CollapsibleArea`.
     ///
+    /// While the area is animating open or closed, the height of the widget is scaled by the
+    /// `openness` of the area.
+    ///
     /// Returns any events produced by the given widget.
     pub fn set<W>(self, widget: W, ui: &mut UiCell) -> W::Event
         where W: Widget,
     {
-        let Area { id, collapsible_area_id, width } = self;
+        let Area { id, collapsible_area_id, width, openness } = self;
+        let widget = match widget.get_h(ui) {
+            Some(h) if openness < 1.0 => widget.h(h * openness),
+            _ => widget,
+        };
         widget
             .w(width)
             .parent(collapsible_area_id)
@@ -0,0 +1,441 @@
+//! A `TextEdit` combined with a drop down list of suggestions filtered by the entered text.
+
+use {Color, Colorable, FontSize, Borderable, Labelable, Positionable, Sizeable};
+use input;
+use position::{Place, Range, Rect, Relative, Scalar};
+use text;
+use utils;
+use widget::{self, Widget};
+use widget::drop_down_list::MaxHeight;
+
+
+/// The index of an item.
+pub type Idx = usize;
+
+/// An editable field of text with a drop down list of suggestions.
+///
+/// Suggestions are filtered from the given `items` using the entered text and may be navigated
+/// via the `Up` and `Down` keys and chosen via `Return` or a mouse click. By default, only the
+/// given items may be chosen. Use the `free_text` builder method to also allow submitting text
+/// that matches none of them.
+#[derive(WidgetCommon_)]
+pub struct ComboBox<'a, T: 'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    items: &'a [T],
+    text: &'a str,
+    matching: Matching,
+    accepts_free_text: bool,
+    style: Style,
+}
+
+/// Unique graphical styling for the `ComboBox`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// Color of the rectangle behind the text and the suggestions.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The width of the border.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of the border.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the text and suggestions.
+    #[conrod(default = "theme.label_color")]
+    pub text_color: Option<Color>,
+    /// The font size for the text and suggestions.
+    #[conrod(default = "theme.font_size_medium")]
+    pub font_size: Option<FontSize>,
+    /// The length of the gap between the border and the edge of the text.
+    #[conrod(default = "5.0")]
+    pub text_padding: Option<Scalar>,
+    /// The font used for the text and suggestions.
+    #[conrod(default = "theme.font_id")]
+    pub font_id: Option<Option<text::font::Id>>,
+    /// Maximum height of the list of suggestions before the scrollbar appears.
+    #[conrod(default = "None")]
+    pub maybe_max_visible_height: Option<Option<MaxHeight>>,
+}
+
+widget_ids! {
+    struct Ids {
+        rectangle,
+        text_edit,
+        list,
+    }
+}
+
+/// The `State` of the `ComboBox` widget that will be cached within the `Ui`.
+pub struct State {
+    ids: Ids,
+    is_open: bool,
+    highlighted: Option<Idx>,
+}
+
+/// The method used to determine which items are suggested for the entered text.
+///
+/// All matching is case-insensitive.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Matching {
+    /// Suggest items that begin with the text.
+    Prefix,
+    /// Suggest items that contain the text, ordered by how early it appears.
+    Substring,
+    /// Suggest items that contain all characters of the text in order, ordered by how few
+    /// characters are skipped between them.
+    Fuzzy,
+}
+
+/// Events produced by the `ComboBox`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// The text was edited.
+    Update(String),
+    /// The item at the given index was chosen from the suggestions.
+    Select(Idx),
+    /// `Return` was pressed while no suggestion was highlighted.
+    ///
+    /// Only produced when the `ComboBox` accepts free text.
+    Submit(String),
+}
+
+impl Matching {
+
+    /// Score how well the `candidate` matches the `query`, where lower scores are better.
+    ///
+    /// Returns `None` if the `candidate` does not match at all.
+    pub fn score(&self, query: &str, candidate: &str) -> Option<usize> {
+        let query = query.to_lowercase();
+        let candidate = candidate.to_lowercase();
+        match *self {
+            Matching::Prefix =>
+                if candidate.starts_with(&query[..]) { Some(0) } else { None },
+            Matching::Substring =>
+                candidate.find(&query[..]).map(|pos| candidate[..pos].chars().count()),
+            Matching::Fuzzy => {
+                let mut skipped = 0;
+                let mut chars = candidate.chars();
+                'query: for q in query.chars() {
+                    for c in &mut chars {
+                        if c == q {
+                            continue 'query;
+                        }
+                        skipped += 1;
+                    }
+                    return None;
+                }
+                Some(skipped)
+            },
+        }
+    }
+
+}
+
+/// The indices of the `items` that match the `query`, best matches first.
+///
+/// Items with equal scores retain their original order.
+pub fn suggestions<T>(items: &[T], query: &str, matching: Matching) -> Vec<Idx>
+    where T: AsRef<str>,
+{
+    let mut scored: Vec<_> = items.iter()
+        .enumerate()
+        .filter_map(|(i, item)| matching.score(query, item.as_ref()).map(|score| (score, i)))
+        .collect();
+    scored.sort();
+    scored.into_iter().map(|(_, i)| i).collect()
+}
+
+impl<'a, T> ComboBox<'a, T> {
+
+    /// Construct a `ComboBox` suggesting the given `items` for the given `text`.
+    pub fn new(items: &'a [T], text: &'a str) -> Self {
+        ComboBox {
+            common: widget::CommonBuilder::default(),
+            items: items,
+            text: text,
+            matching: Matching::Prefix,
+            accepts_free_text: false,
+            style: Style::default(),
+        }
+    }
+
+    /// Specify the font used for displaying the text and suggestions.
+    pub fn font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.font_id = Some(Some(font_id));
+        self
+    }
+
+    /// Set the maximum height of the list of suggestions (before the scrollbar appears) as a
+    /// number of items.
+    pub fn max_visible_items(mut self, num: usize) -> Self {
+        self.style.maybe_max_visible_height = Some(Some(MaxHeight::Items(num)));
+        self
+    }
+
+    /// Set the maximum height of the list of suggestions (before the scrollbar appears) as a
+    /// scalar height.
+    pub fn max_visible_height(mut self, height: Scalar) -> Self {
+        self.style.maybe_max_visible_height = Some(Some(MaxHeight::Scalar(height)));
+        self
+    }
+
+    builder_methods!{
+        pub matching { matching = Matching }
+        pub free_text { accepts_free_text = bool }
+        pub text_color { style.text_color = Some(Color) }
+        pub font_size { style.font_size = Some(FontSize) }
+        pub pad_text { style.text_padding = Some(Scalar) }
+    }
+
+}
+
+impl<'a, T> Widget for ComboBox<'a, T>
+    where T: AsRef<str>,
+{
+    type State = State;
+    type Style = Style;
+    type Event = Vec<Event>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            is_open: false,
+            highlighted: None,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the ComboBox.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, rect, style, ui, .. } = args;
+        let ComboBox { items, text, matching, accepts_free_text, .. } = self;
+
+        let color = style.color(ui.theme());
+        let border = style.border(ui.theme());
+        let border_color = style.border_color(ui.theme());
+        let text_color = style.text_color(ui.theme());
+        let font_size = style.font_size(ui.theme());
+        let text_padding = style.text_padding(ui.theme());
+        let font_id = style.font_id(&ui.theme).or(ui.fonts.ids().next());
+
+        widget::BorderedRectangle::new(rect.dim())
+            .xy(rect.xy())
+            .graphics_for(id)
+            .parent(id)
+            .border(border)
+            .color(color)
+            .border_color(border_color)
+            .set(state.ids.rectangle, ui);
+
+        let text_rect = {
+            let w = rect.x.pad(border + text_padding).len();
+            let h = font_size as Scalar + 1.0;
+            let x = Range::new(0.0, w).align_middle_of(rect.x);
+            let y = Range::new(0.0, h).align_middle_of(rect.y);
+            Rect { x: x, y: y }
+        };
+
+        let mut events = Vec::new();
+        let mut is_open = state.is_open;
+        let mut highlighted = state.highlighted;
+
+        // `Return` is handled below, so strip the newlines that the `TextEdit` inserts for it.
+        let edited = widget::TextEdit::new(text)
+            .and_then(font_id, widget::TextEdit::font_id)
+            .wh(text_rect.dim())
+            .xy(text_rect.xy())
+            .font_size(font_size)
+            .color(text_color)
+            .left_justify()
+            .parent(id)
+            .set(state.ids.text_edit, ui)
+            .map(|string| string.chars().filter(|&c| c != '\n').collect::<String>())
+            .and_then(|string| if string != text { Some(string) } else { None });
+
+        let suggested = {
+            let query = edited.as_ref().map_or(text, |string| &string[..]);
+            suggestions(items, query, matching)
+        };
+
+        // Editing the text re-opens the suggestions. Unless free text is accepted, the best
+        // suggestion is highlighted so that it may be chosen via `Return`.
+        if let Some(ref string) = edited {
+            is_open = true;
+            highlighted = if accepts_free_text { None } else { suggested.first().cloned() };
+            events.push(Event::Update(string.clone()));
+        }
+        if highlighted.map_or(false, |i| !suggested.contains(&i)) {
+            highlighted = None;
+        }
+
+        // Navigate the suggestions with the keyboard.
+        let mut scroll_to = None;
+        let keys: Vec<_> = ui.widget_input(state.ids.text_edit).presses().key()
+            .map(|press| press.key)
+            .collect();
+        for key in keys {
+            let position = highlighted.and_then(|i| suggested.iter().position(|&s| s == i));
+            match key {
+                input::Key::Down => {
+                    let next = match position {
+                        _ if !is_open => position.or(Some(0)),
+                        None => Some(0),
+                        Some(pos) => Some(pos + 1),
+                    };
+                    is_open = true;
+                    if let Some(&i) = next.and_then(|pos| suggested.get(pos)) {
+                        highlighted = Some(i);
+                        scroll_to = Some(i);
+                    }
+                },
+                input::Key::Up => {
+                    highlighted = match position {
+                        Some(0) if accepts_free_text => None,
+                        Some(pos) => Some(suggested[pos.saturating_sub(1)]),
+                        None => None,
+                    };
+                    scroll_to = highlighted;
+                },
+                input::Key::Return => {
+                    match highlighted.and_then(|i| if is_open { Some(i) } else { None }) {
+                        Some(i) => events.push(Event::Select(i)),
+                        None => {
+                            let query = edited.as_ref().map_or(text, |string| &string[..]);
+                            if accepts_free_text {
+                                events.push(Event::Submit(query.to_string()));
+                            } else {
+                                let query = query.to_lowercase();
+                                let exact = items.iter()
+                                    .position(|item| item.as_ref().to_lowercase() == query);
+                                if let Some(i) = exact {
+                                    events.push(Event::Select(i));
+                                }
+                            }
+                        },
+                    }
+                    is_open = false;
+                    highlighted = None;
+                },
+                input::Key::Escape => {
+                    is_open = false;
+                    highlighted = None;
+                },
+                _ => (),
+            }
+        }
+
+        // Close the suggestions if the mouse is pressed or the keyboard is captured outside.
+        if is_open {
+            let input = ui.global_input();
+            let is_own = |widget: Option<widget::Id>| match widget {
+                None => false,
+                Some(widget) => widget == id
+                    || ui.widget_graph().does_recursive_depth_edge_exist(id, widget),
+            };
+            let pressed_elsewhere = input.current.mouse.buttons.pressed().next().is_some()
+                && !is_own(input.current.widget_capturing_mouse);
+            if pressed_elsewhere || !is_own(input.current.widget_capturing_keyboard) {
+                is_open = false;
+                highlighted = None;
+            }
+        }
+
+        if is_open && !suggested.is_empty() {
+            let (_, y, w, h) = rect.x_y_w_h();
+            let max_visible_height = {
+                let bottom_win_y = (-ui.window_dim()[1]) / 2.0;
+                const WINDOW_PADDING: Scalar = 20.0;
+                let max = y - h / 2.0 - bottom_win_y - WINDOW_PADDING;
+                style.maybe_max_visible_height(ui.theme()).map(|max_height| {
+                    let height = match max_height {
+                        MaxHeight::Items(num) => h * num as Scalar,
+                        MaxHeight::Scalar(height) => height,
+                    };
+                    utils::partial_min(height, max)
+                }).unwrap_or(max)
+            };
+            let list_h = max_visible_height.min(suggested.len() as Scalar * h);
+
+            let (mut list_events, scrollbar) = widget::ListSelect::single(suggested.len())
+                .flow_down()
+                .item_size(h)
+                .w_h(w, list_h)
+                .scrollbar_on_top()
+                .scrollbar_color(border_color)
+                .down_from(id, 0.0)
+                .align_left_of(id)
+                .parent(id)
+                .floating(true)
+                .set(state.ids.list, ui);
+
+            // Bring the suggestion highlighted via the keyboard into view.
+            let scroll_pos = scroll_to.and_then(|i| suggested.iter().position(|&s| s == i));
+            if let Some(item_rect) = scroll_pos.and_then(|pos| list_events.item_rect(ui, pos)) {
+                ui.scroll_to_rect(state.ids.list, item_rect, widget::scroll::Alignment::Nearest);
+            }
+
+            while let Some(event) = list_events.next(ui, |pos| Some(suggested[pos]) == highlighted) {
+                use widget::list_select;
+                match event {
+
+                    // Instantiate a `Button` for each suggestion.
+                    list_select::Event::Item(item) => {
+                        let i = suggested[item.i];
+                        let is_highlighted = Some(i) == highlighted;
+                        let mut button = widget::Button::new().label(items[i].as_ref());
+                        button.style = widget::button::Style {
+                            color: Some(if is_highlighted { color.highlighted() } else { color }),
+                            border: Some(border),
+                            border_color: Some(border_color),
+                            label_color: Some(text_color),
+                            label_font_size: Some(font_size),
+                            label_justify: Some(text::Justify::Left),
+                            label_x: Some(Relative::Place(Place::Start(Some(text_padding)))),
+                            label_y: None,
+                            label_font_id: Some(font_id),
+                        };
+                        item.set(button, ui);
+                    },
+
+                    // A suggestion was clicked.
+                    list_select::Event::Selection(pos) => {
+                        events.push(Event::Select(suggested[pos]));
+                        is_open = false;
+                        highlighted = None;
+                    },
+
+                    _ => (),
+                }
+            }
+
+            if let Some(scrollbar) = scrollbar {
+                scrollbar.set(ui);
+            }
+        }
+
+        if state.is_open != is_open || state.highlighted != highlighted {
+            state.update(|state| {
+                state.is_open = is_open;
+                state.highlighted = highlighted;
+            });
+        }
+
+        events
+    }
+
+}
+
+impl<'a, T> Borderable for ComboBox<'a, T> {
+    builder_methods!{
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
+
+impl<'a, T> Colorable for ComboBox<'a, T> {
+    builder_method!(color { style.color = Some(Color) });
+}
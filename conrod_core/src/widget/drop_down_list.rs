@@ -1,7 +1,9 @@
 //! The `DropDownList` and related items.
 
-use {Color, Colorable, FontSize, Borderable, Labelable, Positionable, Sizeable};
-use position::{self, Align, Scalar};
+use {Color, Colorable, FontSize, Borderable, Labelable, Positionable, Sizeable, UiCell};
+use animation;
+use event;
+use position::{self, Align, Rect, Scalar};
 use text;
 use utils;
 use widget::{self, Widget};
@@ -65,6 +67,9 @@ pub struct Style {
     /// The ID of the font used to display the labels.
     #[conrod(default = "theme.font_id")]
     pub label_font_id: Option<Option<text::font::Id>>,
+    /// The transition used to animate the opening and closing of the list.
+    #[conrod(default = "theme.transition")]
+    pub transition: Option<animation::Transition>,
 }
 
 widget_ids! {
@@ -78,6 +83,7 @@ widget_ids! {
 pub struct State {
     menu_state: MenuState,
     ids: Ids,
+    type_ahead: widget::list_select::TypeAhead,
 }
 
 /// Representations of the max height of the visible area of the DropDownList.
@@ -190,6 +196,12 @@ impl<'a, T> DropDownList<'a, T> {
         self
     }
 
+    /// Specify the transition used to animate the opening and closing of the list.
+    pub fn transition(mut self, transition: animation::Transition) -> Self {
+        self.style.transition = Some(transition);
+        self
+    }
+
 }
 
 
@@ -204,6 +216,7 @@ impl<'a, T> Widget for DropDownList<'a, T>
         State {
             menu_state: MenuState::Closed,
             ids: Ids::new(id_gen),
+            type_ahead: widget::list_select::TypeAhead::new(),
         }
     }
 
@@ -224,6 +237,42 @@ impl<'a, T> Widget for DropDownList<'a, T>
         // Track whether or not a list item was clicked.
         let mut clicked_item = None;
 
+        // Jump to the item matching any text typed while the `DropDownList` captures the keyboard.
+        let type_ahead_item = {
+            let closed_menu_id = state.ids.closed_menu;
+            let texts = ui.global_input().events().ui().filter_map(|event| match *event {
+                event::Ui::Text(Some(widget), ref text) => {
+                    let is_own = widget == id
+                        || widget == closed_menu_id
+                        || ui.widget_graph().does_recursive_depth_edge_exist(id, widget);
+                    if is_own { Some(text) } else { None }
+                },
+                _ => None,
+            });
+            let now = instant::Instant::now();
+            let mut type_ahead = state.type_ahead.clone();
+            let mut maybe_item = None;
+            for text in texts {
+                let current = maybe_item.or(selected);
+                let label = |i: usize| self.items[i].as_ref().to_string();
+                let found = type_ahead.search_text(text, now, current, num_items, label);
+                maybe_item = found.or(maybe_item);
+            }
+            if type_ahead != state.type_ahead {
+                state.update(|state| state.type_ahead = type_ahead);
+            }
+            maybe_item
+        };
+
+        // How far open the list is, animated towards the current menu state.
+        //
+        // Clamped as springs that carry their velocity into a new target may overshoot it.
+        let openness = {
+            let target = if state.menu_state == MenuState::Open { 1.0 } else { 0.0 };
+            let transition = style.transition(&ui.theme);
+            utils::clamp(ui.animate(id, "openness", target, transition), 0.0, 1.0)
+        };
+
         // Act on the current menu state and determine what the next one will be.
         // new_menu_state is what we will be getting passed next frame
         let new_menu_state = match state.menu_state {
@@ -246,6 +295,11 @@ impl<'a, T> Widget for DropDownList<'a, T>
                     button.set(state.ids.closed_menu, ui).was_clicked()
                 };
 
+                // Continue to display the list while it animates closed, still accepting clicks.
+                if openness > 0.0 {
+                    clicked_item = self.set_list(id, state.ids.list, rect, openness, None, ui);
+                }
+
                 // If the button was clicked, then open, otherwise stay closed
                 if was_clicked { MenuState::Open } else { MenuState::Closed }
             },
@@ -253,72 +307,8 @@ impl<'a, T> Widget for DropDownList<'a, T>
             MenuState::Open => {
 
                 // Otherwise if open, we want to set all the buttons that would be currently visible.
-                let (_, y, w, h) = rect.x_y_w_h();
-                let max_visible_height = {
-                    let bottom_win_y = (-ui.window_dim()[1]) / 2.0;
-                    const WINDOW_PADDING: Scalar = 20.0;
-                    let max = y + h / 2.0 - bottom_win_y - WINDOW_PADDING;
-                    style.maybe_max_visible_height(ui.theme()).map(|max_height| {
-                        let height = match max_height {
-                            MaxHeight::Items(num) => h * num as Scalar,
-                            MaxHeight::Scalar(height) => height,
-                        };
-                        utils::partial_min(height, max)
-                    }).unwrap_or(max)
-                };
-
-                // The list of buttons.
-                let num_items = self.items.len();
-                let item_h = h;
-                let list_h = max_visible_height.min(num_items as Scalar * item_h);
-                let scrollbar_color = style.border_color(&ui.theme);
-                let scrollbar_position = style.scrollbar_position(&ui.theme);
-                let scrollbar_width = style.scrollbar_width(&ui.theme)
-                    .unwrap_or_else(|| {
-                        ui.theme.widget_style::<widget::scrollbar::Style>()
-                            .and_then(|style| style.style.thickness)
-                            .unwrap_or(10.0)
-                    });
-
-                let (mut events, scrollbar) = widget::ListSelect::single(num_items)
-                    .flow_down()
-                    .item_size(item_h)
-                    .w_h(w, list_h)
-                    .and(|ls| match scrollbar_position {
-                        Some(widget::list::ScrollbarPosition::NextTo) => ls.scrollbar_next_to(),
-                        Some(widget::list::ScrollbarPosition::OnTop) => ls.scrollbar_on_top(),
-                        None => ls,
-                    })
-                    .scrollbar_color(scrollbar_color)
-                    .scrollbar_thickness(scrollbar_width)
-                    .mid_top_of(id)
-                    .floating(true)
-                    .set(state.ids.list, ui);
-
-                while let Some(event) = events.next(ui, |i| Some(i) == selected) {
-                    use widget::list_select::Event;
-                    match event {
-
-                        // Instantiate a `Button` for each item.
-                        Event::Item(item) => {
-                            let i = item.i;
-                            let label = self.items[i].as_ref();
-                            let mut button = widget::Button::new().label(label);
-                            button.style = style.button_style(Some(i) == selected);
-                            item.set(button, ui);
-                        },
-
-                        // The selection changed.
-                        Event::Selection(ix) => clicked_item = Some(ix),
-
-                        _ => (),
-                    }
-                }
-
-                // Instantiate the `Scrollbar` if there is one.
-                if let Some(scrollbar) = scrollbar {
-                    scrollbar.set(ui);
-                }
+                clicked_item = self.set_list(id, state.ids.list, rect, openness, type_ahead_item,
+                                             ui);
 
                 // Close the menu if the mouse is pressed and the currently pressed widget is
                 // not any of the drop down list's children.
@@ -342,6 +332,106 @@ impl<'a, T> Widget for DropDownList<'a, T>
             state.update(|state| state.menu_state = new_menu_state);
         }
 
+        clicked_item.or(type_ahead_item)
+    }
+
+}
+
+
+impl<'a, T> DropDownList<'a, T>
+    where T: AsRef<str>,
+{
+
+    /// Set the list of item buttons beneath the top of the `DropDownList`.
+    ///
+    /// The height of the list is scaled by the given `openness` in order to animate the list
+    /// opening and closing. The list is scrolled to the item at `scroll_to`, if given.
+    ///
+    /// Returns the index of the item that was selected, if any.
+    fn set_list(&self,
+                id: widget::Id,
+                list_id: widget::Id,
+                rect: Rect,
+                openness: Scalar,
+                scroll_to: Option<Idx>,
+                ui: &mut UiCell) -> Option<Idx>
+    {
+        let style = &self.style;
+        let num_items = self.items.len();
+        let selected = self.selected.and_then(|idx| if idx < num_items { Some(idx) }
+                                                    else { None });
+        let (_, y, w, h) = rect.x_y_w_h();
+        let max_visible_height = {
+            let bottom_win_y = (-ui.window_dim()[1]) / 2.0;
+            const WINDOW_PADDING: Scalar = 20.0;
+            let max = y + h / 2.0 - bottom_win_y - WINDOW_PADDING;
+            style.maybe_max_visible_height(ui.theme()).map(|max_height| {
+                let height = match max_height {
+                    MaxHeight::Items(num) => h * num as Scalar,
+                    MaxHeight::Scalar(height) => height,
+                };
+                utils::partial_min(height, max)
+            }).unwrap_or(max)
+        };
+
+        // The list of buttons.
+        let item_h = h;
+        let list_h = max_visible_height.min(num_items as Scalar * item_h) * openness;
+        let scrollbar_color = style.border_color(&ui.theme);
+        let scrollbar_position = style.scrollbar_position(&ui.theme);
+        let scrollbar_width = style.scrollbar_width(&ui.theme)
+            .unwrap_or_else(|| {
+                ui.theme.widget_style::<widget::scrollbar::Style>()
+                    .and_then(|style| style.style.thickness)
+                    .unwrap_or(10.0)
+            });
+
+        let (mut events, scrollbar) = widget::ListSelect::single(num_items)
+            .flow_down()
+            .item_size(item_h)
+            .w_h(w, list_h)
+            .and(|ls| match scrollbar_position {
+                Some(widget::list::ScrollbarPosition::NextTo) => ls.scrollbar_next_to(),
+                Some(widget::list::ScrollbarPosition::OnTop) => ls.scrollbar_on_top(),
+                None => ls,
+            })
+            .scrollbar_color(scrollbar_color)
+            .scrollbar_thickness(scrollbar_width)
+            .mid_top_of(id)
+            .floating(true)
+            .set(list_id, ui);
+
+        // Bring the item found by type-ahead into view.
+        if let Some(item_rect) = scroll_to.and_then(|i| events.item_rect(ui, i)) {
+            ui.scroll_to_rect(list_id, item_rect, widget::scroll::Alignment::Nearest);
+        }
+
+        let mut clicked_item = None;
+        while let Some(event) = events.next_scrolled(ui, |i| Some(i) == selected) {
+            use widget::list_select::Event;
+            match event {
+
+                // Instantiate a `Button` for each item.
+                Event::Item(item) => {
+                    let i = item.i;
+                    let label = self.items[i].as_ref();
+                    let mut button = widget::Button::new().label(label);
+                    button.style = style.button_style(Some(i) == selected);
+                    item.set(button, ui);
+                },
+
+                // The selection changed.
+                Event::Selection(ix) => clicked_item = Some(ix),
+
+                _ => (),
+            }
+        }
+
+        // Instantiate the `Scrollbar` if there is one.
+        if let Some(scrollbar) = scrollbar {
+            scrollbar.set(ui);
+        }
+
         clicked_item
     }
 
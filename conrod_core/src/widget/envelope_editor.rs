@@ -1,7 +1,9 @@
 //! The `EnvelopeEditor` widget and related items.
 
 use {Color, Colorable, Borderable, FontSize, Labelable, Positionable, Sizeable, Widget};
-use num::Float;
+use color;
+use input::keyboard::ModifierKey;
+use num::{Float, NumCast};
 use position::{Direction, Edge, Point, Rect, Scalar};
 use std;
 use text;
@@ -13,6 +15,11 @@ use widget;
 ///
 /// Useful for things such as oscillator/automation envelopes or any value series represented
 /// periodically.
+///
+/// Points may be selected by clicking them, toggled in and out of the selection with shift-click,
+/// or selected within a rectangle by shift-dragging over empty space. Dragging a selected point
+/// moves the whole selection. While the editor captures the keyboard, the arrow keys nudge the
+/// selection (coarsely with shift or finely with ctrl) and delete or backspace removes it.
 #[derive(WidgetCommon_)]
 pub struct EnvelopeEditor<'a, E>
     where E: EnvelopePoint + 'a,
@@ -26,6 +33,8 @@ pub struct EnvelopeEditor<'a, E>
     min_x: E::X, max_x: E::X,
     min_y: E::Y, max_y: E::Y,
     maybe_label: Option<&'a str>,
+    maybe_snap_x: Option<E::X>,
+    maybe_snap_y: Option<E::Y>,
     style: Style,
     enabled: bool,
 }
@@ -57,6 +66,12 @@ pub struct Style {
     /// The thickness of the envelope lines.
     #[conrod(default = "2.0")]
     pub line_thickness: Option<Scalar>,
+    /// The radius of the handles used to adjust the curve of each segment.
+    #[conrod(default = "4.0")]
+    pub curve_handle_radius: Option<Scalar>,
+    /// The color of selected points and of the rubber-band selection rectangle.
+    #[conrod(default = "color::LIGHT_BLUE")]
+    pub selection_color: Option<Color>,
     /// The ID of the font used to display the label.
     #[conrod(default = "theme.font_id")]
     pub label_font_id: Option<Option<text::font::Id>>,
@@ -69,15 +84,36 @@ widget_ids! {
         value_label,
         point_path,
         points[],
+        curve_handles[],
+        rubber_band,
     }
 }
 
 /// Represents the state of the EnvelopeEditor widget.
 pub struct State {
     pressed_point: Option<usize>,
+    // The index of the point whose curve is being dragged, if any.
+    pressed_curve: Option<usize>,
+    // The indices of the selected points in ascending order.
+    selected: Vec<usize>,
+    // The corners of the rubber-band selection rectangle relative to the widget, if any.
+    rubber_band: Option<(Point, Point)>,
     ids: Ids,
 }
 
+/// The number of line segments used to draw each curved segment of the envelope.
+pub const CURVE_RESOLUTION: usize = 16;
+
+/// The number of steps across the range of an axis nudged by each arrow key press when that axis
+/// does not snap to a grid.
+pub const NUDGE_STEPS: usize = 100;
+
+/// The factor applied to the nudge step while shift is held.
+pub const COARSE_NUDGE_FACTOR: f64 = 10.0;
+
+/// The factor applied to the nudge step while ctrl is held.
+pub const FINE_NUDGE_FACTOR: f64 = 0.1;
+
 
 /// `EnvPoint` must be implemented for any type that is used as a 2D point within the
 /// EnvelopeEditor.
@@ -95,7 +131,11 @@ pub trait EnvelopePoint: Clone + PartialEq {
     /// Set the Y value.
     fn set_y(&mut self, _y: Self::Y);
     /// Return the bezier curve depth (-1. to 1.) for the next interpolation.
-    fn get_curve(&self) -> f32 { 1.0 }
+    ///
+    /// A depth of `0.0` interpolates linearly. Positive depths bend the segment towards the
+    /// corner at the next point's *x* and this point's *y*, while negative depths bend it towards
+    /// the corner at this point's *x* and the next point's *y*.
+    fn get_curve(&self) -> f32 { 0.0 }
     /// Set the bezier curve depth (-1. to 1.) for the next interpolation.
     fn set_curve(&mut self, _curve: f32) {}
     /// Create a new EnvPoint.
@@ -132,6 +172,8 @@ impl<'a, E> EnvelopeEditor<'a, E>
             min_x: min_x, max_x: max_x,
             min_y: min_y, max_y: max_y,
             maybe_label: None,
+            maybe_snap_x: None,
+            maybe_snap_y: None,
             enabled: true,
         }
     }
@@ -145,11 +187,31 @@ impl<'a, E> EnvelopeEditor<'a, E>
     builder_methods!{
         pub point_radius { style.point_radius = Some(Scalar) }
         pub line_thickness { style.line_thickness = Some(Scalar) }
+        pub curve_handle_radius { style.curve_handle_radius = Some(Scalar) }
+        pub selection_color { style.selection_color = Some(Color) }
         pub value_font_size { style.value_font_size = Some(FontSize) }
         pub skew_y { skew_y_range = f32 }
         pub enabled { enabled = bool }
     }
 
+    /// Snap the *x* value of points to multiples of the given step from `min_x` while they are
+    /// added or dragged.
+    ///
+    /// Arrow keys also nudge points by the step along *x*.
+    pub fn snap_x(mut self, step: E::X) -> Self {
+        self.maybe_snap_x = Some(step);
+        self
+    }
+
+    /// Snap the *y* value of points to multiples of the given step from `min_y` while they are
+    /// added or dragged.
+    ///
+    /// Arrow keys also nudge points by the step along *y*.
+    pub fn snap_y(mut self, step: E::Y) -> Self {
+        self.maybe_snap_y = Some(step);
+        self
+    }
+
 }
 
 
@@ -179,6 +241,13 @@ pub enum Event<E>
         /// The point's new *y* value.
         y: E::Y,
     },
+    /// Change the curve of the segment following a point.
+    SetCurve {
+        /// The index of the point whose curve should be changed.
+        i: usize,
+        /// The new bezier curve depth (-1. to 1.).
+        curve: f32,
+    },
 }
 
 
@@ -220,6 +289,12 @@ impl<E> Event<E>
                 }
             },
 
+            Event::SetCurve { i, curve } => {
+                if let Some(p) = envelope.get_mut(i) {
+                    p.set_curve(clamp(curve, -1.0, 1.0));
+                }
+            },
+
         }
     }
 
@@ -236,6 +311,9 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
             pressed_point: None,
+            pressed_curve: None,
+            selected: Vec::new(),
+            rubber_band: None,
             ids: Ids::new(id_gen),
         }
     }
@@ -254,12 +332,18 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
             min_x, max_x,
             min_y, max_y,
             maybe_label,
+            maybe_snap_x,
+            maybe_snap_y,
             ..
         } = self;
 
         let mut env = std::borrow::Cow::Borrowed(env);
 
+        // Curve handles are only offered for points that can store the curve they would set.
+        let is_curve_adjustable = env.first().map_or(false, can_store_curve);
+
         let point_radius = style.point_radius(ui.theme());
+        let curve_handle_radius = style.curve_handle_radius(ui.theme());
         let border = style.border(ui.theme());
         let rel_rect = Rect::from_xy_dim([0.0, 0.0], rect.dim());
         let inner_rel_rect = rel_rect.pad(border);
@@ -284,12 +368,12 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
             map_range(unskewed_perc, 0.0, 1.0, min_y, max_y)
         };
 
-        // Determine the left and right X bounds for a point.
-        let get_x_bounds = |env: &[E], idx: usize| -> (E::X, E::X) {
-            let len = env.len();
-            let right_bound = if len > 0 && len - 1 > idx { env[idx + 1].get_x() } else { max_x };
-            let left_bound = if len > 0 && idx > 0 { env[idx - 1].get_x() } else { min_x };
-            (left_bound, right_bound)
+        // Snaps the given values to the grid along each axis, if any.
+        let snap_x = |x: E::X| -> E::X {
+            maybe_snap_x.map(|step| clamp(snap(x, min_x, step), min_x, max_x)).unwrap_or(x)
+        };
+        let snap_y = |y: E::Y| -> E::Y {
+            maybe_snap_y.map(|step| clamp(snap(y, min_y, step), min_y, max_y)).unwrap_or(y)
         };
 
         // The index of the point that is under the given relative xy position.
@@ -308,8 +392,35 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
             None
         };
 
-        // Track the currently pressed point if any.
+        // The position of the given point relative to the centre of the widget.
+        let rel_xy = |point: &E| -> Point {
+            let x = map_x_to(point.get_x(), inner_rel_rect.left(), inner_rel_rect.right());
+            let y = map_y_to(point.get_y(), inner_rel_rect.bottom(), inner_rel_rect.top());
+            [x, y]
+        };
+
+        // The index of the point whose curve handle is under the given relative xy position.
+        let curve_handle_under_rel_xy = |env: &[E], xy: Point| -> Option<usize> {
+            if !is_curve_adjustable {
+                return None;
+            }
+            for i in 0..env.len().saturating_sub(1) {
+                let curve = env[i].get_curve() as Scalar;
+                let handle = curve_point(rel_xy(&env[i]), rel_xy(&env[i + 1]), curve, 0.5);
+                let distance = (xy[0] - handle[0]).powf(2.0)
+                             + (xy[1] - handle[1]).powf(2.0);
+                if distance <= curve_handle_radius.powf(2.0) {
+                    return Some(i);
+                }
+            }
+            None
+        };
+
+        // Track the currently pressed point and curve if any, along with the selection.
         let mut pressed_point = state.pressed_point;
+        let mut pressed_curve = state.pressed_curve;
+        let mut selected = state.selected.clone();
+        let mut rubber_band = state.rubber_band;
 
         // Handle all events that have occurred to the EnvelopeEditor since the last update.
         //
@@ -317,11 +428,21 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
         // - New points via left `Click`.
         // - Remove points via right `Click`.
         // - Dragging points via left `Drag`.
-        let mut events = Vec::new();
+        // - Dragging curves via left `Drag` of a curve handle, or of a segment while holding alt.
+        // - Selecting points via left `Click`, shift `Click` or shift `Drag`.
+        // - Nudging and removing the selected points via the keyboard.
+        let mut events: Vec<Event<E>> = Vec::new();
+        let mut n_applied_events = 0;
         'events: for widget_event in ui.widget_input(id).events() {
             use event;
             use input::{self, MouseButton};
 
+            // Keep the local version of the `env` up to date with the events yielded so far.
+            for event in &events[n_applied_events..] {
+                event.clone().update(env.to_mut());
+            }
+            n_applied_events = events.len();
+
             match widget_event {
 
                 // Upon mouse press, check for:
@@ -339,6 +460,7 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                     // Otherwise, if the mouse is not over an existing point, we want to insert
                     // a new point and begin dragging it.
                     event::Button::Mouse(MouseButton::Left, xy) => {
+                        let shift = press.modifiers.contains(ModifierKey::SHIFT);
 
                         // In this loop, we find the points on either side of the mouse to
                         // determine the insertion index, while checking if we need to break early
@@ -353,9 +475,18 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                             let distance = (xy[0] - x).powf(2.0)
                                          + (xy[1] - y).powf(2.0);
 
-                            // If the press was over a point, begin dragging it and we're done.
+                            // If the press was over a point, begin dragging it along with the rest
+                            // of the selection and we're done. Holding shift toggles its selection.
                             if distance <= point_radius.powf(2.0) {
-                                pressed_point = Some(i);
+                                match selected.binary_search(&i) {
+                                    Ok(pos) if shift => { selected.remove(pos); },
+                                    Err(pos) if shift => selected.insert(pos, i),
+                                    Ok(_) => pressed_point = Some(i),
+                                    Err(_) => {
+                                        selected = vec![i];
+                                        pressed_point = Some(i);
+                                    },
+                                }
                                 continue 'events;
                             }
 
@@ -373,45 +504,99 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                             continue 'events;
                         }
 
-                        let new_x = map_to_x(xy[0], inner_rel_rect.left(), inner_rel_rect.right());
-                        let new_y = map_to_y(xy[1], inner_rel_rect.bottom(), inner_rel_rect.top());
-                        let new_point = EnvelopePoint::new(new_x, new_y);
-
-                        // Insert the point and push an `AddPoint` event.
-                        match (maybe_left, maybe_right) {
-                            (Some(_), None) | (None, None) => {
-                                let idx = env.len();
-                                let event = Event::AddPoint { i: idx, point: new_point };
-                                pressed_point = Some(idx);
-                                events.push(event);
-                            },
-                            (None, Some(_)) => {
-                                let idx = 0;
-                                let event = Event::AddPoint { i: idx, point: new_point };
-                                pressed_point = Some(idx);
-                                events.push(event);
-                            },
-                            (Some(_), Some(idx)) => {
-                                let event = Event::AddPoint { i: idx, point: new_point };
-                                pressed_point = Some(idx);
-                                events.push(event);
+                        // If the press was over a curve handle, or over a segment while alt was
+                        // held, begin dragging its curve instead.
+                        let maybe_curve = match curve_handle_under_rel_xy(&env, xy) {
+                            Some(i) => Some(i),
+                            None => match (maybe_left, maybe_right) {
+                                (Some(i), Some(_)) if is_curve_adjustable
+                                    && press.modifiers.contains(ModifierKey::ALT) => Some(i),
+                                _ => None,
                             },
+                        };
+                        if maybe_curve.is_some() {
+                            pressed_curve = maybe_curve;
+                            continue 'events;
                         }
+
+                        // Holding shift over empty space begins a rubber-band selection.
+                        if shift {
+                            rubber_band = Some((xy, xy));
+                            continue 'events;
+                        }
+
+                        let new_x = map_to_x(xy[0], inner_rel_rect.left(), inner_rel_rect.right());
+                        let new_y = map_to_y(xy[1], inner_rel_rect.bottom(), inner_rel_rect.top());
+                        let new_x = match (maybe_left, maybe_right) {
+                            (Some(l), Some(r)) =>
+                                clamp(snap_x(new_x), env[l].get_x(), env[r].get_x()),
+                            _ => snap_x(new_x),
+                        };
+                        let new_point = EnvelopePoint::new(new_x, snap_y(new_y));
+
+                        // Insert the point, select it and push an `AddPoint` event.
+                        let idx = match (maybe_left, maybe_right) {
+                            (Some(_), None) | (None, None) => env.len(),
+                            (None, Some(_)) => 0,
+                            (Some(_), Some(idx)) => idx,
+                        };
+                        let event = Event::AddPoint { i: idx, point: new_point };
+                        pressed_point = Some(idx);
+                        selected = vec![idx];
+                        events.push(event);
                     },
 
                     // If the right mouse button was pressed over a point that is not currently
                     // being dragged, remove the point.
                     event::Button::Mouse(MouseButton::Right, xy) => {
-                        if pressed_point.is_some() || !inner_rel_rect.is_over(xy) {
+                        if pressed_point.is_some() || pressed_curve.is_some()
+                        || rubber_band.is_some() || !inner_rel_rect.is_over(xy) {
                             continue 'events;
                         }
 
                         if let Some(idx) = point_under_rel_xy(&env, xy) {
                             let event = Event::RemovePoint { i: idx };
                             events.push(event);
+                            selected.retain(|&i| i != idx);
+                            for i in &mut selected {
+                                if *i > idx {
+                                    *i -= 1;
+                                }
+                            }
                         }
                     },
 
+                    // The arrow keys nudge the selection while delete and backspace remove it.
+                    event::Button::Keyboard(key) => {
+                        let (x_steps, y_steps) = match key {
+                            input::Key::Left => (-1.0, 0.0),
+                            input::Key::Right => (1.0, 0.0),
+                            input::Key::Up => (0.0, 1.0),
+                            input::Key::Down => (0.0, -1.0),
+                            input::Key::Delete | input::Key::Backspace => {
+                                if pressed_point.is_none() {
+                                    for &i in selected.iter().rev() {
+                                        events.push(Event::RemovePoint { i: i });
+                                    }
+                                    selected.clear();
+                                }
+                                continue 'events;
+                            },
+                            _ => continue 'events,
+                        };
+                        let factor = if press.modifiers.contains(ModifierKey::SHIFT) {
+                            COARSE_NUDGE_FACTOR
+                        } else if press.modifiers.contains(ModifierKey::CTRL) {
+                            FINE_NUDGE_FACTOR
+                        } else {
+                            1.0
+                        };
+                        let dx = nudge_step(maybe_snap_x, min_x, max_x, x_steps * factor);
+                        let dy = nudge_step(maybe_snap_y, min_y, max_y, y_steps * factor);
+                        let bounds = (min_x, max_x, min_y, max_y);
+                        events.extend(move_selection(&env, &selected, dx, dy, bounds));
+                    },
+
                     _ => (),
                 },
 
@@ -419,24 +604,51 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                 event::Widget::Release(release) => {
                     if let event::Button::Mouse(MouseButton::Left, _) = release.button {
                         pressed_point = None;
+                        pressed_curve = None;
+                        rubber_band = None;
                     }
                 },
 
-                // A left `Drag` moves the `pressed_point` if there is one.
+                // A left `Drag` moves the selection along with the `pressed_point` if there is
+                // one, or otherwise resizes the rubber-band selection.
                 event::Widget::Drag(drag) if drag.button == input::MouseButton::Left => {
+                    let drag_to_x_clamped = inner_rel_rect.x.clamp_value(drag.to[0]);
+                    let drag_to_y_clamped = inner_rel_rect.y.clamp_value(drag.to[1]);
+
                     if let Some(idx) = pressed_point {
-                        let drag_to_x_clamped = inner_rel_rect.x.clamp_value(drag.to[0]);
-                        let drag_to_y_clamped = inner_rel_rect.y.clamp_value(drag.to[1]);
-                        let unbounded_x = map_to_x(drag_to_x_clamped,
-                                                   inner_rel_rect.left(),
-                                                   inner_rel_rect.right());
-                        let (left_bound, right_bound) = get_x_bounds(&env, idx);
-                        let new_x = clamp(unbounded_x, left_bound, right_bound);
-                        let new_y = map_to_y(drag_to_y_clamped,
-                                             inner_rel_rect.bottom(),
-                                             inner_rel_rect.top());
-                        let event = Event::MovePoint { i: idx, x: new_x, y: new_y };
-                        events.push(event);
+                        if idx < env.len() {
+                            let new_x = snap_x(map_to_x(drag_to_x_clamped,
+                                                        inner_rel_rect.left(),
+                                                        inner_rel_rect.right()));
+                            let new_y = snap_y(map_to_y(drag_to_y_clamped,
+                                                        inner_rel_rect.bottom(),
+                                                        inner_rel_rect.top()));
+                            let dx = new_x - env[idx].get_x();
+                            let dy = new_y - env[idx].get_y();
+                            let bounds = (min_x, max_x, min_y, max_y);
+                            events.extend(move_selection(&env, &selected, dx, dy, bounds));
+                        }
+                    }
+
+                    if let Some((start, _)) = rubber_band {
+                        let end = [drag_to_x_clamped, drag_to_y_clamped];
+                        let band = Rect::from_corners(start, end);
+                        selected = (0..env.len())
+                            .filter(|&i| band.is_over(rel_xy(&env[i])))
+                            .collect();
+                        rubber_band = Some((start, end));
+                    }
+
+                    // Bend the curve so that its midpoint follows the mouse vertically.
+                    if let Some(idx) = pressed_curve {
+                        if idx + 1 < env.len() {
+                            let (a, b) = (rel_xy(&env[idx]), rel_xy(&env[idx + 1]));
+                            let y = inner_rel_rect.y.clamp_value(drag.to[1]);
+                            if let Some(curve) = curve_from_midpoint_y(a, b, y) {
+                                let event = Event::SetCurve { i: idx, curve: curve as f32 };
+                                events.push(event);
+                            }
+                        }
                     }
                 },
 
@@ -448,8 +660,20 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
             state.update(|state| state.pressed_point = pressed_point);
         }
 
+        if state.pressed_curve != pressed_curve {
+            state.update(|state| state.pressed_curve = pressed_curve);
+        }
+
+        if state.selected != selected {
+            state.update(|state| state.selected = selected);
+        }
+
+        if state.rubber_band != rubber_band {
+            state.update(|state| state.rubber_band = rubber_band);
+        }
+
         // Ensure that the local version of the `env` is up to date for drawing.
-        for event in &events {
+        for event in &events[n_applied_events..] {
             event.clone().update(env.to_mut());
         }
 
@@ -483,14 +707,32 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                 .set(state.ids.label, ui);
         }
 
+        // The absolute position of the given point.
+        let abs_xy = |point: &E| -> Point {
+            let x = map_x_to(point.get_x(), inner_rect.left(), inner_rect.right());
+            let y = map_y_to(point.get_y(), inner_rect.bottom(), inner_rect.top());
+            [x, y]
+        };
+
         let line_color = label_color.with_alpha(1.0);
+        let selection_color = style.selection_color(ui.theme());
         {
             let thickness = style.line_thickness(ui.theme());
-            let points = env.iter().map(|point| {
-                let x = map_x_to(point.get_x(), inner_rect.left(), inner_rect.right());
-                let y = map_y_to(point.get_y(), inner_rect.bottom(), inner_rect.top());
-                [x, y]
-            });
+            let mut points = Vec::with_capacity(env.len());
+            for (i, point) in env.iter().enumerate() {
+                let a = abs_xy(point);
+                points.push(a);
+                let curve = point.get_curve() as Scalar;
+                if let Some(next) = env.get(i + 1) {
+                    if curve != 0.0 {
+                        let b = abs_xy(next);
+                        let step = 1.0 / CURVE_RESOLUTION as Scalar;
+                        let curve_points = (1..CURVE_RESOLUTION)
+                            .map(|j| curve_point(a, b, curve, j as Scalar * step));
+                        points.extend(curve_points);
+                    }
+                }
+            }
             widget::PointPath::new(points)
                 .wh(inner_rect.dim())
                 .xy(inner_rect.xy())
@@ -510,8 +752,12 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
         for (i, (&point_id, point)) in iter {
             let x = map_x_to(point.get_x(), inner_rect.left(), inner_rect.right());
             let y = map_y_to(point.get_y(), inner_rect.bottom(), inner_rect.top());
+            let point_color = match state.selected.binary_search(&i) {
+                Ok(_) => selection_color,
+                Err(_) => line_color,
+            };
             let point_color = if state.pressed_point == Some(i) {
-                line_color.clicked()
+                point_color.clicked()
             } else {
                 ui.widget_input(id).mouse()
                     .and_then(|mouse| {
@@ -519,12 +765,12 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                         let distance = (mouse_abs_xy[0] - x).powf(2.0)
                                      + (mouse_abs_xy[1] - y).powf(2.0);
                         if distance <= point_radius.powf(2.0) {
-                            Some(line_color.highlighted())
+                            Some(point_color.highlighted())
                         } else {
                             None
                         }
                     })
-                    .unwrap_or(line_color)
+                    .unwrap_or(point_color)
             };
             widget::Circle::fill(point_radius)
                 .color(point_color)
@@ -534,6 +780,54 @@ impl<'a, E> Widget for EnvelopeEditor<'a, E>
                 .set(point_id, &mut ui);
         }
 
+        // Ensure we have a curve handle widget for every segment between points.
+        let n_segments = env.len().saturating_sub(1);
+        if state.ids.curve_handles.len() < n_segments {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| state.ids.curve_handles.resize(n_segments, id_gen));
+        }
+
+        let iter = state.ids.curve_handles.iter().zip(env.windows(2)).enumerate()
+            .filter(|_| is_curve_adjustable);
+        for (i, (&handle_id, segment)) in iter {
+            let curve = segment[0].get_curve() as Scalar;
+            let xy = curve_point(abs_xy(&segment[0]), abs_xy(&segment[1]), curve, 0.5);
+            let handle_color = line_color.with_alpha(0.5);
+            let handle_color = if state.pressed_curve == Some(i) {
+                handle_color.clicked()
+            } else {
+                ui.widget_input(id).mouse()
+                    .and_then(|mouse| {
+                        let mouse_abs_xy = mouse.abs_xy();
+                        let distance = (mouse_abs_xy[0] - xy[0]).powf(2.0)
+                                     + (mouse_abs_xy[1] - xy[1]).powf(2.0);
+                        if distance <= curve_handle_radius.powf(2.0) {
+                            Some(handle_color.highlighted())
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(handle_color)
+            };
+            widget::Circle::fill(curve_handle_radius)
+                .color(handle_color)
+                .xy(xy)
+                .graphics_for(id)
+                .parent(id)
+                .set(handle_id, &mut ui);
+        }
+
+        // Outline the rubber-band selection if there is one.
+        if let Some((start, end)) = state.rubber_band {
+            let band = Rect::from_corners(start, end).shift(rect.xy());
+            widget::Rectangle::outline(band.dim())
+                .xy(band.xy())
+                .color(selection_color)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.rubber_band, ui);
+        }
+
         // Find the closest point to the mouse.
         let maybe_closest_point = ui.widget_input(id).mouse().and_then(|mouse| {
             let mut closest_distance = ::std::f64::MAX;
@@ -614,3 +908,108 @@ impl<'a, E> Labelable<'a> for EnvelopeEditor<'a, E>
         label_font_size { style.label_font_size = Some(FontSize) }
     }
 }
+
+
+/// Whether or not the given point stores the curve depth passed to `EnvelopePoint::set_curve`.
+///
+/// This is not the case for point types that rely on the default `set_curve`, e.g. `Point`.
+pub fn can_store_curve<E>(point: &E) -> bool
+    where E: EnvelopePoint,
+{
+    let curve = if point.get_curve() == 0.5 { -0.5 } else { 0.5 };
+    let mut probe = point.clone();
+    probe.set_curve(curve);
+    probe.get_curve() == curve
+}
+
+/// The control point of the quadratic bezier curve from `a` to `b` with the given curve depth.
+///
+/// See `EnvelopePoint::get_curve`.
+pub fn curve_control_point(a: Point, b: Point, curve: Scalar) -> Point {
+    let mid = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+    let corner = [b[0], a[1]];
+    [mid[0] + (corner[0] - mid[0]) * curve, mid[1] + (corner[1] - mid[1]) * curve]
+}
+
+/// The point at `t` (`0.0` to `1.0`) along the curve from `a` to `b` with the given curve depth.
+pub fn curve_point(a: Point, b: Point, curve: Scalar, t: Scalar) -> Point {
+    let c = curve_control_point(a, b, curve);
+    let u = 1.0 - t;
+    [
+        u * u * a[0] + 2.0 * u * t * c[0] + t * t * b[0],
+        u * u * a[1] + 2.0 * u * t * c[1] + t * t * b[1],
+    ]
+}
+
+/// The curve depth with which the midpoint of the curve from `a` to `b` lies at the given `y`.
+///
+/// Returns `None` if `a` and `b` are at the same height, in which case the depth has no effect.
+pub fn curve_from_midpoint_y(a: Point, b: Point, y: Scalar) -> Option<Scalar> {
+    let dy = a[1] - b[1];
+    if dy == 0.0 {
+        return None;
+    }
+    let mid_y = (a[1] + b[1]) / 2.0;
+    Some(clamp(4.0 * (y - mid_y) / dy, -1.0, 1.0))
+}
+
+// Snap the given value to the nearest multiple of `step` from `start`.
+fn snap<F: Float>(value: F, start: F, step: F) -> F {
+    if step <= F::zero() {
+        return value;
+    }
+    start + ((value - start) / step).round() * step
+}
+
+// The distance moved by a nudge of the given number of steps along an axis.
+//
+// Each step is the snapping step if there is one, or otherwise `1 / NUDGE_STEPS` of the range.
+fn nudge_step<F: Float>(maybe_snap: Option<F>, min: F, max: F, steps: f64) -> F {
+    let n_steps: F = NumCast::from(NUDGE_STEPS).unwrap();
+    let step = maybe_snap.unwrap_or((max - min) / n_steps);
+    let steps: F = NumCast::from(steps).unwrap();
+    step * steps
+}
+
+// The events that move each of the `selected` points by the given distance.
+//
+// The distance is clamped so that the selection keeps its shape, i.e. so that no point passes a
+// point that is not selected or leaves the bounds of the envelope.
+fn move_selection<E>(
+    env: &[E],
+    selected: &[usize],
+    dx: E::X,
+    dy: E::Y,
+    (min_x, max_x, min_y, max_y): (E::X, E::X, E::Y, E::Y),
+) -> Vec<Event<E>>
+    where E: EnvelopePoint,
+{
+    let selected: Vec<usize> = selected.iter().cloned().filter(|&i| i < env.len()).collect();
+    let (mut dx_min, mut dx_max) = (min_x - max_x, max_x - min_x);
+    let (mut dy_min, mut dy_max) = (min_y - max_y, max_y - min_y);
+    for &i in &selected {
+        let (x, y) = (env[i].get_x(), env[i].get_y());
+        let is_unselected = |j: &usize| selected.binary_search(j).is_err();
+        let left = (0..i).rev().find(&is_unselected).map(|j| env[j].get_x());
+        let left = left.unwrap_or(min_x);
+        let right = (i + 1..env.len()).find(&is_unselected).map(|j| env[j].get_x());
+        let right = right.unwrap_or(max_x);
+        dx_min = dx_min.max(left - x);
+        dx_max = dx_max.min(right - x);
+        dy_min = dy_min.max(min_y - y);
+        dy_max = dy_max.min(max_y - y);
+    }
+    let dx = clamp(dx, dx_min, dx_max);
+    let dy = clamp(dy, dy_min, dy_max);
+
+    // Move the points that lead in the direction of movement first so that none are clamped by
+    // selected neighbours that have yet to move.
+    let move_point = |i: usize| {
+        Event::MovePoint { i: i, x: env[i].get_x() + dx, y: env[i].get_y() + dy }
+    };
+    if dx.is_sign_positive() {
+        selected.iter().rev().map(|&i| move_point(i)).collect()
+    } else {
+        selected.iter().map(|&i| move_point(i)).collect()
+    }
+}
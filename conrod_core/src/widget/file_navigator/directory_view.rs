@@ -281,6 +281,10 @@ impl<'a> Widget for DirectoryView<'a> {
                 .flow_down()
                 .item_size(file_h)
                 .scrollbar_on_top()
+                .type_ahead(|i| {
+                    state.entries[i].path.file_name()
+                        .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+                })
                 .w_h(rect.w(), list_h)
                 .mid_top_of(id)
                 .set(state.ids.list_select, ui);
@@ -290,7 +294,7 @@ impl<'a> Widget for DirectoryView<'a> {
             .filter_map(|e| if e.is_selected { Some(e.path.clone()) } else { None })
             .collect();
 
-        while let Some(event) = list_events.next(ui, |i| state.entries[i].is_selected) {
+        while let Some(event) = list_events.next_scrolled(ui, |i| state.entries[i].is_selected) {
             use widget::list_select;
 
             match event {
@@ -358,6 +362,9 @@ impl<'a> Widget for DirectoryView<'a> {
                     events.push(Event::Press(e, collect_selected(&state.entries))),
                 list_select::Event::Release(e) =>
                     events.push(Event::Release(e, collect_selected(&state.entries))),
+
+                // The directory view is never reorderable.
+                list_select::Event::Move(_) => (),
             }
         }
 
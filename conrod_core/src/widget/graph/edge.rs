@@ -0,0 +1,464 @@
+//! A default widget for drawing the edges that connect the sockets of nodes within a `Graph`.
+
+use {color, widget, Color, Colorable, Point, Rect, Scalar, Theme, Ui, Widget};
+use graph;
+use position::{Dimension, Position};
+use std::iter::once;
+use utils;
+use super::node::{self, SocketSide, SocketType};
+use super::{Edge, NodeId};
+use widget::triangles::Triangle;
+
+/// A widget that draws a connection between two sockets.
+///
+/// The connection leaves each socket perpendicular to the socket's `SocketSide`. The bounding
+/// rectangle is fit to the absolute co-ordinates of the connection's path, however only the
+/// path itself may be hovered or clicked.
+#[derive(Clone, Debug, WidgetCommon_)]
+pub struct Connector {
+    /// Data necessary and common for all widget builder types.
+    #[conrod(common_builder)]
+    pub common: widget::CommonBuilder,
+    /// Unique styling for the **Connector**.
+    pub style: Style,
+    /// The absolute position of the start of the connection.
+    pub start: Point,
+    /// The side of the node on which the start socket lies.
+    pub start_side: SocketSide,
+    /// The absolute position of the end of the connection.
+    pub end: Point,
+    /// The side of the node on which the end socket lies.
+    pub end_side: SocketSide,
+    /// The shape of the path between the sockets.
+    pub route: Route,
+    /// Rectangles that the path should avoid when the `route` is `Orthogonal`.
+    pub obstacles: Vec<Rect>,
+    /// The scale applied to the thickness, arrowhead and socket clearance of the connection.
+    ///
+    /// This should normally be the zoom of the `Graph`'s camera, i.e. `Edge::zoom`.
+    pub zoom: Scalar,
+}
+
+/// The shape of the path between two sockets.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Route {
+    /// A straight line from socket to socket.
+    Straight,
+    /// A cubic bezier curve whose control points extend outwards from each socket.
+    Bezier,
+    /// Horizontal and vertical segments that avoid the `Connector`'s obstacles where possible.
+    Orthogonal,
+}
+
+/// Unique styling for the **Connector** widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the connection.
+    #[conrod(default = "color::DARK_CHARCOAL")]
+    pub color: Option<Color>,
+    /// The thickness of the connection.
+    #[conrod(default = "3.0")]
+    pub thickness: Option<Scalar>,
+    /// The length of the arrowhead drawn at the end of the connection.
+    ///
+    /// A length of `0.0` draws no arrowhead.
+    #[conrod(default = "0.0")]
+    pub arrow_length: Option<Scalar>,
+    /// The distance that the path extends straight out of each socket before turning.
+    #[conrod(default = "20.0")]
+    pub clearance: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        path,
+        arrow,
+    }
+}
+
+/// Unique state for the **Connector**.
+pub struct State {
+    ids: Ids,
+    // The absolute points along the path as of the last update, used for hit-testing.
+    points: Vec<Point>,
+    // The thickness of the path as of the last update.
+    thickness: Scalar,
+}
+
+/// The interactions with a **Connector** that occurred during an update.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Event {
+    /// Whether or not the mouse is over the connection.
+    pub is_hovered: bool,
+    /// Whether or not the connection was clicked with the left mouse button.
+    pub was_clicked: bool,
+}
+
+/// The number of line segments used to approximate a bezier curve.
+pub const BEZIER_RESOLUTION: usize = 32;
+
+/// The distance from the path within which the mouse is considered to be over a **Connector**,
+/// in addition to half its thickness.
+pub const HIT_PADDING: Scalar = 4.0;
+
+impl Connector {
+    /// Begin building a bezier **Connector** between the given absolute points.
+    pub fn new(start: Point, start_side: SocketSide, end: Point, end_side: SocketSide) -> Self {
+        Connector {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            start,
+            start_side,
+            end,
+            end_side,
+            route: Route::Bezier,
+            obstacles: Vec::new(),
+            zoom: 1.0,
+        }
+    }
+
+    /// Specify the rectangles that the path should avoid when orthogonally routed.
+    pub fn obstacles<I>(mut self, obstacles: I) -> Self
+    where
+        I: IntoIterator<Item=Rect>,
+    {
+        self.obstacles = obstacles.into_iter().collect();
+        self
+    }
+
+    /// Draw the connection as a straight line.
+    pub fn straight(self) -> Self {
+        self.route(Route::Straight)
+    }
+
+    /// Draw the connection as a bezier curve.
+    pub fn bezier(self) -> Self {
+        self.route(Route::Bezier)
+    }
+
+    /// Route the connection with horizontal and vertical segments around the obstacles.
+    pub fn orthogonal(self) -> Self {
+        self.route(Route::Orthogonal)
+    }
+
+    /// Draw an arrowhead of the given length at the end of the connection.
+    pub fn arrow(mut self, length: Scalar) -> Self {
+        self.style.arrow_length = Some(length);
+        self
+    }
+
+    builder_methods!{
+        pub route { route = Route }
+        pub zoom { zoom = Scalar }
+        pub thickness { style.thickness = Some(Scalar) }
+        pub clearance { style.clearance = Some(Scalar) }
+    }
+
+    /// The absolute points along the path of the connection.
+    pub fn path(&self, theme: &Theme) -> Vec<Point> {
+        let Connector { start, start_side, end, end_side, .. } = *self;
+        match self.route {
+            Route::Straight => vec![start, end],
+            Route::Bezier => bezier_path(start, start_side, end, end_side),
+            Route::Orthogonal => {
+                let clearance = self.style.clearance(theme) * self.zoom;
+                orthogonal_path(start, start_side, end, end_side, &self.obstacles, clearance)
+            },
+        }
+    }
+
+    // The bounding rectangle of the path, padded to include its thickness and arrowhead.
+    fn bounding_rect(&self, theme: &Theme) -> Rect {
+        let path = self.path(theme);
+        let first = Rect::from_xy_dim(path[0], [0.0, 0.0]);
+        let rect = path.iter().fold(first, |rect, &p| rect.max(Rect::from_xy_dim(p, [0.0, 0.0])));
+        let thickness = self.style.thickness(theme).max(self.style.arrow_length(theme));
+        rect.pad(-(thickness * self.zoom / 2.0 + HIT_PADDING))
+    }
+}
+
+impl Widget for Connector {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            points: Vec::new(),
+            thickness: 0.0,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    fn default_x_position(&self, ui: &Ui) -> Position {
+        Position::Absolute(self.bounding_rect(&ui.theme).x())
+    }
+
+    fn default_y_position(&self, ui: &Ui) -> Position {
+        Position::Absolute(self.bounding_rect(&ui.theme).y())
+    }
+
+    fn default_x_dimension(&self, ui: &Ui) -> Dimension {
+        Dimension::Absolute(self.bounding_rect(&ui.theme).w())
+    }
+
+    fn default_y_dimension(&self, ui: &Ui) -> Dimension {
+        Dimension::Absolute(self.bounding_rect(&ui.theme).h())
+    }
+
+    fn is_over(&self) -> widget::IsOverFn {
+        is_over_widget
+    }
+
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, style, rect, ui, .. } = args;
+        let points = self.path(&ui.theme);
+        let thickness = style.thickness(&ui.theme) * self.zoom;
+        let arrow_length = style.arrow_length(&ui.theme) * self.zoom;
+
+        if state.points != points {
+            state.update(|state| state.points = points.clone());
+        }
+        if state.thickness != thickness {
+            state.update(|state| state.thickness = thickness);
+        }
+
+        let input = ui.widget_input(id);
+        let event = Event {
+            is_hovered: input.mouse().is_some(),
+            was_clicked: input.clicks().left().next().is_some(),
+        };
+        let color = style.color(&ui.theme);
+        let color = match input.mouse() {
+            Some(mouse) if mouse.buttons.left().is_down() => color.clicked(),
+            Some(_) => color.highlighted(),
+            None => color,
+        };
+
+        // The path stops short of the end to leave room for the arrowhead.
+        let maybe_arrow = match arrow_length > 0.0 {
+            true => arrow_triangle(&points, arrow_length),
+            false => None,
+        };
+        let mut path = points;
+        if let Some(Triangle([_, base_a, base_b])) = maybe_arrow {
+            let last = path.len() - 1;
+            path[last] = [(base_a[0] + base_b[0]) / 2.0, (base_a[1] + base_b[1]) / 2.0];
+        }
+
+        widget::PointPath::abs(path)
+            .thickness(thickness)
+            .color(color)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.path, ui);
+
+        if let Some(triangle) = maybe_arrow {
+            widget::Triangles::single_color(color, once(triangle))
+                .with_bounding_rect(rect)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.arrow, ui);
+        }
+
+        event
+    }
+}
+
+impl Colorable for Connector {
+    fn color(mut self, color: Color) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+}
+
+/// A bezier **Connector** between the sockets of the given edge.
+///
+/// The connector's zoom is that of the graph's camera and its obstacles are the rectangles of
+/// every node in the graph, ready to be routed orthogonally via `Connector::orthogonal`.
+///
+/// **Panic!**s under the same conditions as `node::edge_socket_rects`.
+pub fn connector<NI>(edge: &Edge<NI>, ui: &Ui) -> Connector
+where
+    NI: NodeId,
+{
+    let (start_id, end_id) = super::edge_node_widget_ids(edge, ui);
+    let (start_rect, end_rect) = node::edge_socket_rects(edge, ui);
+    let start_side = node::socket_side(start_id, SocketType::Output, ui)
+        .unwrap_or(SocketSide::Right);
+    let end_side = node::socket_side(end_id, SocketType::Input, ui)
+        .unwrap_or(SocketSide::Left);
+    let obstacles: Vec<Rect> = {
+        let shared = edge.shared.lock().unwrap();
+        shared.widget_id_map.node_widget_ids.values().filter_map(|&w| ui.rect_of(w)).collect()
+    };
+    Connector::new(start_rect.xy(), start_side, end_rect.xy(), end_side)
+        .zoom(edge.zoom())
+        .obstacles(obstacles)
+}
+
+/// The unit vector pointing outwards from the given side of a node.
+pub fn side_direction(side: SocketSide) -> Point {
+    match side {
+        SocketSide::Left => [-1.0, 0.0],
+        SocketSide::Right => [1.0, 0.0],
+        SocketSide::Top => [0.0, 1.0],
+        SocketSide::Bottom => [0.0, -1.0],
+    }
+}
+
+/// Points along a cubic bezier curve that leaves `start` and enters `end` perpendicular to their
+/// sides.
+pub fn bezier_path(start: Point, start_side: SocketSide, end: Point, end_side: SocketSide)
+    -> Vec<Point>
+{
+    let distance = (end[0] - start[0]).hypot(end[1] - start[1]);
+    let reach = (distance / 2.0).max(20.0);
+    let (a, b) = (side_direction(start_side), side_direction(end_side));
+    let c1 = [start[0] + a[0] * reach, start[1] + a[1] * reach];
+    let c2 = [end[0] + b[0] * reach, end[1] + b[1] * reach];
+    (0..BEZIER_RESOLUTION + 1)
+        .map(|i| {
+            let t = i as Scalar / BEZIER_RESOLUTION as Scalar;
+            let u = 1.0 - t;
+            let (w0, w1, w2, w3) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            [
+                w0 * start[0] + w1 * c1[0] + w2 * c2[0] + w3 * end[0],
+                w0 * start[1] + w1 * c1[1] + w2 * c2[1] + w3 * end[1],
+            ]
+        })
+        .collect()
+}
+
+/// Points along a path of horizontal and vertical segments from `start` to `end`.
+///
+/// The path leaves each socket perpendicular to its side for the given `clearance`. Between
+/// these, the shortest of a number of candidate routes that crosses none of the `obstacles` is
+/// chosen, falling back to a route through the midpoint if every candidate is obstructed.
+pub fn orthogonal_path(
+    start: Point,
+    start_side: SocketSide,
+    end: Point,
+    end_side: SocketSide,
+    obstacles: &[Rect],
+    clearance: Scalar,
+) -> Vec<Point>
+{
+    let (a, b) = (side_direction(start_side), side_direction(end_side));
+    let s = [start[0] + a[0] * clearance, start[1] + a[1] * clearance];
+    let e = [end[0] + b[0] * clearance, end[1] + b[1] * clearance];
+
+    // Only the obstacles near the route are considered when searching for detours.
+    let span = Rect::from_corners(s, e).pad(-clearance);
+    let nearby = obstacles.iter().filter(|r| r.overlap(span).is_some());
+
+    // Routes via a vertical segment at some `x` or a horizontal segment at some `y`.
+    let via_x = |x: Scalar| vec![s, [x, s[1]], [x, e[1]], e];
+    let via_y = |y: Scalar| vec![s, [s[0], y], [e[0], y], e];
+    let midpoint = via_x((s[0] + e[0]) / 2.0);
+    let mut candidates = vec![via_y((s[1] + e[1]) / 2.0), via_x(s[0]), via_x(e[0])];
+    for r in nearby {
+        candidates.push(via_x(r.left() - clearance));
+        candidates.push(via_x(r.right() + clearance));
+        candidates.push(via_y(r.bottom() - clearance));
+        candidates.push(via_y(r.top() + clearance));
+    }
+
+    let is_clear = |route: &[Point]| {
+        route.windows(2).all(|w| {
+            let segment = Rect::from_corners(w[0], w[1]);
+            obstacles.iter().all(|r| !is_crossed(*r, segment))
+        })
+    };
+    let length = |route: &[Point]| {
+        route.windows(2)
+            .map(|w| (w[1][0] - w[0][0]).abs() + (w[1][1] - w[0][1]).abs())
+            .sum::<Scalar>()
+    };
+    let route = once(midpoint.clone())
+        .chain(candidates)
+        .filter(|route| is_clear(route))
+        .fold(None, |best: Option<Vec<Point>>, route| match best {
+            Some(ref best) if length(best) <= length(&route) => Some(best.clone()),
+            _ => Some(route),
+        })
+        .unwrap_or(midpoint);
+
+    // Join the sockets to the route, skipping any points that would not change direction.
+    let mut path: Vec<Point> = vec![start];
+    for p in route.into_iter().chain(once(end)) {
+        if path.last() == Some(&p) {
+            continue;
+        }
+        if path.len() >= 2 {
+            let (p0, p1) = (path[path.len() - 2], path[path.len() - 1]);
+            let is_vertical = p0[0] == p1[0] && p1[0] == p[0];
+            let is_horizontal = p0[1] == p1[1] && p1[1] == p[1];
+            if is_vertical || is_horizontal {
+                path.pop();
+            }
+        }
+        path.push(p);
+    }
+    path
+}
+
+/// The distance from the given point to the nearest point on the given path.
+pub fn distance_to_path(path: &[Point], point: Point) -> Scalar {
+    let distance_to_segment = |a: Point, b: Point| {
+        let ab = [b[0] - a[0], b[1] - a[1]];
+        let ap = [point[0] - a[0], point[1] - a[1]];
+        let len_sq = ab[0] * ab[0] + ab[1] * ab[1];
+        let t = match len_sq > 0.0 {
+            true => utils::clamp((ap[0] * ab[0] + ap[1] * ab[1]) / len_sq, 0.0, 1.0),
+            false => 0.0,
+        };
+        let closest = [a[0] + ab[0] * t, a[1] + ab[1] * t];
+        (point[0] - closest[0]).hypot(point[1] - closest[1])
+    };
+    match path.len() {
+        0 => Scalar::MAX,
+        1 => distance_to_segment(path[0], path[0]),
+        _ => path.windows(2)
+            .map(|w| distance_to_segment(w[0], w[1]))
+            .fold(Scalar::MAX, Scalar::min),
+    }
+}
+
+/// The function to use for picking whether a given point is over the connector.
+pub fn is_over_widget(widget: &graph::Container, point: Point, _: &Theme) -> widget::IsOver {
+    widget
+        .state_and_style::<State, Style>()
+        .map(|unique| {
+            let State { ref points, thickness, .. } = unique.state;
+            distance_to_path(points, point) <= thickness / 2.0 + HIT_PADDING
+        })
+        .unwrap_or_else(|| widget.rect.is_over(point))
+        .into()
+}
+
+// Whether the given axis-aligned segment (described by its bounding rectangle) passes through the
+// interior of the given rectangle.
+fn is_crossed(rect: Rect, segment: Rect) -> bool {
+    segment.left() < rect.right() && segment.right() > rect.left()
+        && segment.bottom() < rect.top() && segment.top() > rect.bottom()
+}
+
+// The arrowhead pointing into the last point of the path, as the tip followed by the two corners
+// of its base.
+fn arrow_triangle(path: &[Point], length: Scalar) -> Option<Triangle<Point>> {
+    let tip = *path.last()?;
+    let from = path.iter().rev().find(|&&p| p != tip)?;
+    let d = [tip[0] - from[0], tip[1] - from[1]];
+    let len = d[0].hypot(d[1]);
+    let unit = [d[0] / len, d[1] / len];
+    let normal = [-unit[1] * length / 2.0, unit[0] * length / 2.0];
+    let base = [tip[0] - unit[0] * length, tip[1] - unit[1] * length];
+    let a = [base[0] + normal[0], base[1] + normal[1]];
+    let b = [base[0] - normal[0], base[1] - normal[1]];
+    Some(Triangle([tip, a, b]))
+}
@@ -0,0 +1,443 @@
+//! Algorithms for automatically positioning the nodes of a `Graph`.
+//!
+//! Each algorithm produces a `Layout` in graph space from the same node and edge iterators that
+//! are given to `Graph::new`.
+//!
+//! - `Grid` places nodes in rows and columns, ignoring edges.
+//! - `Layered` places nodes in columns such that edges flow from left to right. This suits
+//!   directed acyclic graphs, though cycles are tolerated.
+//! - `ForceDirected` treats edges as springs and nodes as repelling charges. This suits general
+//!   graphs and may be stepped once per frame to animate nodes into place.
+//!
+//! The target `Layout` of `Grid` and `Layered` may be animated towards via `animate_towards`.
+
+use super::{Layout, NodeId, NodeSocket};
+use std::collections::HashMap;
+use utils;
+use {Point, Scalar};
+
+/// Places nodes in rows and columns in the order in which they are given.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Grid {
+    /// The distance between the centres of neighbouring nodes along the *x* and *y* axes.
+    pub spacing: [Scalar; 2],
+    /// The number of columns. If `None`, the grid will be as close to square as possible.
+    pub columns: Option<usize>,
+}
+
+/// Places nodes in layers (Sugiyama-style) so that edges flow from left to right.
+///
+/// 1. Cycles are broken by reversing the edges that lead back to an ancestor.
+/// 2. Each node is assigned the layer of the longest path from a source node.
+/// 3. Nodes are ordered within their layer by repeatedly sweeping through the layers and sorting
+///    each node by the mean position of its neighbours, reducing the number of edge crossings.
+///    Edges spanning more than one layer are routed through virtual nodes during this stage.
+/// 4. Layers are placed side by side with their nodes centred vertically.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Layered {
+    /// The distance between the centres of neighbouring layers.
+    pub layer_spacing: Scalar,
+    /// The distance between the centres of neighbouring nodes within a layer.
+    pub node_spacing: Scalar,
+    /// The number of sweeps used to reduce edge crossings.
+    pub sweeps: usize,
+}
+
+/// Positions nodes by simulating edges as springs and nodes as repelling charges
+/// (Fruchterman-Reingold).
+///
+/// The simulation cools over time, limiting how far each node may move per iteration, until the
+/// layout settles. Use `step` to advance the simulation incrementally, e.g. once per frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ForceDirected {
+    /// The ideal distance between connected nodes.
+    pub ideal_length: Scalar,
+    /// The number of iterations performed by each call to `step`.
+    pub iterations_per_step: usize,
+    /// The factor by which the temperature is multiplied after each iteration.
+    pub cooling: Scalar,
+    /// The temperature below which the layout is considered settled.
+    pub min_temperature: Scalar,
+    /// The maximum distance any node may move during the next iteration.
+    pub temperature: Scalar,
+}
+
+/// The default distance between the centres of neighbouring nodes.
+pub const DEFAULT_SPACING: Scalar = 150.0;
+
+impl Default for Grid {
+    fn default() -> Self {
+        Grid {
+            spacing: [DEFAULT_SPACING, DEFAULT_SPACING],
+            columns: None,
+        }
+    }
+}
+
+impl Default for Layered {
+    fn default() -> Self {
+        Layered {
+            layer_spacing: DEFAULT_SPACING * 1.5,
+            node_spacing: DEFAULT_SPACING * 0.75,
+            sweeps: 8,
+        }
+    }
+}
+
+impl Default for ForceDirected {
+    fn default() -> Self {
+        ForceDirected {
+            ideal_length: DEFAULT_SPACING,
+            iterations_per_step: 1,
+            cooling: 0.97,
+            min_temperature: 0.5,
+            temperature: DEFAULT_SPACING,
+        }
+    }
+}
+
+impl Grid {
+    /// Position the given nodes in a grid centred on the origin.
+    pub fn layout<NI, N>(&self, nodes: N) -> Layout<NI>
+    where
+        NI: NodeId,
+        N: IntoIterator<Item=NI>,
+    {
+        let nodes: Vec<NI> = nodes.into_iter().collect();
+        let columns = self.columns
+            .unwrap_or_else(|| (nodes.len() as Scalar).sqrt().ceil() as usize)
+            .max(1);
+        let rows = if nodes.is_empty() { 0 } else { (nodes.len() - 1) / columns + 1 };
+        let (spacing_x, spacing_y) = (self.spacing[0], self.spacing[1]);
+        let w = (columns.min(nodes.len()).max(1) - 1) as Scalar * spacing_x;
+        let h = (rows.max(1) - 1) as Scalar * spacing_y;
+        let map = nodes.into_iter()
+            .enumerate()
+            .map(|(i, node_id)| {
+                let x = (i % columns) as Scalar * spacing_x - w / 2.0;
+                let y = h / 2.0 - (i / columns) as Scalar * spacing_y;
+                (node_id, [x, y])
+            })
+            .collect();
+        Layout { map }
+    }
+}
+
+impl Layered {
+    /// Position the given nodes in layers centred on the origin.
+    pub fn layout<NI, N, E>(&self, nodes: N, edges: E) -> Layout<NI>
+    where
+        NI: NodeId,
+        N: IntoIterator<Item=NI>,
+        E: IntoIterator<Item=(NodeSocket<NI>, NodeSocket<NI>)>,
+    {
+        let (nodes, edges) = indexed(nodes, edges);
+        let n = nodes.len();
+        let edges = acyclic_edges(n, &edges);
+        let layers = longest_path_layers(n, &edges);
+
+        // Route each edge through a virtual node in each layer that it passes through.
+        let mut node_layers = layers.clone();
+        let mut segments = vec![];
+        for &(a, b) in &edges {
+            let mut prev = a;
+            for layer in layers[a] + 1..layers[b] {
+                let virtual_node = node_layers.len();
+                node_layers.push(layer);
+                segments.push((prev, virtual_node));
+                prev = virtual_node;
+            }
+            segments.push((prev, b));
+        }
+
+        // Order the nodes within each layer, beginning with the order in which they were given.
+        let n_layers = layers.iter().map(|&l| l + 1).max().unwrap_or(0);
+        let mut order: Vec<Vec<usize>> = vec![vec![]; n_layers];
+        for (i, &layer) in node_layers.iter().enumerate() {
+            order[layer].push(i);
+        }
+        let mut predecessors = vec![vec![]; node_layers.len()];
+        let mut successors = vec![vec![]; node_layers.len()];
+        for &(a, b) in &segments {
+            successors[a].push(b);
+            predecessors[b].push(a);
+        }
+        for sweep in 0..self.sweeps {
+            if sweep % 2 == 0 {
+                for layer in 1..n_layers {
+                    let (fixed, free) = order.split_at_mut(layer);
+                    sort_by_barycenter(&mut free[0], &fixed[layer - 1], &predecessors);
+                }
+            } else {
+                for layer in (0..n_layers.saturating_sub(1)).rev() {
+                    let (free, fixed) = order.split_at_mut(layer + 1);
+                    sort_by_barycenter(&mut free[layer], &fixed[0], &successors);
+                }
+            }
+        }
+
+        // Place the layers side by side, centring the nodes of each layer on the *x* axis.
+        let w = n_layers.saturating_sub(1) as Scalar * self.layer_spacing;
+        let mut map = HashMap::with_capacity(n);
+        for (layer, layer_order) in order.iter().enumerate() {
+            let x = layer as Scalar * self.layer_spacing - w / 2.0;
+            let h = layer_order.len().saturating_sub(1) as Scalar * self.node_spacing;
+            for (i, &node) in layer_order.iter().enumerate() {
+                if node < n {
+                    let y = h / 2.0 - i as Scalar * self.node_spacing;
+                    map.insert(nodes[node], [x, y]);
+                }
+            }
+        }
+        Layout { map }
+    }
+}
+
+impl ForceDirected {
+    /// Whether or not the simulation has cooled enough for the layout to be considered settled.
+    pub fn is_settled(&self) -> bool {
+        self.temperature < self.min_temperature
+    }
+
+    /// Position the given nodes by running the simulation until the layout settles.
+    ///
+    /// Nodes begin on a spiral around the origin.
+    pub fn layout<NI, N, E>(mut self, nodes: N, edges: E) -> Layout<NI>
+    where
+        NI: NodeId,
+        N: IntoIterator<Item=NI>,
+        E: IntoIterator<Item=(NodeSocket<NI>, NodeSocket<NI>)>,
+    {
+        let (nodes, edges) = indexed(nodes, edges);
+        let mut points: Vec<Point> = (0..nodes.len()).map(|i| self.spiral_point(i)).collect();
+        while !self.is_settled() {
+            self.iterate(&mut points, &edges);
+        }
+        Layout { map: nodes.into_iter().zip(points).collect() }
+    }
+
+    /// Advance the simulation by `iterations_per_step` iterations, updating the given layout.
+    ///
+    /// Nodes that are missing from the layout, or that share a position with an earlier node, are
+    /// first moved onto a spiral around the origin so that they may be pushed apart.
+    ///
+    /// Returns `true` if the layout has not yet settled.
+    pub fn step<NI, N, E>(&mut self, layout: &mut Layout<NI>, nodes: N, edges: E) -> bool
+    where
+        NI: NodeId,
+        N: IntoIterator<Item=NI>,
+        E: IntoIterator<Item=(NodeSocket<NI>, NodeSocket<NI>)>,
+    {
+        let (nodes, edges) = indexed(nodes, edges);
+        let mut points: Vec<Point> = Vec::with_capacity(nodes.len());
+        for (i, node_id) in nodes.iter().enumerate() {
+            let point = match layout.map.get(node_id) {
+                Some(&point) if !points.contains(&point) => point,
+                _ => self.spiral_point(i),
+            };
+            points.push(point);
+        }
+        for _ in 0..self.iterations_per_step {
+            if self.is_settled() {
+                break;
+            }
+            self.iterate(&mut points, &edges);
+        }
+        layout.map.extend(nodes.into_iter().zip(points));
+        !self.is_settled()
+    }
+
+    // The initial position of the node at the given index.
+    fn spiral_point(&self, i: usize) -> Point {
+        const GOLDEN_ANGLE: Scalar = 2.399_963_229_728_653;
+        let radius = self.ideal_length * 0.5 * (i as Scalar).sqrt();
+        let angle = i as Scalar * GOLDEN_ANGLE;
+        [radius * angle.cos(), radius * angle.sin()]
+    }
+
+    // Perform a single iteration of the simulation and cool the temperature.
+    fn iterate(&mut self, points: &mut [Point], edges: &[(usize, usize)]) {
+        let k = self.ideal_length;
+        let mut displacements = vec![[0.0; 2]; points.len()];
+
+        // Every pair of nodes repel one another.
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                let (delta, distance) = separation(points[a], points[b], a, b);
+                let force = k * k / distance;
+                for i in 0..2 {
+                    displacements[a][i] += delta[i] / distance * force;
+                    displacements[b][i] -= delta[i] / distance * force;
+                }
+            }
+        }
+
+        // Connected nodes attract one another.
+        for &(a, b) in edges {
+            if a == b {
+                continue;
+            }
+            let (delta, distance) = separation(points[a], points[b], a, b);
+            let force = distance * distance / k;
+            for i in 0..2 {
+                displacements[a][i] -= delta[i] / distance * force;
+                displacements[b][i] += delta[i] / distance * force;
+            }
+        }
+
+        // Move each node by its displacement, limited by the temperature.
+        for (point, displacement) in points.iter_mut().zip(displacements) {
+            let length = displacement[0].hypot(displacement[1]);
+            if length > 0.0 {
+                let scale = length.min(self.temperature) / length;
+                point[0] += displacement[0] * scale;
+                point[1] += displacement[1] * scale;
+            }
+        }
+        self.temperature *= self.cooling;
+    }
+}
+
+/// Move each node in `layout` towards its position in `target` by the given fraction of the
+/// remaining distance.
+///
+/// Nodes missing from `layout` begin at the origin, matching their position within the `Graph`.
+/// Calling this once per frame with an `amount` of around `0.2` animates nodes into place.
+///
+/// Returns `true` if any node is still further than half a pixel from its target.
+pub fn animate_towards<NI>(layout: &mut Layout<NI>, target: &Layout<NI>, amount: Scalar) -> bool
+where
+    NI: NodeId,
+{
+    let amount = utils::clamp(amount, 0.0, 1.0);
+    let mut is_moving = false;
+    for (&node_id, &to) in target.map.iter() {
+        let point = layout.map.entry(node_id).or_insert([0.0, 0.0]);
+        let delta = [to[0] - point[0], to[1] - point[1]];
+        if delta[0].hypot(delta[1]) * (1.0 - amount) > 0.5 {
+            point[0] += delta[0] * amount;
+            point[1] += delta[1] * amount;
+            is_moving = true;
+        } else {
+            *point = to;
+        }
+    }
+    is_moving
+}
+
+// Collect the nodes and convert each edge into a pair of node indices.
+//
+// Edges referring to unknown nodes are ignored.
+fn indexed<NI, N, E>(nodes: N, edges: E) -> (Vec<NI>, Vec<(usize, usize)>)
+where
+    NI: NodeId,
+    N: IntoIterator<Item=NI>,
+    E: IntoIterator<Item=(NodeSocket<NI>, NodeSocket<NI>)>,
+{
+    let nodes: Vec<NI> = nodes.into_iter().collect();
+    let indices: HashMap<NI, usize> = nodes.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let edges = edges.into_iter()
+        .filter_map(|(a, b)| match (indices.get(&a.id), indices.get(&b.id)) {
+            (Some(&a), Some(&b)) => Some((a, b)),
+            _ => None,
+        })
+        .collect();
+    (nodes, edges)
+}
+
+// The distance between two points along with the vector from `b` to `a`.
+//
+// Coincident points are separated along a direction determined by their indices.
+fn separation(a: Point, b: Point, a_index: usize, b_index: usize) -> (Point, Scalar) {
+    let delta = [a[0] - b[0], a[1] - b[1]];
+    let distance = delta[0].hypot(delta[1]);
+    if distance > 0.0 {
+        return (delta, distance);
+    }
+    let angle = (a_index * 31 + b_index * 17) as Scalar;
+    ([angle.cos() * 0.01, angle.sin() * 0.01], 0.01)
+}
+
+// Remove self-loops and reverse the edges that lead back to an ancestor during a depth-first
+// search, producing a directed acyclic graph.
+fn acyclic_edges(n: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    #[derive(Copy, Clone, PartialEq)]
+    enum Visit { Unvisited, Active, Done }
+    let mut successors = vec![vec![]; n];
+    for &(a, b) in edges {
+        if a != b {
+            successors[a].push(b);
+        }
+    }
+    let mut visits = vec![Visit::Unvisited; n];
+    let mut acyclic = Vec::with_capacity(edges.len());
+    for root in 0..n {
+        if visits[root] != Visit::Unvisited {
+            continue;
+        }
+        visits[root] = Visit::Active;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, next)) = stack.pop() {
+            match successors[node].get(next) {
+                None => visits[node] = Visit::Done,
+                Some(&succ) => {
+                    stack.push((node, next + 1));
+                    match visits[succ] {
+                        Visit::Active => acyclic.push((succ, node)),
+                        Visit::Done => acyclic.push((node, succ)),
+                        Visit::Unvisited => {
+                            acyclic.push((node, succ));
+                            visits[succ] = Visit::Active;
+                            stack.push((succ, 0));
+                        },
+                    }
+                },
+            }
+        }
+    }
+    acyclic
+}
+
+// Assign each node of a directed acyclic graph the length of the longest path leading to it.
+fn longest_path_layers(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut in_degrees = vec![0; n];
+    let mut successors = vec![vec![]; n];
+    for &(a, b) in edges {
+        successors[a].push(b);
+        in_degrees[b] += 1;
+    }
+    let mut layers = vec![0; n];
+    let mut ready: Vec<usize> = (0..n).filter(|&i| in_degrees[i] == 0).rev().collect();
+    while let Some(node) = ready.pop() {
+        for &succ in &successors[node] {
+            layers[succ] = layers[succ].max(layers[node] + 1);
+            in_degrees[succ] -= 1;
+            if in_degrees[succ] == 0 {
+                ready.push(succ);
+            }
+        }
+    }
+    layers
+}
+
+// Sort the nodes of a layer by the mean position of their neighbours within the fixed layer.
+//
+// Nodes without neighbours keep their current position.
+fn sort_by_barycenter(layer: &mut Vec<usize>, fixed: &[usize], neighbours: &[Vec<usize>]) {
+    let positions: HashMap<usize, usize> = fixed.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let mut keyed: Vec<(Scalar, usize)> = layer.iter()
+        .enumerate()
+        .map(|(i, &node)| {
+            let (sum, count) = neighbours[node].iter()
+                .filter_map(|n| positions.get(n))
+                .fold((0, 0), |(sum, count), &p| (sum + p, count + 1));
+            let key = match count {
+                0 => i as Scalar * fixed.len().max(1) as Scalar / layer.len() as Scalar,
+                _ => sum as Scalar / count as Scalar,
+            };
+            (key, node)
+        })
+        .collect();
+    keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("barycenter was NaN"));
+    *layer = keyed.into_iter().map(|(_, node)| node).collect();
+}
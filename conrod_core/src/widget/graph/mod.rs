@@ -1,17 +1,23 @@
 //! A widget for viewing and controlling graph structures.
 
-use {color, widget, Color, Colorable, Point, Positionable, Scalar, Widget, Ui, UiCell};
+use {color, widget, Color, Colorable, Point, Positionable, Rect, Scalar, Sizeable, Widget};
+use {Ui, UiCell};
+use event;
+use input;
 use std::any::{Any, TypeId};
 use std::cell::Cell;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::sync::{Arc, Mutex, Weak};
 use utils::{iter_diff, IterDiff};
 
-pub use self::node::{Node, SocketLayout, SocketSide};
+pub use self::edge::Connector;
+pub use self::node::{Node, Socket, SocketLayout, SocketSide, SocketType};
 
+pub mod edge;
+pub mod layout;
 pub mod node;
 
 /// Traits required by types that may be used as a graph node identifier.
@@ -22,9 +28,14 @@ impl<T> NodeId for T where T: 'static + Copy + Clone + PartialEq + Eq + Hash + S
 
 /// Stores the layout of all nodes within the graph.
 ///
-/// All positions are relative to the centre of the `Graph` widget.
+/// All positions are in *graph space*, i.e. relative to the centre of the graph before the
+/// `Camera` is applied. When the camera is at its default position and zoom, graph space is
+/// equivalent to the space relative to the centre of the `Graph` widget.
 ///
 /// Nodes can be moved by 
+///
+/// A `Layout` may be generated automatically using one of the algorithms within the `layout`
+/// module.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Layout<NI>
 where
@@ -54,7 +65,7 @@ where
 
 /// A widget used for visualising and manipulating **Graph** types.
 #[derive(Clone, Debug, WidgetCommon_)]
-pub struct Graph<'a, N, E>
+pub struct Graph<'a, N, E, F = ValidateEdgeFn<<N as Iterator>::Item>>
 where
     N: Iterator,
     N::Item: NodeId,
@@ -71,20 +82,73 @@ where
     pub edges: E,
     /// The position of each node within the graph.
     pub layout: &'a Layout<N::Item>,
+    /// The minimum and maximum zoom of the camera.
+    pub zoom_range: (Scalar, Scalar),
+    /// If `Some`, the camera will be moved to fit the given nodes during this update.
+    pub maybe_zoom_to: Option<ZoomTo<N::Item>>,
+    /// If `Some`, the camera will be panned by the given offset in view space during this update.
+    pub maybe_pan: Option<Point>,
+    /// Accepts or rejects a prospective edge while it is being dragged between sockets.
+    pub validate_edge: F,
+}
+
+/// The type of the default edge validation function, `sockets_are_compatible`.
+pub type ValidateEdgeFn<NI> = fn(&Connection<NI>) -> bool;
+
+/// A prospective edge between an output socket and an input socket.
+///
+/// This is passed to the `Graph`'s edge validation function while an edge is being dragged in
+/// order to determine which sockets it may connect to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Connection<NI> {
+    /// The output socket at the start of the edge.
+    pub start: NodeSocket<NI>,
+    /// The input socket at the end of the edge.
+    pub end: NodeSocket<NI>,
+    /// The description of the output socket, as last given to its `Node` widget.
+    pub start_socket: node::Socket,
+    /// The description of the input socket, as last given to its `Node` widget.
+    pub end_socket: node::Socket,
+}
+
+/// A request to move the camera so that some set of nodes fits within the `Graph` widget.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ZoomTo<NI> {
+    /// Fit all nodes in the graph.
+    All,
+    /// Fit only the given nodes, e.g. the current selection.
+    Nodes(Vec<NI>),
 }
 
+/// The default minimum and maximum zoom of the camera.
+pub const DEFAULT_ZOOM_RANGE: (Scalar, Scalar) = (0.1, 10.0);
+
+/// The rate at which the camera zooms per unit of scroll.
+pub const ZOOM_PER_SCROLL: Scalar = 0.01;
+
+/// The padding between the fitted nodes and the edges of the `Graph` when zooming to fit.
+pub const ZOOM_TO_FIT_PADDING: Scalar = 20.0;
+
 /// Unique styling for the **BorderedRectangle** widget.
 #[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
 pub struct Style {
     /// Shape styling for the inner rectangle.
     #[conrod(default = "color::TRANSPARENT")]
     pub background_color: Option<Color>,
+    /// The color of the rectangle drawn while rubber-band selecting nodes.
+    #[conrod(default = "color::LIGHT_BLUE")]
+    pub rubber_band_color: Option<Color>,
 }
 
 widget_ids! {
     struct Ids {
         // The rectangle over which all nodes are placed.
         background,
+        // The translucent fill and outline of the rubber-band selection rectangle.
+        rubber_band,
+        rubber_band_outline,
+        // The edge being dragged out of a socket.
+        pending_edge,
     }
 }
 
@@ -113,6 +177,46 @@ where
     edges: Vec<(NodeSocket<NI>, NodeSocket<NI>)>,
     // A map from type identifiers to available `widget::Id`s for those types.
     widget_id_map: WidgetIdMap<NI>,
+    // The camera through which the graph is currently viewed.
+    camera: Camera,
+    // Whether or not the space key is held, in which case left-dragging pans the camera.
+    is_space_down: bool,
+    // The midpoint and distance between two touches that are pinching the graph, if any.
+    maybe_pinch: Option<(Point, Scalar)>,
+    // The set of currently selected nodes.
+    selection: HashSet<NI>,
+    // The rubber-band selection in progress, if any.
+    maybe_rubber_band: Option<RubberBand<NI>>,
+    // A selected node that was pressed and will become the only selected node if it is released
+    // without being dragged.
+    maybe_select_only: Option<NI>,
+    // The edge being dragged out of a socket, if any.
+    maybe_edge_drag: Option<EdgeDrag<NI>>,
+    // The socket nearest to the mouse, if any are within reach.
+    maybe_hovered_socket: Option<(NodeSocket<NI>, SocketType)>,
+}
+
+// An edge being dragged from a socket towards another.
+#[derive(Clone)]
+struct EdgeDrag<NI> {
+    // The socket from which the edge is being dragged.
+    socket: NodeSocket<NI>,
+    // Whether the socket is an input or output.
+    socket_type: SocketType,
+    // The valid socket of the opposite type nearest the mouse, if any are within reach.
+    maybe_target: Option<NodeSocket<NI>>,
+    // The sockets of the opposite type to which the edge may not connect.
+    invalid_targets: Vec<NodeSocket<NI>>,
+}
+
+// A rectangle being dragged out over the graph in order to select the nodes it touches.
+struct RubberBand<NI> {
+    // The corner at which the drag began, in graph space.
+    origin: Point,
+    // The corner under the mouse, in graph space.
+    corner: Point,
+    // The nodes that were selected before the rubber band began.
+    base: Vec<NI>,
 }
 
 // A type for managing the input and output socket layouts.
@@ -185,6 +289,12 @@ where
         self.node_widget_ids.clear();
     }
 
+    // Whether or not the given widget is the widget of one of the nodes, rather than one of its
+    // descendants.
+    fn is_node_widget(&self, widget_id: widget::Id) -> bool {
+        self.node_widget_ids.values().any(|&node_widget_id| node_widget_id == widget_id)
+    }
+
     // Return the next `widget::Id` for a widget of the given type.
     //
     // If there are no more `Id`s available for the type, a new one will be generated from the
@@ -216,17 +326,43 @@ where
 }
 
 /// An interaction has caused some event to occur.
-//
-// TODO:
-//
-// - Hovered near outlet.
-// - Edge end hovered near an outlet?
 #[derive(Clone, Debug, PartialEq)]
 pub enum Event<NI> {
     /// Events associated with nodes.
     Node(NodeEvent<NI>),
     /// Events associated with edges.
     Edge(EdgeEvent<NI>),
+    /// Events associated with the selection of nodes.
+    Selection(SelectionEvent),
+    /// Events associated with the mouse hovering near node sockets.
+    Socket(SocketEvent<NI>),
+}
+
+/// Events related to the mouse hovering near node sockets.
+///
+/// A socket is hovered while it is the nearest socket within `SOCKET_REACH` of the mouse.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SocketEvent<NI> {
+    /// The mouse has moved near the given socket.
+    HoverStart(NodeSocket<NI>, SocketType),
+    /// The mouse has moved away from the given socket.
+    HoverEnd(NodeSocket<NI>, SocketType),
+}
+
+/// The distance from a socket within which the mouse is considered to be near it.
+pub const SOCKET_REACH: Scalar = 16.0;
+
+/// Events related to the set of selected nodes.
+///
+/// The selection itself may be retrieved from any stage of the `Session` via `selection`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SelectionEvent {
+    /// The set of selected nodes has changed.
+    Changed,
+    /// The user has requested that the selected nodes be duplicated with `Ctrl+D`.
+    Duplicate,
+    /// The user has requested that the selected nodes be copied with `Ctrl+C`.
+    Copy,
 }
 
 /// Represents a socket connection on a node.
@@ -248,14 +384,16 @@ pub struct NodeSocket<NI> {
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum NodeEvent<NI> {
     /// The user attempted to remove the node with the given identifier.
+    ///
+    /// Emitted for each selected node when `Delete` or `Backspace` is pressed.
     Remove(NI),
     /// The widget used to represent this `Node` has been dragged.
     Dragged {
         /// Unique identifier of the node being dragged.
         node_id: NI,
-        /// The origin of the drag relative to the `Graph` widget position.
+        /// The origin of the drag in graph space.
         from: Point,
-        /// The end of the drag relative to the `Graph` widget position.
+        /// The end of the drag in graph space.
         to: Point,
     },
 }
@@ -268,12 +406,27 @@ pub enum EdgeEvent<NI> {
     /// edge.
     AddStart(NodeSocket<NI>),
     /// The user has attempted to create an edge between the two given node sockets.
+    ///
+    /// `start` is always an output socket and `end` is always an input socket, regardless of
+    /// which was pressed first.
     Add {
         start: NodeSocket<NI>,
         end: NodeSocket<NI>,
     },
     /// The user has cancelled creating an edge from the given socket.
     Cancelled(NodeSocket<NI>),
+    /// The end of the edge being created has moved near a socket to which it may connect.
+    ///
+    /// Releasing the mouse now will attempt to create an edge from `start` to `end`.
+    TargetStart {
+        start: NodeSocket<NI>,
+        end: NodeSocket<NI>,
+    },
+    /// The end of the edge being created has moved away from the socket it was near.
+    TargetEnd {
+        start: NodeSocket<NI>,
+        end: NodeSocket<NI>,
+    },
     /// The user has attempted to remove the edge connecting the two given sockets.
     Remove {
         start: NodeSocket<NI>,
@@ -283,8 +436,10 @@ pub enum EdgeEvent<NI> {
 
 /// The camera used to view the graph.
 ///
-/// The camera supports 2D positioning and zoom.
-#[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// The camera supports 2D positioning and zoom. The camera may be panned by dragging with the
+/// middle mouse button or by dragging with the left mouse button while holding `Space`. It may be
+/// zoomed around the cursor with the scroll wheel or by pinching with two fingers.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Camera {
     // The position of the camera over the floorplan.
     //
@@ -292,8 +447,8 @@ pub struct Camera {
     point: Point,
     // The higher the zoom, the closer the floorplan appears.
     //
-    // The zoom can be multiplied by a distance in metres to get the equivalent distance as a GUI
-    // scalar value.
+    // The zoom can be multiplied by a distance in graph space to get the equivalent distance as a
+    // GUI scalar value.
     //
     // 1.0 - Original resolution.
     // 0.5 - 50% view.
@@ -368,6 +523,9 @@ struct NodeInner {
 pub struct NodeContext<'a, NI: 'a + NodeId> {
     node_id: NI,
     point: Point,
+    camera: Camera,
+    is_selected: bool,
+    invalid_sockets: Vec<(SocketType, usize)>,
     // The `widget::Id` of the `NodeContext`'s parent `Graph` widget.
     graph_id: widget::Id,
     shared: Arc<Mutex<Shared<NI>>>,
@@ -459,10 +617,106 @@ where
     }
 }
 
+impl Default for Camera {
+    fn default() -> Self {
+        Camera::new([0.0, 0.0], 1.0)
+    }
+}
+
+impl Camera {
+    /// A camera positioned at the given point in graph space with the given zoom.
+    pub fn new(point: Point, zoom: Scalar) -> Self {
+        Camera { point, zoom }
+    }
+
+    /// The point in graph space at the centre of the view.
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    /// The scale applied to graph space distances when viewed.
+    pub fn zoom(&self) -> Scalar {
+        self.zoom
+    }
+
+    /// Convert the given point in graph space to a point relative to the centre of the `Graph`
+    /// widget.
+    pub fn view_point(&self, graph_point: Point) -> Point {
+        [
+            (graph_point[0] - self.point[0]) * self.zoom,
+            (graph_point[1] - self.point[1]) * self.zoom,
+        ]
+    }
+
+    /// Convert the given point relative to the centre of the `Graph` widget to graph space.
+    pub fn graph_point(&self, view_point: Point) -> Point {
+        [
+            view_point[0] / self.zoom + self.point[0],
+            view_point[1] / self.zoom + self.point[1],
+        ]
+    }
+
+    /// Move the camera by the given distance relative to the `Graph` widget.
+    ///
+    /// The graph appears to move along with the given distance.
+    pub fn pan(&mut self, view_delta: Point) {
+        self.point[0] -= view_delta[0] / self.zoom;
+        self.point[1] -= view_delta[1] / self.zoom;
+    }
+
+    /// Change the zoom while keeping the graph point under the given view point in place.
+    pub fn zoom_around(&mut self, view_point: Point, zoom: Scalar) {
+        let graph_point = self.graph_point(view_point);
+        self.zoom = zoom;
+        self.point = [
+            graph_point[0] - view_point[0] / zoom,
+            graph_point[1] - view_point[1] / zoom,
+        ];
+    }
+
+    /// Position and zoom the camera so that the given rectangle in graph space fits within a view
+    /// of the given dimensions.
+    pub fn fit(&mut self, graph_rect: Rect, view_dim: [Scalar; 2]) {
+        let w = graph_rect.w().max(1.0);
+        let h = graph_rect.h().max(1.0);
+        self.point = graph_rect.xy();
+        self.zoom = (view_dim[0] / w).min(view_dim[1] / h);
+    }
+}
+
+impl<NI> Session<NI>
+where
+    NI: NodeId,
+{
+    // The camera through which the graph is viewed during this session.
+    fn camera(&self) -> Camera {
+        let shared = self.shared.upgrade().expect("failed to access `Shared` state");
+        let camera = shared.lock().unwrap().camera;
+        camera
+    }
+
+    // The selected nodes in the order in which they appear in the graph.
+    fn selection(&self) -> Vec<NI> {
+        let shared = self.shared.upgrade().expect("failed to access `Shared` state");
+        let guard = shared.lock().unwrap();
+        guard.node_ids.iter().filter(|id| guard.selection.contains(id)).cloned().collect()
+    }
+}
+
 impl<NI> SessionEvents<NI>
 where
     NI: NodeId,
 {
+    /// The camera through which the graph is viewed.
+    pub fn camera(&self) -> Camera {
+        self.session.camera()
+    }
+
+    /// The currently selected nodes in the order in which they were given to the `Graph`.
+    pub fn selection(&self) -> Vec<NI> {
+        self.session.selection()
+    }
+
     /// All events that have occurred since the last 
     pub fn events(&self) -> Events<NI> {
         let shared = self.session.shared.upgrade().expect("failed to access `Shared` state");
@@ -492,6 +746,16 @@ impl<NI> SessionNodes<NI>
 where
     NI: NodeId,
 {
+    /// The camera through which the graph is viewed.
+    pub fn camera(&self) -> Camera {
+        self.session.camera()
+    }
+
+    /// The currently selected nodes in the order in which they were given to the `Graph`.
+    pub fn selection(&self) -> Vec<NI> {
+        self.session.selection()
+    }
+
     /// Produce an iterator yielding a `NodeContext` for each node present in the graph.
     pub fn nodes(&mut self) -> Nodes<NI> {
         let graph_id = self.session.graph_id;
@@ -519,12 +783,25 @@ where
             .and_then(|guard| {
                 guard.node_ids
                     .get(index)
-                    .and_then(|&id| guard.nodes.get(&id).map(|&inner| (id, inner)))
+                    .and_then(|&id| guard.nodes.get(&id).map(|&inner| {
+                        let invalid_sockets = match guard.maybe_edge_drag {
+                            None => vec![],
+                            Some(ref drag) => drag.invalid_targets.iter()
+                                .filter(|socket| socket.id == id)
+                                .map(|socket| (opposite(drag.socket_type), socket.socket_index))
+                                .collect(),
+                        };
+                        let is_selected = guard.selection.contains(&id);
+                        (id, inner, guard.camera, is_selected, invalid_sockets)
+                    }))
             })
-            .map(|(node_id, NodeInner { point })| {
+            .map(|(node_id, NodeInner { point }, camera, is_selected, invalid_sockets)| {
                 NodeContext {
                     node_id,
                     point,
+                    camera,
+                    is_selected,
+                    invalid_sockets,
                     graph_id: self.graph_id,
                     shared: self.shared.clone(),
                     lifetime: PhantomData,
@@ -537,6 +814,16 @@ impl<NI> SessionEdges<NI>
 where
     NI: NodeId,
 {
+    /// The camera through which the graph is viewed.
+    pub fn camera(&self) -> Camera {
+        self.session.camera()
+    }
+
+    /// The currently selected nodes in the order in which they were given to the `Graph`.
+    pub fn selection(&self) -> Vec<NI> {
+        self.session.selection()
+    }
+
     /// Produce an iterator yielding an `Edge` for each node present in the graph.
     pub fn edges(&mut self) -> Edges<NI> {
         let graph_id = self.session.graph_id;
@@ -578,11 +865,38 @@ where
         self.node_id
     }
 
-    /// The location of the node.
+    /// The location of the node in graph space.
     pub fn point(&self) -> Point {
         self.point
     }
 
+    /// The location of the node relative to the centre of the `Graph` widget.
+    pub fn view_point(&self) -> Point {
+        self.camera.view_point(self.point)
+    }
+
+    /// The zoom of the camera through which the node is viewed.
+    ///
+    /// The dimensions of the node's widget are scaled by this automatically. It may be used to
+    /// scale any other distances within the widget, e.g. via `Node::zoom`.
+    pub fn zoom(&self) -> Scalar {
+        self.camera.zoom
+    }
+
+    /// Whether or not the node is currently selected.
+    ///
+    /// This may be used to highlight the node's widget, e.g. via `Node::border_color`.
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+
+    /// The sockets of this node to which the edge currently being dragged may not connect.
+    ///
+    /// This may be used to grey out the sockets, e.g. via `Node::invalid_sockets`.
+    pub fn invalid_sockets(&self) -> &[(SocketType, usize)] {
+        &self.invalid_sockets
+    }
+
     /// Specify the widget to use 
     pub fn widget<W>(self, widget: W) -> NodeWidget<'a, NI, W> {
         NodeWidget {
@@ -624,11 +938,18 @@ where
     }
 
     /// Set the given widget for the node at `node_id()`.
+    ///
+    /// The widget is positioned and its dimensions are scaled according to the graph's camera.
     pub fn set(self, ui: &mut UiCell) -> W::Event {
         let widget_id = self.widget_id(ui);
         let NodeWidget { node, widget, .. } = self;
+        let zoom = node.camera.zoom;
+        let widget = match widget.get_wh(ui) {
+            Some([w, h]) => widget.w_h(w * zoom, h * zoom),
+            None => widget,
+        };
         widget
-            .xy_relative_to(node.graph_id, node.point)
+            .xy_relative_to(node.graph_id, node.view_point())
             .parent(node.graph_id)
             .set(widget_id, ui)
     }
@@ -667,6 +988,13 @@ where
         (self.start, self.end)
     }
 
+    /// The zoom of the camera through which the edge is viewed.
+    ///
+    /// This may be used to scale the thickness of the edge's widget.
+    pub fn zoom(&self) -> Scalar {
+        self.shared.lock().unwrap().camera.zoom
+    }
+
     /// Specify the widget to use 
     pub fn widget<W>(self, widget: W) -> EdgeWidget<'a, NI, W> {
         EdgeWidget {
@@ -779,9 +1107,84 @@ where
             nodes: nodes.into_iter(),
             edges: edges.into_iter(),
             layout: layout,
+            zoom_range: DEFAULT_ZOOM_RANGE,
+            maybe_zoom_to: None,
+            maybe_pan: None,
+            validate_edge: sockets_are_compatible as ValidateEdgeFn<_>,
+        }.crop_kids()
+    }
+}
+
+impl<'a, N, E, F> Graph<'a, N, E, F>
+where
+    N: Iterator,
+    N::Item: NodeId,
+    E: Iterator<Item=(NodeSocket<N::Item>, NodeSocket<N::Item>)>,
+{
+    /// Specify a function that accepts or rejects a prospective edge while it is being dragged.
+    ///
+    /// Sockets that would produce a rejected edge are listed in `NodeContext::invalid_sockets`
+    /// and will never produce an `EdgeEvent::Add` or `EdgeEvent::TargetStart`.
+    ///
+    /// By default, `sockets_are_compatible` is used.
+    pub fn validate_edge<V>(self, validate_edge: V) -> Graph<'a, N, E, V>
+    where
+        V: Fn(&Connection<N::Item>) -> bool,
+    {
+        let Graph {
+            common,
+            style,
+            nodes,
+            edges,
+            layout,
+            zoom_range,
+            maybe_zoom_to,
+            maybe_pan,
+            ..
+        } = self;
+        Graph {
+            common,
+            style,
+            nodes,
+            edges,
+            layout,
+            zoom_range,
+            maybe_zoom_to,
+            maybe_pan,
+            validate_edge,
         }
     }
 
+    /// Specify the minimum and maximum zoom of the camera.
+    pub fn zoom_range(mut self, min: Scalar, max: Scalar) -> Self {
+        self.zoom_range = (min, max);
+        self
+    }
+
+    /// Move the camera so that all nodes fit within the **Graph** during this update.
+    pub fn zoom_to_fit(mut self) -> Self {
+        self.maybe_zoom_to = Some(ZoomTo::All);
+        self
+    }
+
+    /// Move the camera so that the given nodes fit within the **Graph** during this update.
+    pub fn zoom_to_nodes<I>(mut self, nodes: I) -> Self
+    where
+        I: IntoIterator<Item=N::Item>,
+    {
+        self.maybe_zoom_to = Some(ZoomTo::Nodes(nodes.into_iter().collect()));
+        self
+    }
+
+    /// Pan the camera so that the contents of the **Graph** shift by the given offset during this
+    /// update.
+    ///
+    /// This may be used to pan the graph via a `Minimap`, whose event is such an offset.
+    pub fn pan(mut self, offset: Point) -> Self {
+        self.maybe_pan = Some(offset);
+        self
+    }
+
     /// Color the **Graph**'s rectangular area with the given color.
     pub fn background_color(mut self, color: Color) -> Self {
         self.style.background_color = Some(color);
@@ -789,11 +1192,12 @@ where
     }
 }
 
-impl<'a, N, E> Widget for Graph<'a, N, E>
+impl<'a, N, E, F> Widget for Graph<'a, N, E, F>
 where
     N: Iterator,
     N::Item: NodeId,
     E: Iterator<Item=(NodeSocket<N::Item>, NodeSocket<N::Item>)>,
+    F: Fn(&Connection<N::Item>) -> bool,
 {
     type State = State<N::Item>;
     type Style = Style;
@@ -807,7 +1211,22 @@ where
         let type_widget_ids = HashMap::new();
         let node_widget_ids = HashMap::new();
         let widget_id_map = WidgetIdMap { type_widget_ids, node_widget_ids };
-        let shared = Shared { events, nodes, node_ids, edges, widget_id_map };
+        let camera = Camera::default();
+        let shared = Shared {
+            events,
+            nodes,
+            node_ids,
+            edges,
+            widget_id_map,
+            camera,
+            is_space_down: false,
+            maybe_pinch: None,
+            selection: HashSet::new(),
+            maybe_rubber_band: None,
+            maybe_select_only: None,
+            maybe_edge_drag: None,
+            maybe_hovered_socket: None,
+        };
         State {
             ids: Ids::new(id_gen),
             shared: Arc::new(Mutex::new(shared)),
@@ -820,9 +1239,22 @@ where
 
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs { id, state, style, rect, ui, .. } = args;
-        let Graph { nodes, edges, layout, .. } = self;
+        let Graph {
+            nodes,
+            edges,
+            layout,
+            zoom_range,
+            maybe_zoom_to,
+            maybe_pan,
+            validate_edge,
+            ..
+        } = self;
         let mut shared = state.shared.lock().unwrap();
 
+        // The camera through which the nodes were viewed during the last update.
+        let prev_camera = shared.camera;
+        let is_space_down = update_space_down(id, shared.is_space_down, ui);
+
         // Reset the WidgetIdMap indices.
         shared.widget_id_map.reset_indices();
 
@@ -860,6 +1292,27 @@ where
             None => (),
         }
 
+        // Update the selection before the node positions change so that the rubber band is
+        // compared against the nodes as they were last displayed.
+        update_selection(id, rect, prev_camera, is_space_down, &mut shared, ui);
+        let is_edge_dragged = update_edge_drag(id, &validate_edge, &mut shared, ui);
+
+        // The distance dragged by the left mouse button over the given node's widget.
+        let node_drag = |shared: &Shared<N::Item>, node_id| {
+            match shared.widget_id_map.node_widget_ids.get(&node_id) {
+                None => (0.0, 0.0),
+                Some(&widget_id) => ui.widget_input(widget_id)
+                    .drags()
+                    .left()
+                    .fold((0.0, 0.0), |(x, y), d| (x + d.delta_xy[0], y + d.delta_xy[1])),
+            }
+        };
+
+        // Selected nodes are moved together by dragging any one of them.
+        let selection_drag = shared.selection.iter()
+            .map(|&node_id| node_drag(&shared, node_id))
+            .fold((0.0, 0.0), |(x, y), (dx, dy)| (x + dx, y + dy));
+
         // Use `shared.node_ids` and `shared.edges` to fill `shared.nodes`.
         shared.nodes.clear();
         for i in 0..shared.node_ids.len() {
@@ -869,32 +1322,54 @@ where
             // Get the node position, falling back to 0.0, 0.0 if none was given.
             let point = layout.map.get(&node_id).map(|&p| p).unwrap_or([0.0; 2]);
 
-            // Check to see if this widget has been dragged since the last update.
-            let point = match shared.widget_id_map.node_widget_ids.get(&node_id).map(|&w| w) {
-                None => point,
-                Some(widget_id) => {
-                    let (dragged_x, dragged_y) = ui.widget_input(widget_id)
-                        .drags()
-                        .left()
-                        .fold((0.0, 0.0), |(x, y), d| (x + d.delta_xy[0], y + d.delta_xy[1]));
-
-                    // If dragging would not move the widget, we're done.
-                    if dragged_x == 0.0 && dragged_y == 0.0 {
-                        point
-                    } else {
-                        let to = [point[0] + dragged_x, point[1] + dragged_y];
-                        let node_event = NodeEvent::Dragged { node_id, from: point, to };
-                        let event = Event::Node(node_event);
-                        shared.events.push_back(event);
-                        to
-                    }
-                },
+            // Check to see if this widget, or any selected widget if this node is selected, has
+            // been dragged since the last update.
+            let (dragged_x, dragged_y) = match shared.selection.contains(&node_id) {
+                true => selection_drag,
+                false => node_drag(&shared, node_id),
+            };
+
+            // If dragging would not move the widget, or the camera is being panned or an edge is
+            // being dragged instead, we're done.
+            let is_dragged = dragged_x != 0.0 || dragged_y != 0.0;
+            let point = if !is_dragged || is_space_down || is_edge_dragged {
+                point
+            } else {
+                let zoom = prev_camera.zoom;
+                let to = [point[0] + dragged_x / zoom, point[1] + dragged_y / zoom];
+                let node_event = NodeEvent::Dragged { node_id, from: point, to };
+                let event = Event::Node(node_event);
+                shared.events.push_back(event);
+                to
             };
 
             let node = NodeInner { point };
             shared.nodes.insert(node_id, node);
         }
 
+        // Pan and zoom the camera.
+        let mut camera = prev_camera;
+        let prev_pinch = shared.maybe_pinch;
+        let maybe_pinch = update_camera(&mut camera, id, rect, zoom_range, is_space_down,
+                                        prev_pinch, &shared.widget_id_map, ui);
+        if let Some(offset) = maybe_pan {
+            camera.pan(offset);
+        }
+        if let Some(zoom_to) = maybe_zoom_to {
+            if let Some(graph_rect) = zoom_to_rect(&zoom_to, &shared, prev_camera, rect, ui) {
+                let view_dim = rect.pad(ZOOM_TO_FIT_PADDING).dim();
+                camera.fit(graph_rect, [view_dim[0].max(1.0), view_dim[1].max(1.0)]);
+            }
+        }
+        // Zooming via the given `zoom_to` or a change in `zoom_range` may exceed the range.
+        let zoom = clamp_zoom(camera.zoom, zoom_range);
+        if zoom != camera.zoom {
+            camera.zoom_around([0.0, 0.0], zoom);
+        }
+        shared.camera = camera;
+        shared.is_space_down = is_space_down;
+        shared.maybe_pinch = maybe_pinch;
+
         let background_color = style.background_color(&ui.theme);
         widget::Rectangle::fill(rect.dim())
             .xy(rect.xy())
@@ -903,6 +1378,54 @@ where
             .graphics_for(id)
             .set(state.ids.background, ui);
 
+        // Draw the edge being dragged from the socket to its target or the mouse.
+        if let Some(ref drag) = shared.maybe_edge_drag {
+            let socket_rects = socket_rects(&shared, ui);
+            let find_socket = |socket: NodeSocket<N::Item>, ty: SocketType| {
+                socket_rects.iter().find(|s| s.0 == socket && s.1 == ty)
+            };
+            let target_type = opposite(drag.socket_type);
+            let target = drag.maybe_target.and_then(|socket| find_socket(socket, target_type));
+            if let Some(&(_, _, start, start_side, _)) = find_socket(drag.socket, drag.socket_type) {
+                let (end, end_side) = match target {
+                    Some(&(_, _, end, end_side, _)) => (end.xy(), end_side),
+                    None => (ui.global_input().current.mouse.xy, opposite_side(start_side)),
+                };
+                let (start, start_side, end, end_side) = match drag.socket_type {
+                    SocketType::Output => (start.xy(), start_side, end, end_side),
+                    SocketType::Input => (end, end_side, start.xy(), start_side),
+                };
+                Connector::new(start, start_side, end, end_side)
+                    .zoom(camera.zoom)
+                    .depth(-1.0)
+                    .parent(id)
+                    .graphics_for(id)
+                    .set(state.ids.pending_edge, ui);
+            }
+        }
+
+        // Draw the rubber band in front of the nodes.
+        if let Some(ref band) = shared.maybe_rubber_band {
+            let a = camera.view_point(band.origin);
+            let b = camera.view_point(band.corner);
+            let band_rect = Rect::from_corners(a, b);
+            let color = style.rubber_band_color(&ui.theme);
+            widget::Rectangle::fill(band_rect.dim())
+                .xy_relative_to(id, band_rect.xy())
+                .color(color.alpha(0.2))
+                .depth(-1.0)
+                .parent(id)
+                .graphics_for(id)
+                .set(state.ids.rubber_band, ui);
+            widget::Rectangle::outline(band_rect.dim())
+                .xy_relative_to(id, band_rect.xy())
+                .color(color)
+                .depth(-1.0)
+                .parent(id)
+                .graphics_for(id)
+                .set(state.ids.rubber_band_outline, ui);
+        }
+
         // Clear the old node->widget mappings ready for node instantiation.
         shared.widget_id_map.clear_node_mappings();
 
@@ -912,3 +1435,546 @@ where
         SessionEvents { session }
     }
 }
+
+// Whether or not the space key is held after the events of this update.
+//
+// Space is only tracked while no widget outside the graph captures the keyboard.
+fn update_space_down(graph_id: widget::Id, mut is_space_down: bool, ui: &UiCell) -> bool {
+    let input = ui.global_input();
+    let is_graph_focused = match input.current.widget_capturing_keyboard {
+        None => true,
+        Some(w) => w == graph_id || ui.widget_graph().does_recursive_depth_edge_exist(graph_id, w),
+    };
+    for event in input.events() {
+        match *event {
+            event::Event::Raw(event::Input::Press(input::Button::Keyboard(input::Key::Space)))
+                if is_graph_focused => is_space_down = true,
+            event::Event::Raw(event::Input::Release(input::Button::Keyboard(input::Key::Space))) =>
+                is_space_down = false,
+            _ => (),
+        }
+    }
+    is_space_down
+}
+
+// Pan and zoom the camera in response to mouse and touch input over the graph or its nodes.
+//
+// The zoom is clamped to the `zoom_range` before zooming around the cursor or pinch so that the
+// view remains still once either limit is reached.
+//
+// Scrolling only zooms while over the graph or a node widget itself, so that scrollable widgets
+// within nodes may still be scrolled.
+//
+// Returns the midpoint and distance between the touches currently pinching the graph, if any.
+fn update_camera<NI>(
+    camera: &mut Camera,
+    graph_id: widget::Id,
+    rect: Rect,
+    zoom_range: (Scalar, Scalar),
+    is_space_down: bool,
+    maybe_pinch: Option<(Point, Scalar)>,
+    widget_id_map: &WidgetIdMap<NI>,
+    ui: &UiCell,
+) -> Option<(Point, Scalar)>
+where
+    NI: NodeId,
+{
+    let is_graph_or_node = |w: widget::Id| {
+        w == graph_id || ui.widget_graph().does_recursive_depth_edge_exist(graph_id, w)
+    };
+    let is_graph_or_node_widget = |w: widget::Id| {
+        w == graph_id || widget_id_map.is_node_widget(w)
+    };
+    let input = ui.global_input();
+    let to_view = |xy: Point| [xy[0] - rect.x(), xy[1] - rect.y()];
+
+    for event in input.events().ui() {
+        match *event {
+            event::Ui::Drag(Some(w), drag) if is_graph_or_node(w) => {
+                let is_pan = match drag.button {
+                    input::MouseButton::Middle => true,
+                    input::MouseButton::Left => is_space_down,
+                    _ => false,
+                };
+                if is_pan {
+                    camera.pan(drag.delta_xy);
+                }
+            },
+            event::Ui::Scroll(Some(w), scroll) if is_graph_or_node_widget(w) => {
+                let zoom = camera.zoom * (-scroll.y * ZOOM_PER_SCROLL).exp();
+                let zoom = clamp_zoom(zoom, zoom_range);
+                camera.zoom_around(to_view(input.current.mouse.xy), zoom);
+            },
+            _ => (),
+        }
+    }
+
+    // Pinch with two touches that began over the graph.
+    let touches: Vec<Point> = input.current.touch.values()
+        .filter(|touch| touch.start.widget.map(&is_graph_or_node).unwrap_or(false))
+        .map(|touch| to_view(touch.xy))
+        .collect();
+    if touches.len() != 2 {
+        return None;
+    }
+    let (a, b) = (touches[0], touches[1]);
+    let mid = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+    let distance = (b[0] - a[0]).hypot(b[1] - a[1]);
+    if let Some((prev_mid, prev_distance)) = maybe_pinch {
+        camera.pan([mid[0] - prev_mid[0], mid[1] - prev_mid[1]]);
+        if prev_distance > 0.0 && distance > 0.0 {
+            let zoom = clamp_zoom(camera.zoom * distance / prev_distance, zoom_range);
+            camera.zoom_around(mid, zoom);
+        }
+    }
+    Some((mid, distance))
+}
+
+// Clamp the given zoom to the given minimum and maximum.
+fn clamp_zoom(zoom: Scalar, (min, max): (Scalar, Scalar)) -> Scalar {
+    zoom.max(min).min(max)
+}
+
+// The rectangle in graph space bounding the nodes that should be zoomed to.
+//
+// Each node's widget rectangle from the last update is used where available, otherwise the node's
+// point.
+fn zoom_to_rect<NI>(
+    zoom_to: &ZoomTo<NI>,
+    shared: &Shared<NI>,
+    prev_camera: Camera,
+    rect: Rect,
+    ui: &UiCell,
+) -> Option<Rect>
+where
+    NI: NodeId,
+{
+    let node_ids: &[NI] = match *zoom_to {
+        ZoomTo::All => &shared.node_ids,
+        ZoomTo::Nodes(ref node_ids) => node_ids,
+    };
+    node_ids.iter()
+        .filter_map(|&node_id| node_graph_rect(node_id, shared, prev_camera, rect, ui))
+        .fold(None, |acc: Option<Rect>, r| Some(acc.map(|acc| acc.max(r)).unwrap_or(r)))
+}
+
+// The rectangle in graph space occupied by the given node.
+//
+// The node's widget rectangle from the last update is used where available, otherwise the node's
+// point.
+fn node_graph_rect<NI>(
+    node_id: NI,
+    shared: &Shared<NI>,
+    prev_camera: Camera,
+    rect: Rect,
+    ui: &UiCell,
+) -> Option<Rect>
+where
+    NI: NodeId,
+{
+    let node = shared.nodes.get(&node_id)?;
+    let widget_rect = shared.widget_id_map.node_widget_ids.get(&node_id)
+        .and_then(|&w| ui.rect_of(w));
+    let rect = match widget_rect {
+        None => Rect::from_xy_dim(node.point, [0.0, 0.0]),
+        Some(widget_rect) => {
+            let (l, r, b, t) = widget_rect.l_r_b_t();
+            let bl = prev_camera.graph_point([l - rect.x(), b - rect.y()]);
+            let tr = prev_camera.graph_point([r - rect.x(), t - rect.y()]);
+            Rect::from_corners(bl, tr)
+        },
+    };
+    Some(rect)
+}
+
+// Update the selection in response to mouse and keyboard input over the graph or its nodes.
+//
+// - Pressing a node selects it. `Shift` adds it to the selection and `Ctrl` toggles it.
+// - Pressing the background clears the selection (unless `Shift` or `Ctrl` is held) and begins a
+//   rubber band that selects every node it touches.
+// - `Ctrl+A` selects all nodes and `Escape` clears the selection.
+// - `Delete`, `Ctrl+D` and `Ctrl+C` emit events for the selection.
+//
+// Pressing a socket begins dragging an edge instead, leaving the selection unchanged. Keys are only
+// handled while the graph or a node widget itself captures the keyboard, so that they may still be
+// typed into widgets within nodes.
+fn update_selection<NI>(
+    graph_id: widget::Id,
+    rect: Rect,
+    prev_camera: Camera,
+    is_space_down: bool,
+    shared: &mut Shared<NI>,
+    ui: &UiCell,
+)
+where
+    NI: NodeId,
+{
+    use input::keyboard::ModifierKey;
+
+    let prev_selection = shared.selection.clone();
+
+    // Forget any selected nodes that no longer exist.
+    {
+        let Shared { ref node_ids, ref mut selection, .. } = *shared;
+        selection.retain(|id| node_ids.contains(id));
+    }
+
+    let is_graph_or_node = |w: widget::Id| {
+        w == graph_id || ui.widget_graph().does_recursive_depth_edge_exist(graph_id, w)
+    };
+    let is_graph_or_node_widget = |shared: &Shared<NI>, w: widget::Id| {
+        w == graph_id || shared.widget_id_map.is_node_widget(w)
+    };
+    let sockets = socket_rects(shared, ui);
+    let is_socket_press = |shared: &Shared<NI>, w: widget::Id, xy: Point| {
+        shared.widget_id_map.is_node_widget(w) && sockets.iter().any(|s| s.2.is_over(xy))
+    };
+    let to_graph = |xy: Point| prev_camera.graph_point([xy[0] - rect.x(), xy[1] - rect.y()]);
+
+    // The node whose widget is, or is a parent of, the given widget.
+    let node_of = |shared: &Shared<NI>, w: widget::Id| {
+        shared.widget_id_map.node_widget_ids.iter()
+            .find(|&(_, &node_w)| {
+                node_w == w || ui.widget_graph().does_recursive_depth_edge_exist(node_w, w)
+            })
+            .map(|(&node_id, _)| node_id)
+    };
+
+    for event in ui.global_input().events().ui() {
+        match *event {
+
+            // Select nodes or begin a rubber band via the left mouse button.
+            event::Ui::Press(Some(w), event::Press {
+                button: event::Button::Mouse(input::MouseButton::Left, xy),
+                modifiers,
+            }) if is_graph_or_node(w) && !is_space_down && !is_socket_press(shared, w, xy) => {
+                let is_shift = modifiers.contains(ModifierKey::SHIFT);
+                let is_ctrl = modifiers.contains(ModifierKey::CTRL);
+                match node_of(shared, w) {
+                    Some(node_id) => {
+                        if is_ctrl {
+                            if !shared.selection.remove(&node_id) {
+                                shared.selection.insert(node_id);
+                            }
+                        } else if is_shift {
+                            shared.selection.insert(node_id);
+                        } else if shared.selection.contains(&node_id) {
+                            shared.maybe_select_only = Some(node_id);
+                        } else {
+                            shared.selection.clear();
+                            shared.selection.insert(node_id);
+                        }
+                    },
+                    None => {
+                        if !is_shift && !is_ctrl {
+                            shared.selection.clear();
+                        }
+                        let origin = to_graph(xy);
+                        let base = shared.selection.iter().cloned().collect();
+                        let band = RubberBand { origin, corner: origin, base };
+                        shared.maybe_rubber_band = Some(band);
+                    },
+                }
+            },
+
+            // Clicking a selected node without dragging it selects only that node.
+            event::Ui::Click(_, click) if click.button == input::MouseButton::Left => {
+                if let Some(node_id) = shared.maybe_select_only.take() {
+                    shared.selection.clear();
+                    shared.selection.insert(node_id);
+                }
+            },
+
+            // Keyboard shortcuts for the selection.
+            event::Ui::Press(Some(w), event::Press {
+                button: event::Button::Keyboard(key),
+                modifiers,
+            }) if is_graph_or_node_widget(shared, w) => {
+                let is_ctrl = modifiers.contains(ModifierKey::CTRL);
+                match key {
+                    input::Key::Delete | input::Key::Backspace => {
+                        let removed = shared.node_ids.iter()
+                            .filter(|id| shared.selection.contains(id))
+                            .map(|&id| Event::Node(NodeEvent::Remove(id)))
+                            .collect::<Vec<_>>();
+                        shared.events.extend(removed);
+                    },
+                    input::Key::D if is_ctrl => {
+                        let event = Event::Selection(SelectionEvent::Duplicate);
+                        shared.events.push_back(event);
+                    },
+                    input::Key::C if is_ctrl => {
+                        let event = Event::Selection(SelectionEvent::Copy);
+                        shared.events.push_back(event);
+                    },
+                    input::Key::A if is_ctrl => {
+                        let Shared { ref node_ids, ref mut selection, .. } = *shared;
+                        selection.extend(node_ids.iter().cloned());
+                    },
+                    input::Key::Escape => shared.selection.clear(),
+                    _ => (),
+                }
+            },
+
+            // Stretch the rubber band, selecting the nodes that were selected before it began
+            // along with every node that it touches.
+            event::Ui::Drag(w, drag) if drag.button == input::MouseButton::Left => {
+                shared.maybe_select_only = None;
+                if w != Some(graph_id) {
+                    continue;
+                }
+                let band_rect = match shared.maybe_rubber_band {
+                    Some(ref mut band) => {
+                        band.corner = to_graph(drag.to);
+                        Rect::from_corners(band.origin, band.corner)
+                    },
+                    None => continue,
+                };
+                let touched = shared.node_ids.iter()
+                    .filter(|&&id| {
+                        node_graph_rect(id, shared, prev_camera, rect, ui)
+                            .and_then(|r| r.overlap(band_rect))
+                            .is_some()
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let base = shared.maybe_rubber_band.as_ref().map(|band| band.base.clone());
+                shared.selection = base.unwrap_or_default().into_iter().chain(touched).collect();
+            },
+
+            // Finish the rubber band.
+            event::Ui::Release(_, event::Release {
+                button: event::Button::Mouse(input::MouseButton::Left, _), ..
+            }) => shared.maybe_rubber_band = None,
+
+            _ => (),
+        }
+    }
+
+    if shared.selection != prev_selection {
+        shared.events.push_back(Event::Selection(SelectionEvent::Changed));
+    }
+}
+
+// A socket along with its type, rectangle, the side of the node on which it lies and its
+// description.
+type SocketRect<NI> = (NodeSocket<NI>, SocketType, Rect, SocketSide, node::Socket);
+
+// The socket type to which sockets of the given type may connect.
+fn opposite(socket_type: SocketType) -> SocketType {
+    match socket_type {
+        SocketType::Input => SocketType::Output,
+        SocketType::Output => SocketType::Input,
+    }
+}
+
+// The side facing the given side.
+fn opposite_side(side: SocketSide) -> SocketSide {
+    match side {
+        SocketSide::Left => SocketSide::Right,
+        SocketSide::Right => SocketSide::Left,
+        SocketSide::Top => SocketSide::Bottom,
+        SocketSide::Bottom => SocketSide::Top,
+    }
+}
+
+// Every socket of every `Node` widget in the graph, as displayed during the last update.
+fn socket_rects<NI>(shared: &Shared<NI>, ui: &UiCell) -> Vec<SocketRect<NI>>
+where
+    NI: NodeId,
+{
+    let mut sockets = vec![];
+    for (&id, &w) in shared.widget_id_map.node_widget_ids.iter() {
+        let (inputs, outputs) = match node::socket_rects(w, ui) {
+            Some(rects) => rects,
+            None => continue,
+        };
+        let sides = (
+            node::socket_side(w, SocketType::Input, ui),
+            node::socket_side(w, SocketType::Output, ui),
+        );
+        if let (Some(input_side), Some(output_side)) = sides {
+            let typed = |ty, side| move |(socket_index, rect)| {
+                let socket = node::socket(w, ty, socket_index, ui).unwrap_or_default();
+                (NodeSocket { id, socket_index }, ty, rect, side, socket)
+            };
+            sockets.extend(inputs.enumerate().map(typed(SocketType::Input, input_side)));
+            sockets.extend(outputs.enumerate().map(typed(SocketType::Output, output_side)));
+        }
+    }
+    sockets
+}
+
+// The socket nearest to the given point that is within `SOCKET_REACH` and satisfies the given
+// predicate.
+fn nearest_socket<NI, F>(sockets: &[SocketRect<NI>], point: Point, predicate: F)
+    -> Option<(NodeSocket<NI>, SocketType)>
+where
+    NI: NodeId,
+    F: Fn(&SocketRect<NI>) -> bool,
+{
+    let distance = |r: Rect| {
+        let dx = (r.left() - point[0]).max(point[0] - r.right()).max(0.0);
+        let dy = (r.bottom() - point[1]).max(point[1] - r.top()).max(0.0);
+        dx.hypot(dy)
+    };
+    sockets.iter()
+        .filter(|s| predicate(s))
+        .map(|s| (distance(s.2), s))
+        .filter(|&(d, _)| d <= SOCKET_REACH)
+        .fold(None, |nearest: Option<(Scalar, _)>, (d, s)| match nearest {
+            Some((nearest_d, _)) if nearest_d <= d => nearest,
+            _ => Some((d, s)),
+        })
+        .map(|(_, s)| (s.0, s.1))
+}
+
+/// Whether or not the kinds of the connection's sockets are compatible.
+///
+/// This is the default edge validation function used by the `Graph`. Sockets are compatible if
+/// their kinds are equal or if either socket has no kind.
+pub fn sockets_are_compatible<NI>(connection: &Connection<NI>) -> bool {
+    match (&connection.start_socket.kind, &connection.end_socket.kind) {
+        (&Some(ref a), &Some(ref b)) => a == b,
+        _ => true,
+    }
+}
+
+// Create edges by dragging from a socket with the left mouse button and track the sockets near
+// the mouse, emitting the associated events.
+//
+// Only sockets for which `validate_edge` returns `true` may become the target of a dragged edge.
+//
+// Returns whether or not an edge was dragged during this update.
+fn update_edge_drag<NI, F>(
+    graph_id: widget::Id,
+    validate_edge: &F,
+    shared: &mut Shared<NI>,
+    ui: &UiCell,
+) -> bool
+where
+    NI: NodeId,
+    F: Fn(&Connection<NI>) -> bool,
+{
+    let sockets = socket_rects(shared, ui);
+    let was_edge_dragged = shared.maybe_edge_drag.is_some();
+
+    // The given edge drag with its ends ordered from output to input.
+    let edge_ends = |drag: &EdgeDrag<NI>, target: NodeSocket<NI>| match drag.socket_type {
+        SocketType::Output => (drag.socket, target),
+        SocketType::Input => (target, drag.socket),
+    };
+
+    // The sockets to which an edge dragged from the given socket may not connect.
+    let invalid_targets = |socket: NodeSocket<NI>, socket_type: SocketType| -> Vec<NodeSocket<NI>> {
+        let from = match sockets.iter().find(|s| s.0 == socket && s.1 == socket_type) {
+            Some(from) => from,
+            None => return Vec::new(),
+        };
+        sockets.iter()
+            .filter(|to| to.1 == opposite(socket_type))
+            .filter(|to| {
+                let (start, end) = match socket_type {
+                    SocketType::Output => (from, *to),
+                    SocketType::Input => (*to, from),
+                };
+                let connection = Connection {
+                    start: start.0,
+                    end: end.0,
+                    start_socket: start.4.clone(),
+                    end_socket: end.4.clone(),
+                };
+                !validate_edge(&connection)
+            })
+            .map(|to| to.0)
+            .collect()
+    };
+
+    // Whether or not the given socket is a valid target for the given edge drag.
+    let is_target = |drag: &EdgeDrag<NI>, s: &SocketRect<NI>| {
+        s.1 == opposite(drag.socket_type) && !drag.invalid_targets.contains(&s.0)
+    };
+
+    for event in ui.global_input().events().ui() {
+        match *event {
+            event::Ui::Press(Some(w), event::Press {
+                button: event::Button::Mouse(input::MouseButton::Left, xy),
+                ..
+            }) => {
+                if !shared.widget_id_map.is_node_widget(w) {
+                    continue;
+                }
+                let pressed = sockets.iter().find(|s| s.2.is_over(xy));
+                if let Some(&(socket, socket_type, _, _, _)) = pressed {
+                    let drag = EdgeDrag {
+                        socket,
+                        socket_type,
+                        maybe_target: None,
+                        invalid_targets: invalid_targets(socket, socket_type),
+                    };
+                    shared.maybe_edge_drag = Some(drag);
+                    shared.events.push_back(Event::Edge(EdgeEvent::AddStart(socket)));
+                }
+            },
+            event::Ui::Release(_, event::Release {
+                button: event::Button::Mouse(input::MouseButton::Left, xy),
+                ..
+            }) => {
+                let drag = match shared.maybe_edge_drag.take() {
+                    Some(drag) => drag,
+                    None => continue,
+                };
+                let target = nearest_socket(&sockets, xy, |s| is_target(&drag, s));
+                let event = match target {
+                    Some((target, _)) => {
+                        let (start, end) = edge_ends(&drag, target);
+                        EdgeEvent::Add { start, end }
+                    },
+                    None => EdgeEvent::Cancelled(drag.socket),
+                };
+                shared.events.push_back(Event::Edge(event));
+            },
+            _ => (),
+        }
+    }
+
+    // Track the socket nearest to the mouse while it is over the graph.
+    let mouse = &ui.global_input().current.mouse;
+    let is_over_graph = ui.global_input().current.widget_under_mouse
+        .map(|w| w == graph_id || ui.widget_graph().does_recursive_depth_edge_exist(graph_id, w))
+        .unwrap_or(false);
+    let hovered = match is_over_graph || shared.maybe_edge_drag.is_some() {
+        true => nearest_socket(&sockets, mouse.xy, |_| true),
+        false => None,
+    };
+    if hovered != shared.maybe_hovered_socket {
+        if let Some((socket, ty)) = shared.maybe_hovered_socket {
+            shared.events.push_back(Event::Socket(SocketEvent::HoverEnd(socket, ty)));
+        }
+        if let Some((socket, ty)) = hovered {
+            shared.events.push_back(Event::Socket(SocketEvent::HoverStart(socket, ty)));
+        }
+        shared.maybe_hovered_socket = hovered;
+    }
+
+    // Track the valid socket to which the dragged edge would connect.
+    if let Some(mut drag) = shared.maybe_edge_drag.take() {
+        drag.invalid_targets = invalid_targets(drag.socket, drag.socket_type);
+        let target = nearest_socket(&sockets, mouse.xy, |s| is_target(&drag, s))
+            .map(|(socket, _)| socket);
+        if target != drag.maybe_target {
+            if let Some(prev) = drag.maybe_target {
+                let (start, end) = edge_ends(&drag, prev);
+                shared.events.push_back(Event::Edge(EdgeEvent::TargetEnd { start, end }));
+            }
+            if let Some(target) = target {
+                let (start, end) = edge_ends(&drag, target);
+                shared.events.push_back(Event::Edge(EdgeEvent::TargetStart { start, end }));
+            }
+            drag.maybe_target = target;
+        }
+        shared.maybe_edge_drag = Some(drag);
+    }
+
+    was_edge_dragged || shared.maybe_edge_drag.is_some()
+}
@@ -1,6 +1,6 @@
 //! A default container widget to use for nodes that exist within a `Graph` widget.
 
-use {widget, color, Color, Point, Positionable, Scalar, Sizeable, Widget, Ui};
+use {widget, color, Color, Colorable, FontSize, Point, Positionable, Scalar, Sizeable, Widget, Ui};
 use graph;
 use position::{Axis, Direction, Range, Rect};
 use std::iter::once;
@@ -20,6 +20,23 @@ pub struct Node<W> {
     pub inputs: usize,
     /// The number of output sockets on the node.
     pub outputs: usize,
+    /// Descriptions of the input sockets, by index.
+    ///
+    /// Sockets beyond the end of this list use the default `Socket`.
+    pub input_sockets: Vec<Socket>,
+    /// Descriptions of the output sockets, by index.
+    ///
+    /// Sockets beyond the end of this list use the default `Socket`.
+    pub output_sockets: Vec<Socket>,
+    /// Sockets that are drawn greyed out, e.g. because they may not be connected to the edge
+    /// currently being dragged.
+    ///
+    /// This should normally be `NodeContext::invalid_sockets`.
+    pub invalid_sockets: Vec<(SocketType, usize)>,
+    /// The scale applied to the border, border radius and sockets of the node.
+    ///
+    /// This should normally be the zoom of the `Graph`'s camera, i.e. `NodeContext::zoom`.
+    pub zoom: Scalar,
 }
 
 #[allow(missing_docs)]
@@ -54,6 +71,15 @@ pub struct Style {
     /// Color of the sockets.
     #[conrod(default = "color::DARK_GREY")]
     pub socket_color: Option<Color>,
+    /// Color of the sockets that may not be connected to the edge currently being dragged.
+    #[conrod(default = "color::LIGHT_GREY")]
+    pub invalid_socket_color: Option<Color>,
+    /// Color of the socket labels.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// Font size of the socket labels, before the node's zoom is applied.
+    #[conrod(default = "theme.font_size_small")]
+    pub label_font_size: Option<FontSize>,
     /// Default layout for input sockets.
     #[conrod(default = "SocketLayout { side: SocketSide::Left, direction: Direction::Backwards }")]
     pub input_socket_layout: Option<SocketLayout>,
@@ -71,6 +97,20 @@ pub struct SocketLayout {
     pub direction: Direction,
 }
 
+/// Describes a single input or output socket of a `Node`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Socket {
+    /// Text drawn within the node beside the socket.
+    pub label: Option<String>,
+    /// A tag describing the type of data that flows through the socket.
+    ///
+    /// By default, the `Graph` only allows edges between sockets whose kinds are equal or where
+    /// either socket has no kind. See `Graph::validate_edge`.
+    pub kind: Option<String>,
+    /// The color of the socket, overriding the `Node`'s `socket_color`.
+    pub color: Option<Color>,
+}
+
 /// Represents the side of a node widget's bounding rectangle.
 ///
 /// This is used to describe default node socket layout.
@@ -97,6 +137,8 @@ widget_ids! {
         triangles,
         // The unique identifier for the wrapped widget.
         widget,
+        // A `Text` widget for each labelled socket, inputs first.
+        labels[],
     }
 }
 
@@ -109,6 +151,12 @@ pub struct State {
     inputs: usize,
     // The number of output sockets.
     outputs: usize,
+    // Descriptions of the input sockets.
+    input_sockets: Vec<Socket>,
+    // Descriptions of the output sockets.
+    output_sockets: Vec<Socket>,
+    // The scale applied to the border, border radius and sockets.
+    zoom: Scalar,
 }
 
 /// Describes whether a socket is associated with a node's inputs or outputs.
@@ -132,6 +180,10 @@ impl<W> Node<W> {
             widget,
             inputs: 0,
             outputs: 0,
+            input_sockets: Vec::new(),
+            output_sockets: Vec::new(),
+            invalid_sockets: Vec::new(),
+            zoom: 1.0,
         }
     }
 
@@ -147,6 +199,49 @@ impl<W> Node<W> {
         self
     }
 
+    /// Describe each of the node's input sockets.
+    ///
+    /// The number of inputs becomes the number of sockets given.
+    pub fn input_sockets<I>(mut self, sockets: I) -> Self
+    where
+        I: IntoIterator<Item=Socket>,
+    {
+        self.input_sockets = sockets.into_iter().collect();
+        self.inputs = self.input_sockets.len();
+        self
+    }
+
+    /// Describe each of the node's output sockets.
+    ///
+    /// The number of outputs becomes the number of sockets given.
+    pub fn output_sockets<I>(mut self, sockets: I) -> Self
+    where
+        I: IntoIterator<Item=Socket>,
+    {
+        self.output_sockets = sockets.into_iter().collect();
+        self.outputs = self.output_sockets.len();
+        self
+    }
+
+    /// Specify the sockets that should be greyed out.
+    ///
+    /// This should normally be `NodeContext::invalid_sockets`.
+    pub fn invalid_sockets<I>(mut self, sockets: I) -> Self
+    where
+        I: IntoIterator<Item=(SocketType, usize)>,
+    {
+        self.invalid_sockets = sockets.into_iter().collect();
+        self
+    }
+
+    /// Specify the scale applied to the node's border, border radius and sockets.
+    ///
+    /// This should normally be the zoom of the `Graph`'s camera, i.e. `NodeContext::zoom`.
+    pub fn zoom(mut self, zoom: Scalar) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
     /// Specify the color for the node's inner rectangle.
     pub fn color(mut self, color: Color) -> Self {
         self.style.color = Some(color);
@@ -180,6 +275,24 @@ impl<W> Node<W> {
         self
     }
 
+    /// Specify the color for sockets that may not be connected to the edge being dragged.
+    pub fn invalid_socket_color(mut self, color: Color) -> Self {
+        self.style.invalid_socket_color = Some(color);
+        self
+    }
+
+    /// Specify the color for the socket labels.
+    pub fn label_color(mut self, color: Color) -> Self {
+        self.style.label_color = Some(color);
+        self
+    }
+
+    /// Specify the font size for the socket labels.
+    pub fn label_font_size(mut self, size: FontSize) -> Self {
+        self.style.label_font_size = Some(size);
+        self
+    }
+
     /// Specify the layout of the input sockets.
     pub fn input_socket_layout(mut self, layout: SocketLayout) -> Self {
         self.style.input_socket_layout = Some(layout);
@@ -193,6 +306,38 @@ impl<W> Node<W> {
     }
 }
 
+impl Socket {
+    /// An unlabelled socket with no kind, drawn with the `Node`'s `socket_color`.
+    pub fn new() -> Self {
+        Socket::default()
+    }
+
+    /// Specify the text drawn within the node beside the socket.
+    pub fn label<S>(mut self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Specify the type of data that flows through the socket.
+    pub fn kind<S>(mut self, kind: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.kind = Some(kind.into());
+        self
+    }
+}
+
+impl Colorable for Socket {
+    fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
 impl<W> Deref for Event<W> {
     type Target = W;
     fn deref(&self) -> &Self::Target {
@@ -326,8 +471,8 @@ pub fn socket_rect(
                 Some(unique) => unique,
             };
             let rect = container.rect;
-            let border = style.border(&ui.theme);
-            let socket_length = style.socket_length(&ui.theme);
+            let border = style.border(&ui.theme) * state.zoom;
+            let socket_length = style.socket_length(&ui.theme) * state.zoom;
 
             let (n_sockets, layout) = match socket_type {
                 SocketType::Input => (state.inputs, style.input_socket_layout(&ui.theme)),
@@ -340,6 +485,44 @@ pub fn socket_rect(
         })
 }
 
+/// Retrieve the side of the given node on which sockets of the given type are laid out.
+///
+/// Returns `None` if there is no node for the given `Id`.
+pub fn socket_side(node_id: widget::Id, socket_type: SocketType, ui: &Ui) -> Option<SocketSide> {
+    ui.widget_graph()
+        .widget(node_id)
+        .and_then(|container| container.state_and_style::<State, Style>())
+        .map(|unique| match socket_type {
+            SocketType::Input => unique.style.input_socket_layout(&ui.theme).side,
+            SocketType::Output => unique.style.output_socket_layout(&ui.theme).side,
+        })
+}
+
+/// Retrieve the description of the given socket on the given node.
+///
+/// Returns `None` if there is no node for the given `Id` or if the `socket_index` is out of range.
+pub fn socket(
+    node_id: widget::Id,
+    socket_type: SocketType,
+    socket_index: usize,
+    ui: &Ui,
+) -> Option<Socket> {
+    ui.widget_graph()
+        .widget(node_id)
+        .and_then(|container| container.state_and_style::<State, Style>())
+        .and_then(|unique| {
+            let state = &unique.state;
+            let (n_sockets, sockets) = match socket_type {
+                SocketType::Input => (state.inputs, &state.input_sockets),
+                SocketType::Output => (state.outputs, &state.output_sockets),
+            };
+            match socket_index < n_sockets {
+                true => Some(sockets.get(socket_index).cloned().unwrap_or_default()),
+                false => None,
+            }
+        })
+}
+
 /// Returns a `Rect` for an edge's start and end nodes.
 pub fn edge_socket_rects<NI>(edge: &super::Edge<NI>, ui: &Ui) -> (Rect, Rect)
 where
@@ -369,8 +552,8 @@ pub fn socket_rects(node_id: widget::Id, ui: &Ui) -> Option<(SocketRects, Socket
                 Some(unique) => unique,
             };
             let rect = container.rect;
-            let border = style.border(&ui.theme);
-            let socket_length = style.socket_length(&ui.theme);
+            let border = style.border(&ui.theme) * state.zoom;
+            let socket_length = style.socket_length(&ui.theme) * state.zoom;
             let input_socket_rects = SocketRects {
                 index: 0,
                 n_sockets: state.inputs,
@@ -443,6 +626,9 @@ where
             capturing_socket: None,
             inputs: self.inputs,
             outputs: self.outputs,
+            input_sockets: self.input_sockets.clone(),
+            output_sockets: self.output_sockets.clone(),
+            zoom: self.zoom,
         }
     }
 
@@ -452,9 +638,18 @@ where
 
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs { id, state, style, rect, ui, .. } = args;
-        let Node { widget, inputs, outputs, .. } = self;
-        let socket_length = style.socket_length(&ui.theme);
-        let border = style.border(&ui.theme);
+        let Node {
+            widget,
+            inputs,
+            outputs,
+            input_sockets,
+            output_sockets,
+            invalid_sockets,
+            zoom,
+            ..
+        } = self;
+        let socket_length = style.socket_length(&ui.theme) * zoom;
+        let border = style.border(&ui.theme) * zoom;
 
         if state.inputs != inputs {
             state.update(|state| state.inputs = inputs);
@@ -464,6 +659,18 @@ where
             state.update(|state| state.outputs = outputs);
         }
 
+        if state.input_sockets != input_sockets {
+            state.update(|state| state.input_sockets = input_sockets);
+        }
+
+        if state.output_sockets != output_sockets {
+            state.update(|state| state.output_sockets = output_sockets);
+        }
+
+        if state.zoom != zoom {
+            state.update(|state| state.zoom = zoom);
+        }
+
         let input_socket_layout = style.input_socket_layout(&ui.theme);
         let output_socket_layout = style.output_socket_layout(&ui.theme);
 
@@ -543,7 +750,7 @@ where
             },
         };
 
-        let border_radius = style.border_radius(&ui.theme).min(border);
+        let border_radius = (style.border_radius(&ui.theme) * zoom).min(border);
         let border_triangles = widget::bordered_rectangle::rounded_border_triangles(
             rect,
             border,
@@ -555,11 +762,23 @@ where
 
         // A function for producing the triangles for sockets along some axis.
         let socket_color = style.socket_color(&ui.theme);
+        let invalid_socket_color = style.invalid_socket_color(&ui.theme);
+        let input_sockets = &state.input_sockets;
+        let output_sockets = &state.output_sockets;
+        let invalid_sockets = &invalid_sockets;
         let socket_triangles = |socket_type, n_sockets, layout| {
+            let sockets = match socket_type {
+                SocketType::Input => input_sockets,
+                SocketType::Output => output_sockets,
+            };
             socket_rectangles(n_sockets, layout)
                 .enumerate()
                 .flat_map(move |(i, rect)| {
                     let (tri_a, tri_b) = widget::primitive::shape::rectangle::triangles(rect);
+                    let socket_color = match invalid_sockets.contains(&(socket_type, i)) {
+                        true => invalid_socket_color,
+                        false => sockets.get(i).and_then(|s| s.color).unwrap_or(socket_color),
+                    };
                     let color = match maybe_socket_interaction {
                         Some((ty, ix, action)) if ty == socket_type && ix == i => match action {
                             Interaction::Hover => socket_color.highlighted(),
@@ -596,6 +815,44 @@ where
             .parent(id)
             .set(state.ids.widget, ui);
 
+        // Label the sockets within the inner rectangle, beside each socket.
+        let labels: Vec<(String, Rect, SocketSide)> = {
+            let labelled = |sockets: &[Socket], n_sockets, layout: SocketLayout| {
+                socket_rectangles(n_sockets, layout)
+                    .zip(sockets.iter())
+                    .filter_map(|(rect, s)| s.label.clone().map(|l| (l, rect, layout.side)))
+                    .collect::<Vec<_>>()
+            };
+            let mut labels = labelled(&state.input_sockets, inputs, input_socket_layout);
+            labels.extend(labelled(&state.output_sockets, outputs, output_socket_layout));
+            labels
+        };
+        if state.ids.labels.len() < labels.len() {
+            let n = labels.len();
+            state.update(|state| state.ids.labels.resize(n, &mut ui.widget_id_generator()));
+        }
+        let label_color = style.label_color(&ui.theme);
+        let font_size = (style.label_font_size(&ui.theme) as Scalar * zoom).round().max(1.0);
+        for (i, (label, socket_rect, side)) in labels.into_iter().enumerate() {
+            let text = widget::Text::new(&label)
+                .font_size(font_size as FontSize)
+                .color(label_color);
+            let [w, h] = text.get_wh(ui).unwrap_or([0.0, 0.0]);
+            // Offset the label inwards from the socket by half the border.
+            let [x, y] = socket_rect.xy();
+            let pad = border / 2.0;
+            let xy = match side {
+                SocketSide::Left => [inner_rect.left() + pad + w / 2.0, y],
+                SocketSide::Right => [inner_rect.right() - pad - w / 2.0, y],
+                SocketSide::Bottom => [x, inner_rect.bottom() + pad + h / 2.0],
+                SocketSide::Top => [x, inner_rect.top() - pad - h / 2.0],
+            };
+            text.xy(xy)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.labels[i], ui);
+        }
+
         Event { widget_event }
     }
 }
@@ -0,0 +1,442 @@
+//! A rotary widget for selecting a single value within some range.
+
+use {Color, Colorable, FontSize, Labelable, Positionable, Widget};
+use color;
+use event;
+use input;
+use num::{Float, NumCast, ToPrimitive};
+use position::{Point, Scalar};
+use std::f64::consts::PI;
+use text;
+use utils::clamp;
+use widget;
+use widget::triangles::Triangle;
+
+
+/// The fraction of the usual adjustment applied while the fine adjustment modifier is held.
+pub const FINE_ADJUSTMENT: Scalar = 0.1;
+
+
+/// A rotary knob for selecting a single value within some range.
+///
+/// The range is displayed as an arc around the knob, while a pointer indicates the current value.
+/// The knob is adjusted by dragging the mouse, either vertically or around the knob's centre. The
+/// adjustment may be slowed by holding the fine adjustment modifier key (`Shift` by default).
+/// Double-clicking the knob resets it to its default value, if one was given.
+///
+/// Its reaction is triggered if the value is updated.
+#[derive(WidgetCommon_)]
+pub struct Knob<'a, T> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    value: T,
+    min: T,
+    max: T,
+    maybe_default: Option<T>,
+    maybe_label: Option<&'a str>,
+    /// The mapping between the knob's angle and its value.
+    pub mapping: Mapping,
+    /// How dragging the mouse adjusts the knob.
+    pub drag: Drag,
+    /// The distance in pixels that the mouse must be dragged vertically to sweep the full range
+    /// when using `Drag::Vertical`.
+    pub drag_distance: Scalar,
+    /// The modifier key that slows adjustment for fine tuning while held.
+    pub fine_modifier: input::keyboard::ModifierKey,
+    /// The angles in radians at which the `min` and `max` values are displayed respectively.
+    ///
+    /// Angles are measured anti-clockwise from the positive *x* axis.
+    pub angle_range: (Scalar, Scalar),
+    /// The number of evenly spaced ticks displayed around the arc.
+    pub ticks: usize,
+    style: Style,
+    /// Whether or not user input is enabled for the Knob.
+    pub enabled: bool,
+}
+
+/// Graphical styling unique to the Knob widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the arc up to the current value and of the pointer.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The color of the remainder of the arc and of the ticks.
+    #[conrod(default = "theme.border_color")]
+    pub track_color: Option<Color>,
+    /// The color of the knob's face within the arc.
+    #[conrod(default = "theme.shape_color.with_luminance(0.15)")]
+    pub face_color: Option<Color>,
+    /// The thickness of the arc as a fraction of the knob's radius.
+    #[conrod(default = "0.15")]
+    pub arc_thickness: Option<Scalar>,
+    /// The color of the Knob's label.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font-size for the Knob's label.
+    #[conrod(default = "theme.font_size_small")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the label.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        arc,
+        face,
+        pointer,
+        label,
+    }
+}
+
+/// Represents the state of the Knob widget.
+pub struct State {
+    ids: Ids,
+}
+
+/// The mapping between the position of a `Knob` throughout its range and its value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Mapping {
+    /// The value changes evenly throughout the range.
+    Linear,
+    /// Each equal change in angle multiplies the value by an equal amount.
+    ///
+    /// This is useful for frequencies and gains. Both `min` and `max` must be greater than zero.
+    Logarithmic,
+    /// The value is the normalised position raised to the given exponent.
+    ///
+    /// Exponents above `1.0` give finer control over lower values.
+    Exponential(Scalar),
+}
+
+/// How dragging the mouse over a `Knob` adjusts its value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Drag {
+    /// Dragging upwards increases the value while dragging downwards decreases it.
+    Vertical,
+    /// The value follows the angle of the mouse around the knob's centre.
+    Circular,
+}
+
+impl Mapping {
+
+    /// Map the given value within the range to its normalised position, `0.0` to `1.0`.
+    pub fn perc_from_value(&self, value: Scalar, min: Scalar, max: Scalar) -> Scalar {
+        let perc = match *self {
+            Mapping::Linear => (value - min) / (max - min),
+            Mapping::Logarithmic => (value / min).ln() / (max / min).ln(),
+            Mapping::Exponential(exp) => ((value - min) / (max - min)).max(0.0).powf(1.0 / exp),
+        };
+        if perc.is_finite() { clamp(perc, 0.0, 1.0) } else { 0.0 }
+    }
+
+    /// Map the given normalised position, `0.0` to `1.0`, to its value within the range.
+    pub fn value_from_perc(&self, perc: Scalar, min: Scalar, max: Scalar) -> Scalar {
+        match *self {
+            Mapping::Linear => min + (max - min) * perc,
+            Mapping::Logarithmic => min * (max / min).powf(perc),
+            Mapping::Exponential(exp) => min + (max - min) * perc.powf(exp),
+        }
+    }
+
+}
+
+impl<'a, T> Knob<'a, T> {
+
+    /// Construct a new Knob widget.
+    pub fn new(value: T, min: T, max: T) -> Self {
+        Knob {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            value: value,
+            min: min,
+            max: max,
+            maybe_default: None,
+            maybe_label: None,
+            mapping: Mapping::Linear,
+            drag: Drag::Vertical,
+            drag_distance: 200.0,
+            fine_modifier: input::keyboard::ModifierKey::SHIFT,
+            angle_range: (PI * 1.25, -PI * 0.25),
+            ticks: 0,
+            enabled: true,
+        }
+    }
+
+    /// The value to which the knob is reset when double-clicked.
+    pub fn default_value(mut self, value: T) -> Self {
+        self.maybe_default = Some(value);
+        self
+    }
+
+    /// Specify the angles in radians at which the `min` and `max` values are displayed.
+    ///
+    /// Angles are measured anti-clockwise from the positive *x* axis, so the default range of
+    /// `(1.25 * PI, -0.25 * PI)` sweeps clockwise over the top of the knob.
+    pub fn angle_range(mut self, min: Scalar, max: Scalar) -> Self {
+        self.angle_range = (min, max);
+        self
+    }
+
+    /// Use a logarithmic mapping between the knob's angle and its value.
+    pub fn logarithmic(self) -> Self {
+        self.mapping(Mapping::Logarithmic)
+    }
+
+    /// Use an exponential mapping between the knob's angle and its value.
+    pub fn exponential(self, exponent: Scalar) -> Self {
+        self.mapping(Mapping::Exponential(exponent))
+    }
+
+    /// Adjust the knob by dragging the mouse around its centre.
+    pub fn circular_drag(self) -> Self {
+        self.drag(Drag::Circular)
+    }
+
+    /// Specify the font used for displaying the label.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods!{
+        pub mapping { mapping = Mapping }
+        pub drag { drag = Drag }
+        pub drag_distance { drag_distance = Scalar }
+        pub fine_modifier { fine_modifier = input::keyboard::ModifierKey }
+        pub ticks { ticks = usize }
+        pub track_color { style.track_color = Some(Color) }
+        pub face_color { style.face_color = Some(Color) }
+        pub arc_thickness { style.arc_thickness = Some(Scalar) }
+        pub enabled { enabled = bool }
+    }
+
+}
+
+impl<'a, T> Widget for Knob<'a, T>
+    where T: Float + NumCast + ToPrimitive,
+{
+    type State = State;
+    type Style = Style;
+    type Event = Option<T>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Knob.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, rect, style, ui, .. } = args;
+        let Knob { value, min, max, maybe_default, maybe_label, mapping, drag, .. } = self;
+        let (start_angle, end_angle) = self.angle_range;
+
+        let to_f64 = |t: T| t.to_f64().unwrap_or(0.0);
+        let (min_f, max_f) = (to_f64(min), to_f64(max));
+        let mut perc = mapping.perc_from_value(to_f64(value), min_f, max_f);
+        let mut new_value = value;
+
+        if self.enabled {
+            let input = ui.widget_input(id);
+            for drag_event in input.drags().left() {
+                let delta = match drag {
+                    Drag::Vertical => drag_event.delta_xy[1] / self.drag_distance,
+                    Drag::Circular => {
+                        let angle = |p: Point| p[1].atan2(p[0]);
+                        let mut delta_angle = angle(drag_event.to) - angle(drag_event.from);
+                        if delta_angle > PI { delta_angle -= 2.0 * PI; }
+                        if delta_angle < -PI { delta_angle += 2.0 * PI; }
+                        delta_angle / (end_angle - start_angle)
+                    },
+                };
+                let is_fine = drag_event.modifiers.contains(self.fine_modifier);
+                let delta = if is_fine { delta * FINE_ADJUSTMENT } else { delta };
+                perc = clamp(perc + delta, 0.0, 1.0);
+                let value_f = mapping.value_from_perc(perc, min_f, max_f);
+                new_value = NumCast::from(value_f).unwrap_or(value);
+            }
+            if let Some(default) = maybe_default {
+                let is_double_clicked = input.events().any(|event| match event {
+                    event::Widget::DoubleClick(click) => click.button == input::MouseButton::Left,
+                    _ => false,
+                });
+                if is_double_clicked {
+                    new_value = default;
+                    perc = mapping.perc_from_value(to_f64(default), min_f, max_f);
+                }
+            }
+        }
+
+        let is_interacting = ui.widget_input(id).mouse()
+            .map(|mouse| mouse.buttons.left().is_down());
+        let interaction_color = |color: Color| match is_interacting {
+            Some(true) => color.clicked(),
+            Some(false) => color.highlighted(),
+            None => color,
+        };
+
+        // Layout the arc within the ticks and the face within the arc.
+        let radius = rect.w().min(rect.h()) / 2.0;
+        let tick_len = if self.ticks > 0 { radius * 0.15 } else { 0.0 };
+        let arc_outer = radius - tick_len;
+        let arc_inner = arc_outer * (1.0 - style.arc_thickness(ui.theme()));
+        let face_radius = arc_inner * 0.85;
+        let value_angle = start_angle + (end_angle - start_angle) * perc;
+        let centre = rect.xy();
+
+        // The track, the arc up to the value and the ticks.
+        let color = interaction_color(style.color(ui.theme())).to_rgb();
+        let track_color = style.track_color(ui.theme()).to_rgb();
+        let track = arc_triangles(centre, arc_inner, arc_outer, value_angle, end_angle);
+        let value_arc = arc_triangles(centre, arc_inner, arc_outer, start_angle, value_angle);
+        let ticks = (0..self.ticks).flat_map(|i| {
+            let step = if self.ticks > 1 { i as Scalar / (self.ticks - 1) as Scalar } else { 0.0 };
+            let angle = start_angle + (end_angle - start_angle) * step;
+            let half_width = (PI / 180.0).min((end_angle - start_angle).abs() / 100.0);
+            arc_triangles(centre, arc_outer, radius, angle - half_width, angle + half_width)
+        });
+        let colored = |color: color::Rgba| move |Triangle(ps): Triangle<Point>| {
+            Triangle([(ps[0], color), (ps[1], color), (ps[2], color)])
+        };
+        let triangles = track.chain(ticks).map(colored(track_color))
+            .chain(value_arc.map(colored(color)));
+        widget::Triangles::multi_color(triangles)
+            .with_bounding_rect(rect)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.arc, ui);
+
+        // The face and the pointer.
+        let face_color = style.face_color(ui.theme());
+        widget::Circle::fill_with(face_radius, face_color)
+            .xy(centre)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.face, ui);
+
+        let pointer_end = [
+            centre[0] + value_angle.cos() * face_radius * 0.85,
+            centre[1] + value_angle.sin() * face_radius * 0.85,
+        ];
+        widget::Line::abs(centre, pointer_end)
+            .thickness((face_radius * 0.12).max(1.0))
+            .color(interaction_color(style.color(ui.theme())))
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.pointer, ui);
+
+        // The **Text** for the knob's label (if it has one).
+        if let Some(label) = maybe_label {
+            let label_color = style.label_color(ui.theme());
+            let font_size = style.label_font_size(ui.theme());
+            let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+            widget::Text::new(label)
+                .and_then(font_id, widget::Text::font_id)
+                .mid_bottom_of(id)
+                .graphics_for(id)
+                .color(label_color)
+                .font_size(font_size)
+                .set(state.ids.label, ui);
+        }
+
+        // If the value has just changed, return the new value.
+        if value != new_value { Some(new_value) } else { None }
+    }
+
+}
+
+/// The greatest angle in radians spanned by each segment of an arc.
+pub const MAX_ARC_SEGMENT_ANGLE: Scalar = PI / 32.0;
+
+/// An iterator yielding the triangles describing the band between the `inner` and `outer` radii
+/// of a circle around `centre`, from angle `a` to angle `b` in radians.
+#[derive(Copy, Clone, Debug)]
+pub struct ArcTriangles {
+    centre: Point,
+    inner: Scalar,
+    outer: Scalar,
+    a: Scalar,
+    b: Scalar,
+    segments: usize,
+    // The index of the next triangle to be yielded, where each segment has two triangles.
+    next: usize,
+}
+
+impl ArcTriangles {
+    /// Constructor for an iterator yielding the triangles of an arc.
+    pub fn new(centre: Point, inner: Scalar, outer: Scalar, a: Scalar, b: Scalar) -> Self {
+        let segments = ((b - a).abs() / MAX_ARC_SEGMENT_ANGLE).ceil().max(1.0) as usize;
+        ArcTriangles {
+            centre: centre,
+            inner: inner,
+            outer: outer,
+            a: a,
+            b: b,
+            segments: segments,
+            next: 0,
+        }
+    }
+
+    // The point at the given radius and angle around the centre.
+    fn point(&self, r: Scalar, angle: Scalar) -> Point {
+        [self.centre[0] + angle.cos() * r, self.centre[1] + angle.sin() * r]
+    }
+
+    // The angle at the start of the given segment.
+    fn angle(&self, segment: usize) -> Scalar {
+        self.a + (self.b - self.a) * segment as Scalar / self.segments as Scalar
+    }
+}
+
+impl Iterator for ArcTriangles {
+    type Item = Triangle<Point>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.segments * 2 {
+            return None;
+        }
+        let segment = self.next / 2;
+        let (start, end) = (self.angle(segment), self.angle(segment + 1));
+        let (a_in, a_out) = (self.point(self.inner, start), self.point(self.outer, start));
+        let (b_in, b_out) = (self.point(self.inner, end), self.point(self.outer, end));
+        let triangle = if self.next % 2 == 0 {
+            Triangle([a_in, a_out, b_out])
+        } else {
+            Triangle([a_in, b_out, b_in])
+        };
+        self.next += 1;
+        Some(triangle)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.segments * 2 - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ArcTriangles {}
+
+/// The triangles describing the band between the `inner` and `outer` radii of a circle around
+/// `centre`, from angle `a` to angle `b` in radians.
+pub fn arc_triangles(centre: Point, inner: Scalar, outer: Scalar, a: Scalar, b: Scalar)
+    -> ArcTriangles
+{
+    ArcTriangles::new(centre, inner, outer, a, b)
+}
+
+
+impl<'a, T> Colorable for Knob<'a, T> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl<'a, T> Labelable<'a> for Knob<'a, T> {
+    builder_methods!{
+        label { maybe_label = Some(&'a str) }
+        label_color { style.label_color = Some(Color) }
+        label_font_size { style.label_font_size = Some(FontSize) }
+    }
+}
@@ -11,11 +11,25 @@ use {
     Ui,
     UiCell,
 };
+use animation;
 use graph;
-use position::{Range, Rect};
+use position::{Align, Dimensions, Point, Range, Rect};
 use std;
+use utils;
 use widget;
 
+/// The distance that the mouse must travel while pressed over an item of a reorderable `List`
+/// before the item begins being dragged.
+pub const REORDER_DRAG_THRESHOLD: Scalar = 4.0;
+
+/// The distance from either end of a reorderable `List` within which a dragged item causes the
+/// `List` to scroll.
+pub const REORDER_AUTO_SCROLL_EDGE: Scalar = 32.0;
+
+/// The speed (in offset units per second) at which a reorderable `List` scrolls while an item is
+/// dragged to its very edge.
+pub const REORDER_AUTO_SCROLL_SPEED: Scalar = 600.0;
+
 /// A helper widget, useful for instantiating a sequence of widgets in a vertical list.
 ///
 /// The `List` widget simplifies this process by:
@@ -34,6 +48,8 @@ pub struct List<D, S> {
     pub style: Style,
     /// Whether all or only visible items should be instantiated.
     pub item_instantiation: ItemInstantiation,
+    /// Whether or not items may be dragged to a new position within the list.
+    pub is_reorderable: bool,
     num_items: usize,
     direction: std::marker::PhantomData<D>,
     item_size: S,
@@ -65,6 +81,21 @@ pub struct Fixed {
 #[derive(Clone, Copy, Debug)]
 pub struct Dynamic {}
 
+/// A type that implements `ItemSize` for `List`s whose `Item`s' lengths are unknown until setting
+/// the widget for each item, but may be estimated in advance.
+///
+/// Items are laid out using their estimated length until they have been instantiated, after which
+/// their measured length is cached by index. This allows for only instantiating visible items,
+/// even when each item has a different length, i.e. a chat log with many messages.
+///
+/// When the measured length of an item differs from its estimate, the `List` scrolls in order to
+/// keep the first visible item anchored in place.
+#[derive(Clone, Copy, Debug)]
+pub struct Estimated {
+    /// The estimated length of each item in the direction that the list flows.
+    pub length: Scalar,
+}
+
 /// The direction in which the list is laid out.
 pub trait Direction {
     /// The direction along which the `Scrollbar` is laid out.
@@ -95,6 +126,17 @@ pub trait Direction {
         where Self: Sized,
               S: ItemSize;
 
+    /// Snap the scrolling of the `List` so that the start edge of an item of the given `length`
+    /// is aligned with the start of the `List`.
+    ///
+    /// By default, the `List` is returned unchanged and its items are not snapped.
+    fn snap_list_items<S>(list: List<Self, S>, _length: Scalar) -> List<Self, S>
+        where Self: Sized,
+              S: ItemSize,
+    {
+        list
+    }
+
     /// Size the widget given its breadth.
     fn size_breadth<W>(widget: W, breadth: Scalar) -> W
         where W: Widget;
@@ -102,6 +144,21 @@ pub trait Direction {
     /// Size the widget given its length.
     fn size_length<W>(widget: W, length: Scalar) -> W
         where W: Widget;
+
+    /// Construct a `Rect` from its parallel and perpendicular ranges, i.e. the inverse of `ranges`.
+    ///
+    /// By default, the parallel axis is determined by passing a `Rect` that is taller than it is
+    /// wide to `ranges`.
+    fn rect(parallel: Range, perpendicular: Range) -> Rect {
+        let tall = Rect { x: Range::new(0.0, 1.0), y: Range::new(0.0, 2.0) };
+        let (tall_parallel, _) = Self::ranges(tall);
+        let (parallel, perpendicular) = (parallel.undirected(), perpendicular.undirected());
+        if tall_parallel.len() > 1.0 {
+            Rect { x: perpendicular, y: parallel }
+        } else {
+            Rect { x: parallel, y: perpendicular }
+        }
+    }
 }
 
 /// The way in which the `List`'s items are sized. E.g. `Fired` or `Dynamic`.
@@ -116,6 +173,26 @@ pub trait ItemSize: Sized + Clone + Copy {
     fn size_item<W, D>(&self, widget: W, breadth: Scalar) -> W
         where W: Widget,
               D: Direction;
+
+    /// Position the given item `widget` within the `List` and return it.
+    ///
+    /// By default, each item is positioned directly after the previous item.
+    fn position_item<W, D>(&self, widget: W, item: &Item<D, Self>) -> W
+        where W: Widget,
+              D: Direction,
+    {
+        D::position_item(widget, item.last_id, item.scroll_trigger_id, item.first_item_margin)
+    }
+
+    /// The `Rect` occupied by the item at index `i` given the current scroll offset of the `List`.
+    ///
+    /// Returns `None` if the position of the item is not yet known, which is always the case by
+    /// default.
+    fn item_rect<D>(_items: &Items<D, Self>, _ui: &Ui, _i: usize) -> Option<Rect>
+        where D: Direction,
+    {
+        None
+    }
 }
 
 /// Unique styling for the `List`.
@@ -130,6 +207,9 @@ pub struct Style {
     /// The location of the `List`'s scrollbar.
     #[conrod(default = "None")]
     pub scrollbar_position: Option<Option<ScrollbarPosition>>,
+    /// The color of the drag ghost and insertion indicator shown while reordering items.
+    #[conrod(default = "theme.label_color")]
+    pub reorder_color: Option<Color>,
 }
 
 widget_ids! {
@@ -137,12 +217,46 @@ widget_ids! {
         scroll_trigger,
         items[],
         scrollbar,
+        reorder_ghost,
+        reorder_indicator,
     }
 }
 
 /// Represents the state of the List widget.
 pub struct State {
     ids: Ids,
+    /// The cached length of each item for `List`s with `Estimated` item sizes.
+    measured_lengths: Vec<Option<Scalar>>,
+    /// The indices of the items that were instantiated during the previous update.
+    prev_item_indices: std::ops::Range<usize>,
+    /// The item currently being dragged within a reorderable `List`, if any.
+    maybe_reorder: Option<Reorder>,
+}
+
+/// An item that is being dragged to a new position within a reorderable `List`.
+#[derive(Copy, Clone, Debug)]
+struct Reorder {
+    /// The index of the dragged item.
+    from: usize,
+    /// The dimensions of the dragged item's widget, used for the drag ghost.
+    dim: Dimensions,
+    /// The position of the mouse relative to the centre of the item when the drag began.
+    grab_offset: Point,
+    /// The time of the last update during the drag.
+    last_step: instant::Instant,
+}
+
+/// Produced when items are dragged to a new position within a reorderable `List`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Move {
+    /// The indices of the moved items in ascending order.
+    ///
+    /// This only ever contains a single index for a `List`, however a `ListSelect` moves all of
+    /// its selected items when one of them is dragged.
+    pub from: Vec<usize>,
+    /// The gap between items at which the items were dropped, where `0` is before the first item
+    /// and `num_items` is after the last.
+    pub to: usize,
 }
 
 /// The data necessary for instantiating a single item within a `List`.
@@ -197,6 +311,10 @@ pub struct Items<D, S> {
     last_id: Option<widget::Id>,
     scroll_trigger_id: widget::Id,
     first_item_margin: Scalar,
+    /// The margin of each item from the start of the `scroll_trigger` if known in advance.
+    item_margins: Vec<Scalar>,
+    /// Items dragged to a new position during this update of a reorderable `List`.
+    maybe_move: Option<Move>,
     item_breadth: Scalar,
     item_size: S,
     direction: std::marker::PhantomData<D>,
@@ -251,6 +369,7 @@ impl<D, S> List<D, S>
             style: Style::default(),
             num_items: num_items,
             item_instantiation: ItemInstantiation::All,
+            is_reorderable: false,
             item_size: item_size,
             direction: std::marker::PhantomData,
         }.crop_kids()
@@ -260,16 +379,39 @@ impl<D, S> List<D, S>
     /// flowing. When a `List` is constructed with this method, all items will have a fixed, equal
     /// length.
     pub fn item_size(self, length: Scalar) -> List<D, Fixed> {
-        let List { common, style, num_items, .. } = self;
+        let List { common, style, num_items, is_reorderable, .. } = self;
         List {
             common: common,
             style: style,
             num_items: num_items,
             item_instantiation: ItemInstantiation::OnlyVisible,
+            is_reorderable: is_reorderable,
             item_size: Fixed { length: length },
             direction: std::marker::PhantomData,
         }
     }
+
+    /// Specify an estimated item size, where size is a `Scalar` in the direction that the `List`
+    /// is flowing. When a `List` is constructed with this method, items may have differing lengths
+    /// while still only instantiating those that are visible.
+    ///
+    /// The estimate is used for items that have not yet been instantiated, so the closer it is to
+    /// the average item length, the more accurate the `Scrollbar` will be.
+    ///
+    /// Measured lengths are cached by item index, so the `List`'s widget should be given a new
+    /// `widget::Id` if items are inserted or removed anywhere other than the end of the list.
+    pub fn estimated_item_size(self, length: Scalar) -> List<D, Estimated> {
+        let List { common, style, num_items, is_reorderable, .. } = self;
+        List {
+            common: common,
+            style: style,
+            num_items: num_items,
+            item_instantiation: ItemInstantiation::OnlyVisible,
+            is_reorderable: is_reorderable,
+            item_size: Estimated { length: length },
+            direction: std::marker::PhantomData,
+        }
+    }
 }
 
 impl<D> List<D, Fixed>
@@ -299,6 +441,15 @@ impl<D> List<D, Fixed>
         self.item_instantiation = ItemInstantiation::OnlyVisible;
         self
     }
+
+    /// Indicates that scrolling should settle with the start of an item aligned with the start of
+    /// the `List` once the user has finished scrolling.
+    ///
+    /// This also makes the `List` scrollable.
+    pub fn snap_to_items(self) -> Self {
+        let length = self.item_size.length;
+        D::snap_list_items(self, length)
+    }
 }
 
 impl<D, S> List<D, S>
@@ -309,14 +460,22 @@ impl<D, S> List<D, S>
     /// right of the items.
     pub fn scrollbar_next_to(mut self) -> Self {
         self.style.scrollbar_position = Some(Some(ScrollbarPosition::NextTo));
-        D::scroll_list_kids(self)
+        self.scroll_list_kids()
     }
 
     /// Specifies that the `List` should be scrollable and should provide a `Scrollbar` that hovers
     /// above the right edge of the items and automatically hides when the user is not scrolling.
     pub fn scrollbar_on_top(mut self) -> Self {
         self.style.scrollbar_position = Some(Some(ScrollbarPosition::OnTop));
-        D::scroll_list_kids(self)
+        self.scroll_list_kids()
+    }
+
+    /// Make the `List` scrollable, keeping any scrolling args that have already been specified.
+    fn scroll_list_kids(self) -> Self {
+        match D::common_scroll(&self.common) {
+            Some(_) => self,
+            None => D::scroll_list_kids(self),
+        }
     }
 
     /// The width of the `Scrollbar`.
@@ -330,6 +489,22 @@ impl<D, S> List<D, S>
         self.style.scrollbar_color = Some(color);
         self
     }
+
+    /// Allow items to be dragged to a new position within the `List` using the mouse.
+    ///
+    /// While an item is dragged, a ghost of the item follows the mouse and an indicator is shown
+    /// at the position at which it would be inserted. Dragging near either end of a scrollable
+    /// `List` scrolls it. Upon release, a `Move` may be retrieved via `Items::take_move`.
+    pub fn reorderable(mut self) -> Self {
+        self.is_reorderable = true;
+        self
+    }
+
+    /// The color of the drag ghost and insertion indicator shown while reordering items.
+    pub fn reorder_color(mut self, color: Color) -> Self {
+        self.style.reorder_color = Some(color);
+        self
+    }
 }
 
 impl<D, S> Widget for List<D, S>
@@ -343,6 +518,9 @@ impl<D, S> Widget for List<D, S>
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
             ids: Ids::new(id_gen),
+            measured_lengths: Vec::new(),
+            prev_item_indices: 0..0,
+            maybe_reorder: None,
         }
     }
 
@@ -351,7 +529,16 @@ impl<D, S> Widget for List<D, S>
     }
 
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
-        S::update_list(self, args)
+        let maybe_move = if self.is_reorderable {
+            let is_scroll_enabled = D::common_scroll(&self.common).is_some();
+            update_reorder::<D>(args.id, args.state, args.rect, args.style, is_scroll_enabled,
+                                args.ui)
+        } else {
+            None
+        };
+        let (mut items, scrollbar) = S::update_list(self, args);
+        items.maybe_move = maybe_move;
+        (items, scrollbar)
     }
 }
 
@@ -361,12 +548,38 @@ impl<D, S> Items<D, S>
           S: ItemSize,
 {
 
+    /// The `Rect` occupied by the item at index `i` given the current scroll offset of the `List`.
+    ///
+    /// For `List`s with `Fixed` item sizes, this is known even if the item is not instantiated.
+    /// This is useful for scrolling some item into view via `UiCell::scroll_to_rect`.
+    pub fn item_rect(&self, ui: &Ui, i: usize) -> Option<Rect> {
+        S::item_rect(self, ui, i)
+    }
+
+    /// Take the `Move` produced if an item of a reorderable `List` was dropped during this
+    /// update.
+    ///
+    /// No `Move` is produced if the item was dropped where it already was.
+    pub fn take_move(&mut self) -> Option<Move> {
+        self.take_drop().and_then(|move_| if move_.is_noop() { None } else { Some(move_) })
+    }
+
+    /// Take the `Move` produced if an item was dropped during this update, even if it was dropped
+    /// where it already was.
+    ///
+    /// This is useful for widgets that widen the `Move` to include other items (e.g. all items
+    /// selected within a `ListSelect`), as such a `Move` may no longer be a no-op.
+    pub fn take_drop(&mut self) -> Option<Move> {
+        self.maybe_move.take()
+    }
+
     /// Yield the next `Item` in the list.
     pub fn next(&mut self, ui: &Ui) -> Option<Item<D, S>> {
         let Items {
             ref mut item_indices,
             ref mut next_item_indices_index,
             ref mut last_id,
+            ref item_margins,
             list_id,
             scroll_trigger_id,
             first_item_margin,
@@ -375,6 +588,10 @@ impl<D, S> Items<D, S>
             ..
         } = *self;
 
+        let margin = item_margins.get(*next_item_indices_index)
+            .cloned()
+            .unwrap_or(first_item_margin);
+
         // Retrieve the `node_index` that was generated for the next `Item`.
         let node_index = match ui.widget_graph().widget(list_id)
             .and_then(|container| container.unique_widget_state::<List<D, S>>())
@@ -398,7 +615,7 @@ impl<D, S> Items<D, S>
                     scroll_trigger_id: scroll_trigger_id,
                     breadth: item_breadth,
                     size: item_size,
-                    first_item_margin: first_item_margin,
+                    first_item_margin: margin,
                     direction: std::marker::PhantomData,
                 };
                 *last_id = Some(node_index);
@@ -411,6 +628,42 @@ impl<D, S> Items<D, S>
 }
 
 
+impl Move {
+
+    /// The index of the first moved item once the `Move` has been applied.
+    pub fn new_index(&self) -> usize {
+        self.to - self.from.iter().filter(|&&i| i < self.to).count()
+    }
+
+    /// Whether or not applying the `Move` would leave the order of the items unchanged.
+    ///
+    /// This is the case when the moved items are contiguous and dropped either side of themselves.
+    pub fn is_noop(&self) -> bool {
+        let (first, last) = match (self.from.first(), self.from.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return true,
+        };
+        let is_contiguous = self.from.iter().enumerate().all(|(n, &i)| i == first + n);
+        is_contiguous && first <= self.to && self.to <= last + 1
+    }
+
+    /// Apply the `Move` to the given items, preserving the relative order of the moved items.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        let mut moved = Vec::with_capacity(self.from.len());
+        for &i in self.from.iter().rev() {
+            if i < items.len() {
+                moved.push(items.remove(i));
+            }
+        }
+        let index = std::cmp::min(self.new_index(), items.len());
+        for item in moved {
+            items.insert(index, item);
+        }
+    }
+
+}
+
+
 impl<D, S> Item<D, S>
     where D: Direction,
           S: ItemSize,
@@ -426,13 +679,11 @@ impl<D, S> Item<D, S>
     pub fn set<W>(self, widget: W, ui: &mut UiCell) -> W::Event
         where W: Widget,
     {
-        let Item {
-            widget_id, last_id, breadth, size, scroll_trigger_id, first_item_margin, ..
-        } = self;
+        let Item { widget_id, breadth, size, scroll_trigger_id, .. } = self;
 
         widget
             .and(|w| size.size_item::<W, D>(w, breadth))
-            .and(|w| D::position_item(w, last_id, scroll_trigger_id, first_item_margin))
+            .and(|w| size.position_item(w, &self))
             .parent(scroll_trigger_id)
             .set(widget_id, ui)
     }
@@ -507,6 +758,156 @@ impl<A> Scrollbar<A>
 }
 
 
+/// Update the drag of an item within a reorderable `List`.
+///
+/// The items instantiated during the previous update are used to determine which item was pressed
+/// and where the dragged item would be inserted.
+///
+/// Returns a `Move` if the dragged item was released over the list.
+fn update_reorder<D>(id: widget::Id,
+                     state: &mut widget::State<State>,
+                     rect: Rect,
+                     style: &Style,
+                     is_scroll_enabled: bool,
+                     ui: &mut UiCell) -> Option<Move>
+    where D: Direction,
+{
+    let (list_range, list_perpendicular_range) = D::ranges(rect);
+    let direction = list_range.direction();
+    let mouse = ui.global_input().current.mouse;
+    let (mouse_range, _) = D::ranges(Rect::from_xy_dim(mouse.xy, [0.0, 0.0]));
+    let mouse_pos = mouse_range.start;
+
+    // The index, `widget::Id` and `Rect` of each item instantiated during the previous update.
+    let items: Vec<(usize, widget::Id, Rect)> = state.prev_item_indices.clone()
+        .zip(state.ids.items.iter().cloned())
+        .filter(|&(_, item_id)| ui.prev_updated_widgets().contains(&item_id))
+        .filter_map(|(i, item_id)| ui.rect_of(item_id).map(|item_rect| (i, item_id, item_rect)))
+        .collect();
+
+    let now = instant::Instant::now();
+    let reorder = match state.maybe_reorder {
+        Some(reorder) => reorder,
+
+        // Begin dragging once the mouse has travelled far enough after pressing an item.
+        None => {
+            let (press_xy, pressed_id) = match mouse.buttons.left().if_down() {
+                Some((xy, Some(pressed_id))) => (xy, pressed_id),
+                _ => return None,
+            };
+            let distance = (mouse.xy[0] - press_xy[0]).hypot(mouse.xy[1] - press_xy[1]);
+            if distance < REORDER_DRAG_THRESHOLD {
+                return None;
+            }
+            let (from, item_rect) = {
+                let graph = ui.widget_graph();
+                let is_item = |item_id: widget::Id| {
+                    item_id == pressed_id
+                        || graph.does_recursive_depth_edge_exist(item_id, pressed_id)
+                };
+                match items.iter().find(|&&(_, item_id, _)| is_item(item_id)) {
+                    Some(&(i, _, item_rect)) => (i, item_rect),
+                    None => return None,
+                }
+            };
+            let reorder = Reorder {
+                from: from,
+                dim: item_rect.dim(),
+                grab_offset: [press_xy[0] - item_rect.x(), press_xy[1] - item_rect.y()],
+                last_step: now,
+            };
+            state.update(|state| state.maybe_reorder = Some(reorder));
+            reorder
+        },
+    };
+
+    // The gap between items at which the dragged item would be inserted.
+    let gap = match items.first() {
+        Some(&(first, _, _)) => {
+            let is_before_mouse = |item_rect: Rect| {
+                let (item_range, _) = D::ranges(item_rect);
+                (mouse_pos - item_range.middle()) * direction > 0.0
+            };
+            first + items.iter().filter(|&&(_, _, item_rect)| is_before_mouse(item_rect)).count()
+        },
+        None => reorder.from,
+    };
+
+    // Produce the `Move` once the item is released, unless it was released outside of the list.
+    if mouse.buttons.left().is_up() {
+        state.update(|state| state.maybe_reorder = None);
+        if !rect.is_over(mouse.xy) {
+            return None;
+        }
+        return Some(Move {
+            from: vec![reorder.from],
+            to: gap,
+        });
+    }
+
+    // Scroll the list while the item is dragged near either end.
+    if is_scroll_enabled {
+        let from_start = (mouse_pos - list_range.start) * direction;
+        let from_end = (list_range.end - mouse_pos) * direction;
+        let proximity = |distance: Scalar| {
+            1.0 - utils::clamp(distance / REORDER_AUTO_SCROLL_EDGE, 0.0, 1.0)
+        };
+        let speed = if from_start < from_end {
+            -proximity(from_start)
+        } else {
+            proximity(from_end)
+        };
+        if speed != 0.0 {
+            let secs = animation::secs(now.duration_since(reorder.last_step));
+            let scroll = -direction * speed * REORDER_AUTO_SCROLL_SPEED * secs;
+            ui.scroll_widget(id, <D::Axis as widget::scrollbar::Axis>::to_2d(scroll));
+            ui.redraw_at(now);
+        }
+    }
+    state.update(|state| {
+        if let Some(ref mut reorder) = state.maybe_reorder {
+            reorder.last_step = now;
+        }
+    });
+
+    // The ghost and indicator are instantiated upon the window so that they are neither offset by
+    // the scrolling of the list nor hidden beneath the list's items.
+    let color = style.reorder_color(&ui.theme);
+
+    // Indicate the gap at which the item would be inserted.
+    let item_edge = |i: usize, is_start: bool| {
+        items.iter().find(|&&(item_idx, _, _)| item_idx == i).map(|&(_, _, item_rect)| {
+            let (item_range, _) = D::ranges(item_rect);
+            if is_start { item_range.start } else { item_range.end }
+        })
+    };
+    let maybe_edge = item_edge(gap, true)
+        .or_else(|| if gap > 0 { item_edge(gap - 1, false) } else { None });
+    if let Some(edge) = maybe_edge {
+        let indicator_rect = D::rect(Range::new(edge - 1.0, edge + 1.0), list_perpendicular_range);
+        widget::Rectangle::fill(indicator_rect.dim())
+            .xy(indicator_rect.xy())
+            .color(color)
+            .depth(-1.0)
+            .graphics_for(id)
+            .parent(ui.window)
+            .set(state.ids.reorder_indicator, ui);
+    }
+
+    // The ghost of the dragged item follows the mouse.
+    let ghost_xy = [mouse.xy[0] - reorder.grab_offset[0], mouse.xy[1] - reorder.grab_offset[1]];
+    widget::Rectangle::fill(reorder.dim)
+        .xy(ghost_xy)
+        .color(color.alpha(0.25))
+        .depth(-1.0)
+        .graphics_for(id)
+        .parent(ui.window)
+        .set(state.ids.reorder_ghost, ui);
+
+    None
+}
+
+
 impl ItemSize for Fixed {
 
     fn update_list<D>(list: List<D, Self>, args: widget::UpdateArgs<List<D, Self>>)
@@ -592,6 +993,10 @@ impl ItemSize for Fixed {
             let id_gen = &mut ui.widget_id_generator();
             state.update(|state| state.ids.items.resize(item_idx_range.len(), id_gen));
         }
+        if state.prev_item_indices != item_idx_range {
+            let item_idx_range = item_idx_range.clone();
+            state.update(|state| state.prev_item_indices = item_idx_range);
+        }
 
         let items = Items {
             list_id: id,
@@ -602,6 +1007,8 @@ impl ItemSize for Fixed {
             item_breadth: item_breadth,
             direction: std::marker::PhantomData,
             first_item_margin: first_item_margin,
+            item_margins: Vec::new(),
+            maybe_move: None,
             item_size: item_size,
         };
 
@@ -633,6 +1040,30 @@ impl ItemSize for Fixed {
         D::size_length(widget, self.length)
     }
 
+    /// Each item is positioned at the start of its own fixed length slot, rather than relative to
+    /// the previous item. This way, items whose length is clamped by their `DimensionLimits` do
+    /// not offset the position of all following items.
+    fn position_item<W, D>(&self, widget: W, item: &Item<D, Self>) -> W
+        where W: Widget,
+              D: Direction,
+    {
+        let margin = item.i as Scalar * self.length;
+        D::position_item(widget, None, item.scroll_trigger_id, margin)
+    }
+
+    fn item_rect<D>(items: &Items<D, Self>, ui: &Ui, i: usize) -> Option<Rect>
+        where D: Direction,
+    {
+        // Each item occupies its own slot along the `scroll_trigger`, so the position is known
+        // regardless of whether or not the item is instantiated.
+        ui.rect_of(items.scroll_trigger_id).map(|scroll_trigger_rect| {
+            let (range, perpendicular_range) = D::ranges(scroll_trigger_rect);
+            let length = items.item_size.length * range.direction();
+            let start = range.start + i as Scalar * length;
+            D::rect(Range::new(start, start + length), perpendicular_range)
+        })
+    }
+
 }
 
 impl ItemSize for Dynamic {
@@ -656,6 +1087,10 @@ impl ItemSize for Dynamic {
             let id_gen = &mut ui.widget_id_generator();
             state.update(|state| state.ids.items.resize(item_idx_range.len(), id_gen));
         }
+        if state.prev_item_indices != item_idx_range {
+            let item_idx_range = item_idx_range.clone();
+            state.update(|state| state.prev_item_indices = item_idx_range);
+        }
 
         // The width of the scrollbar.
         let scrollbar_thickness = style.scrollbar_thickness(&ui.theme)
@@ -680,6 +1115,8 @@ impl ItemSize for Dynamic {
             item_breadth: item_breadth,
             direction: std::marker::PhantomData,
             first_item_margin: first_item_margin,
+            item_margins: Vec::new(),
+            maybe_move: None,
             item_size: item_size,
         };
 
@@ -710,6 +1147,239 @@ impl ItemSize for Dynamic {
         D::size_breadth(widget, breadth)
     }
 
+    fn item_rect<D>(items: &Items<D, Self>, ui: &Ui, i: usize) -> Option<Rect>
+        where D: Direction,
+    {
+        // All items are instantiated, so use the `Rect` of the item's widget.
+        ui.widget_graph().widget(items.list_id)
+            .and_then(|container| container.unique_widget_state::<List<D, Self>>())
+            .and_then(|&graph::UniqueWidgetState { ref state, .. }| state.ids.items.get(i))
+            .and_then(|&id| ui.rect_of(id))
+    }
+
+}
+
+
+impl ItemSize for Estimated {
+
+    fn update_list<D>(list: List<D, Self>, args: widget::UpdateArgs<List<D, Self>>)
+        -> <List<D, Self> as Widget>::Event
+        where D: Direction,
+    {
+        let widget::UpdateArgs { id, state, rect, prev, ui, style, .. } = args;
+        let List { common, item_size, num_items, .. } = list;
+
+        // We need a positive item length in order to do anything useful.
+        assert!(item_size.length > 0.0,
+                "the given estimated item length was {:?} however it must be > 0",
+                item_size.length);
+
+        let (list_range, list_perpendicular_range) = D::ranges(rect);
+        let list_length = list_range.len();
+        let list_breadth = list_perpendicular_range.len();
+        let is_scroll_enabled = D::common_scroll(&common).is_some();
+
+        // Measure the items that were instantiated during the previous update.
+        //
+        // The first of these items whose length was already known is used as an anchor. If any
+        // item before the anchor turns out to differ from its estimate, the list is shifted so
+        // that the anchor stays in place.
+        let mut measurements = Vec::new();
+        let mut maybe_anchor = None;
+        for (k, i) in state.prev_item_indices.clone().enumerate() {
+            if i >= num_items {
+                break;
+            }
+            let item_id = match state.ids.items.get(k) {
+                Some(&item_id) if ui.prev_updated_widgets().contains(&item_id) => item_id,
+                _ => continue,
+            };
+            let cached = state.measured_lengths.get(i).and_then(|&length| length);
+            if maybe_anchor.is_none() && cached.is_some() {
+                maybe_anchor = Some(i);
+            }
+            if let Some(item_rect) = ui.rect_of(item_id) {
+                let (item_range, _) = D::ranges(item_rect);
+                if cached != Some(item_range.len()) {
+                    measurements.push((i, item_range.len()));
+                }
+            }
+        }
+
+        // The length of the item at the given index, using the estimate if it is not yet known.
+        let item_length = |lengths: &[Option<Scalar>], i: usize| {
+            lengths.get(i).and_then(|&length| length).unwrap_or(item_size.length)
+        };
+
+        let anchor = maybe_anchor.unwrap_or(state.prev_item_indices.start);
+        let anchor = std::cmp::min(anchor, num_items);
+        let prev_anchor_start = (0..anchor)
+            .fold(0.0, |total, i| total + item_length(&state.measured_lengths, i));
+
+        if !measurements.is_empty() || state.measured_lengths.len() != num_items {
+            state.update(|state| {
+                state.measured_lengths.resize(num_items, None);
+                for (i, length) in measurements {
+                    state.measured_lengths[i] = Some(length);
+                }
+            });
+        }
+
+        // The distance from the start of the list to the start of each item, followed by the
+        // total length of all items.
+        let mut item_starts = Vec::with_capacity(num_items + 1);
+        let mut total_item_length = 0.0;
+        for i in 0..num_items {
+            item_starts.push(total_item_length);
+            total_item_length += item_length(&state.measured_lengths, i);
+        }
+        item_starts.push(total_item_length);
+
+        // Shift the items to keep the anchor in place during this update and scroll the list by
+        // the same distance so that they remain in place for the next.
+        let anchor_shift = if is_scroll_enabled {
+            item_starts[anchor] - prev_anchor_start
+        } else {
+            0.0
+        };
+        if anchor_shift != 0.0 {
+            let scroll = -list_range.direction() * anchor_shift;
+            ui.scroll_widget(id, <D::Axis as widget::scrollbar::Axis>::to_2d(scroll));
+        }
+
+        // Determine whether or not the list is currently scrollable.
+        let is_scrollable = is_scroll_enabled && total_item_length > list_length;
+
+        // The width of the scrollbar.
+        let scrollbar_thickness = style.scrollbar_thickness(&ui.theme)
+            .unwrap_or_else(|| {
+                ui.theme.widget_style::<widget::scrollbar::Style>()
+                    .and_then(|style| style.style.thickness)
+                    .unwrap_or(10.0)
+            });
+
+        let scrollbar_position = style.scrollbar_position(&ui.theme);
+        let item_breadth = match (is_scrollable, scrollbar_position) {
+            (true, Some(ScrollbarPosition::NextTo)) => list_breadth - scrollbar_thickness,
+            _ => list_breadth,
+        };
+
+        // The widget used to scroll the `List`'s range.
+        {
+            let scroll_trigger = widget::Rectangle::fill([0.0, 0.0]);
+            let scroll_trigger = D::position_scroll_trigger(scroll_trigger, id);
+            let scroll_trigger = D::size_breadth(scroll_trigger, list_breadth);
+            let scroll_trigger = D::size_length(scroll_trigger, total_item_length);
+            scroll_trigger.color(color::TRANSPARENT).parent(id).set(state.ids.scroll_trigger, ui);
+        }
+
+        // Determine the index range of the visible items.
+        let item_idx_range = {
+            let scroll_trigger_rect = ui.rect_of(state.ids.scroll_trigger).unwrap();
+            let (scroll_trigger_range, _) = D::ranges(scroll_trigger_rect);
+            let hidden_range_length = (scroll_trigger_range.start - list_range.start).abs()
+                + anchor_shift;
+            let visible_end = hidden_range_length + list_length;
+            let first_visible_item_idx = item_starts[1..].iter()
+                .take_while(|&&end| end <= hidden_range_length)
+                .count();
+            let end_visible_item_idx = item_starts[..num_items].iter()
+                .take_while(|&&start| start < visible_end)
+                .count();
+            std::cmp::min(first_visible_item_idx, end_visible_item_idx)..end_visible_item_idx
+        };
+
+        // Ensure there are at least as many indices as there are visible items.
+        if state.ids.items.len() < item_idx_range.len() {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| state.ids.items.resize(item_idx_range.len(), id_gen));
+        }
+        if state.prev_item_indices != item_idx_range {
+            let item_idx_range = item_idx_range.clone();
+            state.update(|state| state.prev_item_indices = item_idx_range);
+        }
+
+        // Update again to measure any items that are yet to be measured and to apply the anchor.
+        let is_measured = |i: usize| state.measured_lengths[i].is_some();
+        if anchor_shift != 0.0 || !item_idx_range.clone().all(is_measured) {
+            ui.redraw_at(instant::Instant::now());
+        }
+
+        let item_margins = item_idx_range.clone()
+            .map(|i| item_starts[i] - anchor_shift)
+            .collect();
+
+        let items = Items {
+            list_id: id,
+            item_indices: item_idx_range,
+            next_item_indices_index: 0,
+            last_id: None,
+            scroll_trigger_id: state.ids.scroll_trigger,
+            item_breadth: item_breadth,
+            direction: std::marker::PhantomData,
+            first_item_margin: 0.0,
+            item_margins: item_margins,
+            maybe_move: None,
+            item_size: item_size,
+        };
+
+        // Instantiate the `Scrollbar` only if necessary.
+        let auto_hide = match (is_scrollable, scrollbar_position) {
+            (false, _) | (true, None) => return (items, None),
+            (_, Some(ScrollbarPosition::NextTo)) => false,
+            (_, Some(ScrollbarPosition::OnTop)) => true,
+        };
+        let scrollbar_color = style.scrollbar_color(&ui.theme);
+        let scrollbar = D::scrollbar(id)
+            .and_if(prev.maybe_floating.is_some(), |s| s.floating(true))
+            .color(scrollbar_color)
+            .thickness(scrollbar_thickness)
+            .auto_hide(auto_hide);
+        let scrollbar = Scrollbar {
+            widget: scrollbar,
+            id: state.ids.scrollbar,
+        };
+
+        (items, Some(scrollbar))
+    }
+
+    fn size_item<W, D>(&self, widget: W, breadth: Scalar) -> W
+        where W: Widget,
+              D: Direction,
+    {
+        D::size_breadth(widget, breadth)
+    }
+
+    /// Each item is positioned at the start of its own slot so that items which are yet to be
+    /// measured do not displace those that are.
+    fn position_item<W, D>(&self, widget: W, item: &Item<D, Self>) -> W
+        where W: Widget,
+              D: Direction,
+    {
+        D::position_item(widget, None, item.scroll_trigger_id, item.first_item_margin)
+    }
+
+    fn item_rect<D>(items: &Items<D, Self>, ui: &Ui, i: usize) -> Option<Rect>
+        where D: Direction,
+    {
+        let lengths = match ui.widget_graph().widget(items.list_id)
+            .and_then(|container| container.unique_widget_state::<List<D, Self>>())
+        {
+            Some(&graph::UniqueWidgetState { ref state, .. }) => &state.measured_lengths,
+            None => return None,
+        };
+        let item_length = |i: usize| {
+            lengths.get(i).and_then(|&length| length).unwrap_or(items.item_size.length)
+        };
+        ui.rect_of(items.scroll_trigger_id).map(|scroll_trigger_rect| {
+            let (range, perpendicular_range) = D::ranges(scroll_trigger_rect);
+            let start = (0..i).fold(0.0, |total, i| total + item_length(i));
+            let start = range.start + start * range.direction();
+            let end = start + item_length(i) * range.direction();
+            D::rect(Range::new(start, end), perpendicular_range)
+        })
+    }
+
 }
 
 
@@ -735,6 +1405,16 @@ impl Direction for Down {
         list.scroll_kids_vertically()
     }
 
+    fn snap_list_items<S>(mut list: List<Self, S>, length: Scalar) -> List<Self, S>
+        where Self: Sized,
+              S: ItemSize,
+    {
+        let scroll = list.common.maybe_y_scroll.unwrap_or_else(widget::scroll::Scroll::new);
+        let snap = widget::scroll::Snap::Interval(length, Align::End);
+        list.common.maybe_y_scroll = Some(scroll.snap(snap));
+        list.crop_kids()
+    }
+
     fn position_item<W>(widget: W,
                         last_id: Option<widget::Id>,
                         scroll_trigger_id: widget::Id,
@@ -766,6 +1446,10 @@ impl Direction for Down {
         widget.h(length)
     }
 
+    fn rect(parallel: Range, perpendicular: Range) -> Rect {
+        Rect { x: perpendicular, y: parallel.undirected() }
+    }
+
 }
 
 impl Direction for Up {
@@ -790,6 +1474,16 @@ impl Direction for Up {
         list.scroll_kids_vertically()
     }
 
+    fn snap_list_items<S>(mut list: List<Self, S>, length: Scalar) -> List<Self, S>
+        where Self: Sized,
+              S: ItemSize,
+    {
+        let scroll = list.common.maybe_y_scroll.unwrap_or_else(widget::scroll::Scroll::new);
+        let snap = widget::scroll::Snap::Interval(length, Align::Start);
+        list.common.maybe_y_scroll = Some(scroll.snap(snap));
+        list.crop_kids()
+    }
+
     fn position_item<W>(widget: W,
                         last_id: Option<widget::Id>,
                         scroll_trigger_id: widget::Id,
@@ -821,6 +1515,10 @@ impl Direction for Up {
         widget.h(length)
     }
 
+    fn rect(parallel: Range, perpendicular: Range) -> Rect {
+        Rect { x: perpendicular, y: parallel }
+    }
+
 }
 
 impl Direction for Left {
@@ -845,6 +1543,16 @@ impl Direction for Left {
         list.scroll_kids_horizontally()
     }
 
+    fn snap_list_items<S>(mut list: List<Self, S>, length: Scalar) -> List<Self, S>
+        where Self: Sized,
+              S: ItemSize,
+    {
+        let scroll = list.common.maybe_x_scroll.unwrap_or_else(widget::scroll::Scroll::new);
+        let snap = widget::scroll::Snap::Interval(length, Align::End);
+        list.common.maybe_x_scroll = Some(scroll.snap(snap));
+        list.crop_kids()
+    }
+
     fn position_item<W>(widget: W,
                         last_id: Option<widget::Id>,
                         scroll_trigger_id: widget::Id,
@@ -876,6 +1584,10 @@ impl Direction for Left {
         widget.w(length)
     }
 
+    fn rect(parallel: Range, perpendicular: Range) -> Rect {
+        Rect { x: parallel.undirected(), y: perpendicular }
+    }
+
 }
 
 impl Direction for Right {
@@ -900,6 +1612,16 @@ impl Direction for Right {
         list.scroll_kids_horizontally()
     }
 
+    fn snap_list_items<S>(mut list: List<Self, S>, length: Scalar) -> List<Self, S>
+        where Self: Sized,
+              S: ItemSize,
+    {
+        let scroll = list.common.maybe_x_scroll.unwrap_or_else(widget::scroll::Scroll::new);
+        let snap = widget::scroll::Snap::Interval(length, Align::Start);
+        list.common.maybe_x_scroll = Some(scroll.snap(snap));
+        list.crop_kids()
+    }
+
     fn position_item<W>(widget: W,
                         last_id: Option<widget::Id>,
                         scroll_trigger_id: widget::Id,
@@ -931,4 +1653,8 @@ impl Direction for Right {
         widget.w(length)
     }
 
+    fn rect(parallel: Range, perpendicular: Range) -> Rect {
+        Rect { x: parallel, y: perpendicular }
+    }
+
 }
@@ -1,15 +1,19 @@
 //! A wrapper around the `List` widget providing the ability to select one or more items.
 
-use {Color, Positionable, Scalar, Sizeable, Ui, Widget};
+use {Color, Positionable, Scalar, Sizeable, Ui, UiCell, Widget};
 use {event, graph, input, widget};
 use std;
 use input::keyboard::ModifierKey;
 use input::state::mouse::Button;
 
+/// The longest duration between typed characters for which they are accumulated into a single
+/// type-ahead query.
+pub const TYPE_AHEAD_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(1000);
+
 /// A wrapper around the `List` widget that handles single and multiple selection logic.
 #[derive(Clone, WidgetCommon_)]
 #[allow(missing_copy_implementations)]
-pub struct ListSelect<M, D, S> {
+pub struct ListSelect<M, D, S, L = fn(usize) -> String> {
     #[conrod(common_builder)]
     common: widget::CommonBuilder,
     num_items: usize,
@@ -18,6 +22,8 @@ pub struct ListSelect<M, D, S> {
     item_size: S,
     style: widget::list::Style,
     item_instantiation: widget::list::ItemInstantiation,
+    is_reorderable: bool,
+    maybe_type_ahead: Option<L>,
 }
 
 /// A trait that extends the `List` `Direction` trait with behaviour necessary for the `ListSelect`
@@ -79,6 +85,18 @@ pub struct State {
     /// Tracking index of last selected entry that has been pressed in order to
     /// perform multi selection when `SHIFT` or `ALT`(Mac) / 'CTRL'(Other OS) is held.
     last_selected_entry: std::cell::Cell<Option<usize>>,
+    /// The query typed so far for finding items by their labels.
+    type_ahead: TypeAhead,
+}
+
+/// Accumulates characters typed in quick succession into a query used to jump to the first item
+/// whose label starts with it.
+///
+/// Used by the `ListSelect`, `DropDownList` and `DirectoryView` widgets.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypeAhead {
+    query: String,
+    maybe_last_input: Option<instant::Instant>,
 }
 
 /// Buffer used for storing events that have been produced but are yet to be yielded.
@@ -93,6 +111,9 @@ pub struct Events<M, D, S>
     num_items: usize,
     mode: M,
     pending_events: PendingEvents<M::Selection, D, S>,
+    maybe_type_ahead_selection: Option<usize>,
+    /// The item newly selected via the keyboard that should be scrolled into view, if any.
+    maybe_scroll_to_item: Option<usize>,
 }
 
 /// The kind of events that the `ListSelect` may `react` to.
@@ -111,6 +132,8 @@ pub enum Event<Selection, Direction, Size> {
     Click(event::Click),
     /// A double click occurred while the widget was capturing the mouse.
     DoubleClick(event::DoubleClick),
+    /// Items were dragged to a new position within a reorderable list.
+    Move(widget::list::Move),
 }
 
 /// A single item selection `Mode` for the `ListSelect`.
@@ -131,6 +154,91 @@ pub enum Selection<H: std::hash::BuildHasher = std::collections::hash_map::Rando
 }
 
 
+impl TypeAhead {
+
+    /// An empty type-ahead query.
+    pub fn new() -> Self {
+        TypeAhead {
+            query: String::new(),
+            maybe_last_input: None,
+        }
+    }
+
+    /// The query typed so far.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Append the given typed `text` to the query, first clearing the query if the last input was
+    /// more than `TYPE_AHEAD_TIMEOUT` before `now`.
+    ///
+    /// Returns the index of the first item whose label starts with the query (ignoring case),
+    /// searching from the `current` item and wrapping around. A query of a single character skips
+    /// the current item, so that repeatedly typing the same character cycles through the items
+    /// that start with it.
+    ///
+    /// Returns `None` if no characters were added or no item matches.
+    pub fn search<F>(&mut self,
+                     text: &str,
+                     now: instant::Instant,
+                     current: Option<usize>,
+                     num_items: usize,
+                     label: F) -> Option<usize>
+        where F: Fn(usize) -> String,
+    {
+        let is_expired = self.maybe_last_input
+            .map_or(true, |last| now.duration_since(last) > TYPE_AHEAD_TIMEOUT);
+        if is_expired {
+            self.query.clear();
+        }
+        let len = self.query.len();
+        self.query.extend(text.chars().filter(|c| !c.is_control()).flat_map(char::to_lowercase));
+        if self.query.len() == len {
+            return None;
+        }
+        self.maybe_last_input = Some(now);
+
+        let find = |query: &str, start: usize| {
+            (0..num_items)
+                .map(|n| (start + n) % num_items)
+                .find(|&i| label(i).to_lowercase().starts_with(query))
+        };
+        let mut chars = self.query.chars();
+        let first = chars.next().expect("the query cannot be empty");
+        let is_single_char = chars.clone().next().is_none();
+        let next = current.map_or(0, |i| i + 1);
+        let start = if is_single_char { next } else { current.unwrap_or(0) };
+        find(&self.query, start).or_else(|| {
+            // Repeating a single character cycles through the items that start with it.
+            if !is_single_char && chars.all(|c| c == first) {
+                find(&first.to_string(), next)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Search for the given `Text` event, ignoring text entered while `CTRL` or `GUI` is held.
+    ///
+    /// See `search` for details.
+    pub fn search_text<F>(&mut self,
+                          text: &event::Text,
+                          now: instant::Instant,
+                          current: Option<usize>,
+                          num_items: usize,
+                          label: F) -> Option<usize>
+        where F: Fn(usize) -> String,
+    {
+        let shortcut_modifiers = ModifierKey::CTRL | ModifierKey::GUI;
+        if text.modifiers.intersects(shortcut_modifiers) {
+            return None;
+        }
+        self.search(&text.string, now, current, num_items, label)
+    }
+
+}
+
+
 impl<H: std::hash::BuildHasher> Selection<H> {
 
     /// Update the given slice of `bool`s with this `Selection`.
@@ -186,15 +294,18 @@ impl ListSelect<Multiple, widget::list::Down, widget::list::Dynamic> {
     }
 }
 
-impl<M, D, S> ListSelect<M, D, S>
+impl<M, D, S, L> ListSelect<M, D, S, L>
     where M: Mode,
           D: Direction,
           S: widget::list::ItemSize,
 {
 
     /// Flows items from top to bottom.
-    pub fn flow_down(self) -> ListSelect<M, widget::list::Down, S> {
-        let ListSelect { common, num_items, mode, item_size, style, item_instantiation, .. } = self;
+    pub fn flow_down(self) -> ListSelect<M, widget::list::Down, S, L> {
+        let ListSelect {
+            common, num_items, mode, item_size, style, item_instantiation, is_reorderable,
+            maybe_type_ahead, ..
+        } = self;
         ListSelect {
             common: common,
             num_items: num_items,
@@ -203,12 +314,17 @@ impl<M, D, S> ListSelect<M, D, S>
             item_size: item_size,
             style: style,
             item_instantiation: item_instantiation,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: maybe_type_ahead,
         }
     }
 
     /// Flows items from left to right.
-    pub fn flow_right(self) -> ListSelect<M, widget::list::Right, S> {
-        let ListSelect { common, num_items, mode, item_size, style, item_instantiation, .. } = self;
+    pub fn flow_right(self) -> ListSelect<M, widget::list::Right, S, L> {
+        let ListSelect {
+            common, num_items, mode, item_size, style, item_instantiation, is_reorderable,
+            maybe_type_ahead, ..
+        } = self;
         ListSelect {
             common: common,
             num_items: num_items,
@@ -217,12 +333,17 @@ impl<M, D, S> ListSelect<M, D, S>
             item_size: item_size,
             style: style,
             item_instantiation: item_instantiation,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: maybe_type_ahead,
         }
     }
 
     /// Flows items from right to left.
-    pub fn flow_left(self) -> ListSelect<M, widget::list::Left, S> {
-        let ListSelect { common, num_items, mode, item_size, style, item_instantiation, .. } = self;
+    pub fn flow_left(self) -> ListSelect<M, widget::list::Left, S, L> {
+        let ListSelect {
+            common, num_items, mode, item_size, style, item_instantiation, is_reorderable,
+            maybe_type_ahead, ..
+        } = self;
         ListSelect {
             common: common,
             num_items: num_items,
@@ -231,12 +352,17 @@ impl<M, D, S> ListSelect<M, D, S>
             item_size: item_size,
             style: style,
             item_instantiation: item_instantiation,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: maybe_type_ahead,
         }
     }
 
     /// Flows items from bottom to top.
-    pub fn flow_up(self) -> ListSelect<M, widget::list::Up, S> {
-        let ListSelect { common, num_items, mode, item_size, style, item_instantiation, .. } = self;
+    pub fn flow_up(self) -> ListSelect<M, widget::list::Up, S, L> {
+        let ListSelect {
+            common, num_items, mode, item_size, style, item_instantiation, is_reorderable,
+            maybe_type_ahead, ..
+        } = self;
         ListSelect {
             common: common,
             num_items: num_items,
@@ -245,14 +371,18 @@ impl<M, D, S> ListSelect<M, D, S>
             item_size: item_size,
             style: style,
             item_instantiation: item_instantiation,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: maybe_type_ahead,
         }
     }
 
     /// Specify a fixed item size, where size is a `Scalar` in the direction that the `List` is
     /// flowing. When a `List` is constructed with this method, all items will have a fixed, equal
     /// length.
-    pub fn item_size(self, length: Scalar) -> ListSelect<M, D, widget::list::Fixed> {
-        let ListSelect { common, num_items, mode, direction, style, .. } = self;
+    pub fn item_size(self, length: Scalar) -> ListSelect<M, D, widget::list::Fixed, L> {
+        let ListSelect {
+            common, num_items, mode, direction, style, is_reorderable, maybe_type_ahead, ..
+        } = self;
         ListSelect {
             common: common,
             num_items: num_items,
@@ -261,6 +391,8 @@ impl<M, D, S> ListSelect<M, D, S>
             item_size: widget::list::Fixed { length: length },
             style: style,
             item_instantiation: widget::list::ItemInstantiation::OnlyVisible,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: maybe_type_ahead,
         }
     }
 }
@@ -281,11 +413,13 @@ impl<M> ListSelect<M, widget::list::Down, widget::list::Dynamic> {
             mode: mode,
             direction: std::marker::PhantomData,
             item_instantiation: widget::list::ItemInstantiation::All,
+            is_reorderable: false,
+            maybe_type_ahead: None,
         }
     }
 }
 
-impl<M, D, S> ListSelect<M, D, S> {
+impl<M, D, S, L> ListSelect<M, D, S, L> {
 
     /// Specifies that the `List` should be scrollable and should provide a `Scrollbar` to the
     /// right of the items.
@@ -313,9 +447,49 @@ impl<M, D, S> ListSelect<M, D, S> {
         self
     }
 
+    /// Allow items to be dragged to a new position within the list using the mouse.
+    ///
+    /// Dropping an item produces an `Event::Move`. If the dragged item is selected, all selected
+    /// items are moved along with it.
+    pub fn reorderable(mut self) -> Self {
+        self.is_reorderable = true;
+        self
+    }
+
+    /// The color of the drag ghost and insertion indicator shown while reordering items.
+    pub fn reorder_color(mut self, color: Color) -> Self {
+        self.style.reorder_color = Some(color);
+        self
+    }
+
+    /// Jump to items by typing the first letters of their labels.
+    ///
+    /// Characters typed while the list captures the keyboard are accumulated into a query for as
+    /// long as each follows the last within the `TYPE_AHEAD_TIMEOUT`. The selection then moves to
+    /// the next item whose label, as produced by the given `label` function, starts with the query.
+    pub fn type_ahead<F>(self, label: F) -> ListSelect<M, D, S, F>
+        where F: Fn(usize) -> String,
+    {
+        let ListSelect {
+            common, num_items, mode, direction, item_size, style, item_instantiation,
+            is_reorderable, ..
+        } = self;
+        ListSelect {
+            common: common,
+            num_items: num_items,
+            mode: mode,
+            direction: direction,
+            item_size: item_size,
+            style: style,
+            item_instantiation: item_instantiation,
+            is_reorderable: is_reorderable,
+            maybe_type_ahead: Some(label),
+        }
+    }
+
 }
 
-impl<M, D> ListSelect<M, D, widget::list::Fixed> {
+impl<M, D, L> ListSelect<M, D, widget::list::Fixed, L> {
 
     /// Indicates that an `Item` should be instatiated for every element in the list, regardless of
     /// whether or not the `Item` would be visible.
@@ -344,10 +518,11 @@ impl<M, D> ListSelect<M, D, widget::list::Fixed> {
 
 }
 
-impl<M, D, S> Widget for ListSelect<M, D, S>
+impl<M, D, S, L> Widget for ListSelect<M, D, S, L>
     where M: Mode,
           D: Direction,
           S: widget::list::ItemSize,
+          L: Fn(usize) -> String,
 {
     type State = State;
     type Style = widget::list::Style;
@@ -357,6 +532,7 @@ impl<M, D, S> Widget for ListSelect<M, D, S>
         State {
             ids: Ids::new(id_gen),
             last_selected_entry: std::cell::Cell::new(None),
+            type_ahead: TypeAhead::new(),
         }
     }
 
@@ -367,7 +543,9 @@ impl<M, D, S> Widget for ListSelect<M, D, S>
     /// Update the state of the ListSelect.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs { id, state, style, ui, .. } = args;
-        let ListSelect { num_items, item_size, item_instantiation, mode, .. } = self;
+        let ListSelect {
+            num_items, item_size, item_instantiation, mode, is_reorderable, maybe_type_ahead, ..
+        } = self;
 
         // Make sure that `last_selected_entry` refers to an actual selected value in the list.
         // If not push first selected item, if any.
@@ -387,7 +565,38 @@ impl<M, D, S> Widget for ListSelect<M, D, S>
             None => list,
         };
 
+        // Find the item matching any text typed while one of the items captures the keyboard.
+        let maybe_type_ahead_selection = match maybe_type_ahead {
+            Some(ref label) => {
+                let list_id = state.ids.list;
+                let texts = ui.global_input().events().ui().filter_map(|event| match *event {
+                    event::Ui::Text(Some(widget), ref text) => {
+                        if ui.widget_graph().does_recursive_depth_edge_exist(list_id, widget) {
+                            Some(text)
+                        } else {
+                            None
+                        }
+                    },
+                    _ => None,
+                });
+                let now = instant::Instant::now();
+                let mut type_ahead = state.type_ahead.clone();
+                let mut maybe_selection = None;
+                for text in texts {
+                    let current = maybe_selection.or_else(|| state.last_selected_entry.get());
+                    let found = type_ahead.search_text(text, now, current, num_items, label);
+                    maybe_selection = found.or(maybe_selection);
+                }
+                if type_ahead != state.type_ahead {
+                    state.update(|state| state.type_ahead = type_ahead);
+                }
+                maybe_selection
+            },
+            None => None,
+        };
+
         list.item_instantiation = item_instantiation;
+        list.is_reorderable = is_reorderable;
         list.style = style.clone();
         let (items, scrollbar) = list.middle_of(id).wh_of(id).set(state.ids.list, ui);
 
@@ -397,6 +606,8 @@ impl<M, D, S> Widget for ListSelect<M, D, S>
             num_items: num_items,
             mode: mode,
             pending_events: PendingEvents::new(),
+            maybe_type_ahead_selection: maybe_type_ahead_selection,
+            maybe_scroll_to_item: None,
         };
 
         (events, scrollbar)
@@ -409,9 +620,42 @@ impl<M, D, S> Events<M, D, S>
           S: widget::list::ItemSize,
 {
 
+    /// The `Rect` of the item at the given index if it is known.
+    ///
+    /// See `widget::list::Items::item_rect` for details.
+    pub fn item_rect(&self, ui: &Ui, i: usize) -> Option<::Rect> {
+        self.items.item_rect(ui, i)
+    }
+
     /// Yield the next `Event`.
+    ///
+    /// Items newly selected via the keyboard are not scrolled into view. See `next_scrolled`.
     pub fn next<F>(&mut self, ui: &Ui, is_selected: F) -> Option<Event<M::Selection, D, S>>
         where F: Fn(usize) -> bool,
+    {
+        let event = self.next_event(ui, is_selected);
+        self.maybe_scroll_to_item = None;
+        event
+    }
+
+    /// Yield the next `Event`, scrolling items newly selected via the keyboard into view.
+    pub fn next_scrolled<F>(&mut self, ui: &mut UiCell, is_selected: F)
+        -> Option<Event<M::Selection, D, S>>
+        where F: Fn(usize) -> bool,
+    {
+        let event = self.next_event(ui, is_selected);
+        if let Some(i) = self.maybe_scroll_to_item.take() {
+            let list_id = state::<M, D, S>(ui, self.id).ids.list;
+            if let Some(item_rect) = self.items.item_rect(ui, i) {
+                ui.scroll_to_rect(list_id, item_rect, widget::scroll::Alignment::Nearest);
+            }
+        }
+        event
+    }
+
+    // Yield the next `Event`, recording any item newly selected via the keyboard.
+    fn next_event<F>(&mut self, ui: &Ui, is_selected: F) -> Option<Event<M::Selection, D, S>>
+        where F: Fn(usize) -> bool,
     {
         let Events {
             id,
@@ -419,25 +663,23 @@ impl<M, D, S> Events<M, D, S>
             ref mode,
             ref mut items,
             ref mut pending_events,
+            ref mut maybe_type_ahead_selection,
+            ref mut maybe_scroll_to_item,
         } = *self;
 
         if let Some(event) = pending_events.pop_front() {
             return Some(event);
         }
 
-        let item = match items.next(ui) {
-            Some(item) => item,
-            None => return None,
-        };
-
-        // Borrow the `ListSelect::State` from the `Ui`'s widget graph.
-        let state = || {
-            ui.widget_graph()
-                .widget(id)
-                .and_then(|container| container.unique_widget_state::<ListSelect<M, D, S>>())
-                .map(|&graph::UniqueWidgetState { ref state, .. }| state)
-                .expect("couldn't find `ListSelect` state in the widget graph")
-        };
+        // If the dragged item is part of the selection, move the whole selection along with it.
+        if let Some(mut move_) = items.take_drop() {
+            if move_.from.iter().all(|&i| is_selected(i)) {
+                move_.from = (0..num_items).filter(|&i| is_selected(i)).collect();
+            }
+            if !move_.is_noop() {
+                return Some(Event::Move(move_));
+            }
+        }
 
         // Ensure's the last selected entry is still selected.
         //
@@ -450,6 +692,27 @@ impl<M, D, S> Events<M, D, S>
             }
         };
 
+        // Select the item found by type-ahead as though it were clicked without modifiers.
+        if let Some(i) = maybe_type_ahead_selection.take() {
+            let click = event::Click {
+                button: input::MouseButton::Left,
+                xy: [0.0, 0.0],
+                modifiers: ModifierKey::NO_MODIFIER,
+            };
+            let state = state::<M, D, S>(ui, id);
+            ensure_last_selected_validity(state);
+            mode.click_selection(click, i, num_items, state, &is_selected, pending_events);
+            *maybe_scroll_to_item = Some(i);
+            if let Some(event) = pending_events.pop_front() {
+                return Some(event);
+            }
+        }
+
+        let item = match items.next(ui) {
+            Some(item) => item,
+            None => return None,
+        };
+
         let i = item.i;
 
         // Check for any events that may have occurred to this widget.
@@ -467,7 +730,7 @@ impl<M, D, S> Events<M, D, S>
                 event::Widget::Click(click) => {
                     pending_events.push_back(Event::Click(click));
 
-                    let state = state();
+                    let state = state::<M, D, S>(ui, id);
                     ensure_last_selected_validity(state);
                     mode.click_selection(click, i, num_items, state,
                                          &is_selected, pending_events);
@@ -478,10 +741,15 @@ impl<M, D, S> Events<M, D, S>
                     pending_events.push_back(Event::Press(press));
 
                     if let Some(key_press) = press.key() {
-                        let state = state();
+                        let state = state::<M, D, S>(ui, id);
                         ensure_last_selected_validity(state);
+                        let last_selected_entry = state.last_selected_entry.get();
                         mode.key_selection(key_press, i, num_items, state,
                                            &is_selected, pending_events);
+                        let selected_entry = state.last_selected_entry.get();
+                        if selected_entry != last_selected_entry {
+                            *maybe_scroll_to_item = selected_entry;
+                        }
                     }
                 },
                 
@@ -492,7 +760,7 @@ impl<M, D, S> Events<M, D, S>
                         modifiers:ModifierKey::NO_MODIFIER
                     };
                     pending_events.push_back(Event::Click(dummy_click.clone()));
-                    let state = state();
+                    let state = state::<M, D, S>(ui, id);
                     ensure_last_selected_validity(state);
                     mode.click_selection(dummy_click, i, num_items, state,
                                          &is_selected, pending_events);
@@ -521,6 +789,19 @@ impl<M, D, S> Events<M, D, S>
 
 }
 
+// Borrow the `ListSelect::State` from the `Ui`'s widget graph.
+fn state<M, D, S>(ui: &Ui, id: widget::Id) -> &State
+    where M: Mode,
+          D: Direction,
+          S: widget::list::ItemSize,
+{
+    ui.widget_graph()
+        .widget(id)
+        .and_then(|container| container.unique_widget_state::<ListSelect<M, D, S>>())
+        .map(|&graph::UniqueWidgetState { ref state, .. }| state)
+        .expect("couldn't find `ListSelect` state in the widget graph")
+}
+
 impl Mode for Single {
     type Selection = usize;
 
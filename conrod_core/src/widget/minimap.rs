@@ -0,0 +1,382 @@
+//! A scaled-down overview of the contents of some scrollable widget or node graph.
+
+use {Borderable, Color, Colorable, Positionable, Widget};
+use color;
+use position::{Point, Rect, Scalar};
+use render;
+use widget;
+use widget::triangles::{ColoredPoint, Triangle};
+
+
+/// A scaled-down view of the widgets within some target widget, e.g. a scrollable `Canvas` or a
+/// `Graph`.
+///
+/// The area of the target that is currently visible is outlined over the contents. Clicking or
+/// dragging over the **Minimap** scrolls the target so that the visible area is centred on the
+/// mouse.
+///
+/// The **Minimap** should not be a descendant of its target, otherwise it would be scrolled along
+/// with and cropped by the contents that it displays, e.g. give it an explicit parent via
+/// `.parent(ui.window)`.
+///
+/// Targets that are not scrollable, e.g. the `Graph`, may be panned by the offset yielded by the
+/// **Minimap**, e.g. via `Graph::pan`. The current offset of their contents should be given via
+/// `contents_offset` so that the contents may be mapped regardless of how they were moved.
+#[derive(Copy, Clone, Debug, WidgetCommon_)]
+pub struct Minimap {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    /// The widget whose contents are displayed and scrolled by the **Minimap**.
+    pub target: widget::Id,
+    /// The offset of the contents of a target that is not scrollable from their original position.
+    pub contents_offset: Point,
+    style: Style,
+}
+
+/// Graphical styling unique to the Minimap widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the area behind the scaled-down contents.
+    #[conrod(default = "theme.shape_color.with_luminance(0.15)")]
+    pub color: Option<Color>,
+    /// The width of the border around the **Minimap**.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of the border around the **Minimap**.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the outline around the visible area of the target.
+    #[conrod(default = "color::WHITE")]
+    pub viewport_color: Option<Color>,
+}
+
+widget_ids! {
+    struct Ids {
+        background,
+        contents,
+        viewport_fill,
+        viewport_outline,
+    }
+}
+
+// Offsets smaller than this are considered to be rounding errors in the target's offset and are
+// not yielded.
+const MIN_OFFSET: Scalar = 1e-6;
+
+/// Represents the state of the Minimap widget.
+pub struct State {
+    // The bounds of the mapping when the left mouse button was pressed, if it is still down.
+    maybe_press_bounds: Option<Rect>,
+    ids: Ids,
+}
+
+/// The transformation from the absolute co-ordinates of the target's contents to those of the
+/// **Minimap**.
+///
+/// Contents are described in *content space*, i.e. their absolute position before the target's
+/// scroll offset or contents offset is applied, so that the mapping remains stable while the target is
+/// scrolled or panned.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mapping {
+    /// The bounds of the contents and visible area in content space.
+    pub bounds: Rect,
+    /// The area within the **Minimap** onto which the `bounds` are mapped.
+    pub area: Rect,
+    /// The scroll offset or contents offset of the target's contents.
+    pub offset: Point,
+}
+
+impl Mapping {
+
+    /// The scale applied to distances in content space when mapped onto the **Minimap**.
+    pub fn scale(&self) -> Scalar {
+        let w = self.area.w() / self.bounds.w().max(1.0);
+        let h = self.area.h() / self.bounds.h().max(1.0);
+        w.min(h)
+    }
+
+    /// Map the given absolute point within the target onto the **Minimap**.
+    pub fn map_point(&self, p: Point) -> Point {
+        let scale = self.scale();
+        let (bounds, area) = (self.bounds.xy(), self.area.xy());
+        [
+            area[0] + (p[0] - self.offset[0] - bounds[0]) * scale,
+            area[1] + (p[1] - self.offset[1] - bounds[1]) * scale,
+        ]
+    }
+
+    /// Map the given absolute point over the **Minimap** to content space.
+    pub fn unmap_point(&self, p: Point) -> Point {
+        let scale = self.scale();
+        let (bounds, area) = (self.bounds.xy(), self.area.xy());
+        [
+            bounds[0] + (p[0] - area[0]) / scale,
+            bounds[1] + (p[1] - area[1]) / scale,
+        ]
+    }
+
+    /// Map the given absolute rectangle within the target onto the **Minimap**.
+    pub fn map_rect(&self, rect: Rect) -> Rect {
+        let (l, r, b, t) = rect.l_r_b_t();
+        Rect::from_corners(self.map_point([l, b]), self.map_point([r, t]))
+    }
+
+}
+
+impl Minimap {
+
+    /// Begin building a **Minimap** of the given target widget.
+    pub fn new(target: widget::Id) -> Self {
+        Minimap {
+            common: widget::CommonBuilder::default(),
+            target: target,
+            contents_offset: [0.0, 0.0],
+            style: Style::default(),
+        }
+    }
+
+    /// Specify the current offset of the contents of a target that is not scrollable from their
+    /// original position.
+    ///
+    /// For a `Graph`, this is the position of the origin as viewed through its `Camera`, i.e.
+    /// `camera.view_point([0.0, 0.0])`. This is ignored for scrollable targets, whose scroll
+    /// offset is used instead.
+    pub fn contents_offset(mut self, offset: Point) -> Self {
+        self.contents_offset = offset;
+        self
+    }
+
+    builder_methods!{
+        pub viewport_color { style.viewport_color = Some(Color) }
+    }
+
+}
+
+impl Widget for Minimap {
+    type State = State;
+    type Style = Style;
+    /// The offset by which the **Minimap** has requested that the target's contents be scrolled.
+    ///
+    /// Scrollable targets are scrolled automatically. Targets that are not scrollable, e.g. the
+    /// `Graph`, may be panned by this offset instead, e.g. via `Graph::pan`.
+    type Event = Option<Point>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            maybe_press_bounds: None,
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Minimap.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, rect, style, ui, .. } = args;
+        let Minimap { target, contents_offset, .. } = self;
+        let border = style.border(&ui.theme);
+        let area = rect.pad(border);
+
+        widget::BorderedRectangle::new(rect.dim())
+            .middle_of(id)
+            .graphics_for(id)
+            .color(style.color(&ui.theme))
+            .border(border)
+            .border_color(style.border_color(&ui.theme))
+            .set(state.ids.background, ui);
+
+        let viewport = match ui.visible_area(target) {
+            Some(viewport) => viewport,
+            None => return None,
+        };
+        let is_scrollable = is_scrollable(ui, target);
+        let contents_offset = if is_scrollable { [0.0, 0.0] } else { contents_offset };
+        let mut mapping = match mapping(ui, target, area, contents_offset) {
+            Some(mapping) => mapping,
+            None => return None,
+        };
+
+        // Scroll the target so that the visible area is centred on the mouse.
+        //
+        // The bounds of the mapping are fixed while the mouse is pressed so that the point under
+        // the mouse remains the same as the target moves.
+        let mut maybe_scroll = None;
+        let maybe_pressed_mouse = ui.widget_input(id).mouse()
+            .and_then(|mouse| if mouse.buttons.left().is_down() { Some(mouse) } else { None });
+        match maybe_pressed_mouse {
+            None => if state.maybe_press_bounds.is_some() {
+                state.update(|state| state.maybe_press_bounds = None);
+            },
+            Some(mouse) => {
+                match state.maybe_press_bounds {
+                    Some(bounds) => mapping.bounds = bounds,
+                    None => state.update(|state| state.maybe_press_bounds = Some(mapping.bounds)),
+                }
+
+                // The offset that would move the point under the mouse to the centre of the
+                // visible area, relative to the offset towards which the target is scrolling.
+                let (_, target_offset) = scroll_offsets(ui, target);
+                let offset = if is_scrollable { target_offset } else { contents_offset };
+                let point = mapping.unmap_point(mouse.abs_xy());
+                let centre = viewport.xy();
+                let x = centre[0] - point[0] - offset[0];
+                let y = centre[1] - point[1] - offset[1];
+                if x.abs() > MIN_OFFSET || y.abs() > MIN_OFFSET {
+                    // Scrolling a target that is not scrollable may have some other meaning,
+                    // e.g. zooming a `Graph`, so leave these to the user.
+                    if is_scrollable {
+                        ui.scroll_widget(target, [x, y]);
+                    }
+                    maybe_scroll = Some([x, y]);
+                }
+            },
+        }
+
+        // The scaled-down primitives of every widget within the target.
+        let triangles = contents_triangles(ui, id, target, &mapping);
+        widget::Triangles::multi_color(triangles)
+            .with_bounding_rect(area)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.contents, ui);
+
+        // Outline the visible area of the target.
+        let viewport_rect = mapping.map_rect(viewport);
+        let viewport_rect = viewport_rect.overlap(area).unwrap_or(viewport_rect);
+        let viewport_color = style.viewport_color(&ui.theme);
+        widget::Rectangle::fill(viewport_rect.dim())
+            .xy(viewport_rect.xy())
+            .color(viewport_color.alpha(0.15))
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.viewport_fill, ui);
+        widget::Rectangle::outline(viewport_rect.dim())
+            .xy(viewport_rect.xy())
+            .color(viewport_color)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.viewport_outline, ui);
+
+        maybe_scroll
+    }
+
+}
+
+impl Colorable for Minimap {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl Borderable for Minimap {
+    builder_methods!{
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
+
+
+// Whether or not the target is scrollable along either axis.
+fn is_scrollable(ui: &::Ui, target: widget::Id) -> bool {
+    ui.widget_graph()
+        .widget(target)
+        .map(|c| c.maybe_x_scroll_state.is_some() || c.maybe_y_scroll_state.is_some())
+        .unwrap_or(false)
+}
+
+// The scroll offset of the target's contents, along with the offset towards which they are
+// currently moving if the target is scrolling smoothly.
+fn scroll_offsets(ui: &::Ui, target: widget::Id) -> (Point, Point) {
+    ui.widget_graph()
+        .widget(target)
+        .map(|container| {
+            let x = container.maybe_x_scroll_state;
+            let y = container.maybe_y_scroll_state;
+            let offset = [
+                x.map(|s| s.offset).unwrap_or(0.0),
+                y.map(|s| s.offset).unwrap_or(0.0),
+            ];
+            let target_offset = [
+                x.map(|s| s.target_offset()).unwrap_or(0.0),
+                y.map(|s| s.target_offset()).unwrap_or(0.0),
+            ];
+            (offset, target_offset)
+        })
+        .unwrap_or(([0.0, 0.0], [0.0, 0.0]))
+}
+
+/// The mapping from the target's contents onto the given area of a **Minimap**.
+///
+/// The contents are bounded by the visible area of the target along with the bounding box of all
+/// of its children. `contents_offset` is the offset of the contents of a target that is not
+/// scrollable from their original position, as given to `Minimap::contents_offset`. Returns `None`
+/// if the target is not visible.
+pub fn mapping(ui: &::Ui, target: widget::Id, area: Rect, contents_offset: Point)
+    -> Option<Mapping>
+{
+    let (scroll_offset, _) = scroll_offsets(ui, target);
+    let offset = [scroll_offset[0] + contents_offset[0], scroll_offset[1] + contents_offset[1]];
+    let to_content = |rect: Rect| rect.shift([-offset[0], -offset[1]]);
+    ui.visible_area(target).map(|viewport| {
+        let viewport = to_content(viewport);
+        let bounds = match ui.kids_bounding_box(target) {
+            Some(kids) => viewport.max(to_content(kids)),
+            None => viewport,
+        };
+        Mapping { bounds, area, offset }
+    })
+}
+
+// Triangles describing the primitives of every widget within the `target`, mapped onto the
+// **Minimap**.
+//
+// The minimap itself and its children are skipped in case it is placed within the target.
+fn contents_triangles(
+    ui: &::Ui,
+    minimap: widget::Id,
+    target: widget::Id,
+    mapping: &Mapping,
+) -> Vec<Triangle<ColoredPoint>>
+{
+    let graph = ui.widget_graph();
+    let ids: Vec<widget::Id> = ui.depth_order()
+        .iter()
+        .cloned()
+        .filter(|&w| w != target && graph.does_recursive_depth_edge_exist(target, w))
+        .filter(|&w| w != minimap && !graph.does_recursive_depth_edge_exist(minimap, w))
+        .collect();
+
+    let window_dim = [ui.win_w, ui.win_h];
+    let mut primitives = render::Primitives::new(graph, &ids, &ui.theme, &ui.fonts, window_dim)
+        .uncropped();
+    let map = |p: Point| mapping.map_point(p);
+    let mut triangles = Vec::new();
+    while let Some(primitive) = primitives.next() {
+        let rect_color = match primitive.kind {
+            render::PrimitiveKind::Rectangle { color } => color.to_rgb(),
+            render::PrimitiveKind::Text { color, .. } => color.alpha(0.5).to_rgb(),
+            render::PrimitiveKind::Image { color, .. } =>
+                color.unwrap_or(color::GREY).to_rgb(),
+            render::PrimitiveKind::TrianglesSingleColor { color, triangles: tris } => {
+                triangles.extend(tris.iter().map(|t| {
+                    let [a, b, c] = t.0;
+                    Triangle([(map(a), color), (map(b), color), (map(c), color)])
+                }));
+                continue;
+            },
+            render::PrimitiveKind::TrianglesMultiColor { triangles: tris } => {
+                triangles.extend(tris.iter().map(|t| {
+                    let [(a, ca), (b, cb), (c, cc)] = t.0;
+                    Triangle([(map(a), ca), (map(b), cb), (map(c), cc)])
+                }));
+                continue;
+            },
+            render::PrimitiveKind::Other(_) => continue,
+        };
+        let rect = mapping.map_rect(primitive.rect);
+        let (a, b) = widget::rectangle::triangles(rect);
+        triangles.push(a.color_all(rect_color));
+        triangles.push(b.color_all(rect_color));
+    }
+    triangles
+}
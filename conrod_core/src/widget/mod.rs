@@ -4,7 +4,7 @@
 //! re-exports all widgets (and their modules) that are provided by conrod.
 
 use graph::{Container, UniqueWidgetState};
-use position::{Align, Depth, Dimension, Dimensions, Padding, Position, Point,
+use position::{Align, Depth, Dimension, DimensionLimits, Dimensions, Padding, Position, Point,
                Positionable, Rect, Relative, Sizeable};
 use std;
 use text::font;
@@ -27,17 +27,23 @@ pub use self::primitive::text::{self, Text};
 pub use self::bordered_rectangle::BorderedRectangle;
 pub use self::button::Button;
 pub use self::canvas::Canvas;
+pub use self::chart::Chart;
+pub use self::checkbox::Checkbox;
 pub use self::collapsible_area::CollapsibleArea;
+pub use self::combo_box::ComboBox;
 pub use self::drop_down_list::DropDownList;
 pub use self::envelope_editor::EnvelopeEditor;
 pub use self::file_navigator::FileNavigator;
 pub use self::grid::Grid;
+pub use self::knob::Knob;
 pub use self::list::List;
 pub use self::list_select::ListSelect;
 pub use self::matrix::Matrix;
+pub use self::minimap::Minimap;
 pub use self::graph::Graph;
 pub use self::number_dialer::NumberDialer;
 pub use self::plot_path::PlotPath;
+pub use self::radio_group::RadioGroup;
 pub use self::range_slider::RangeSlider;
 pub use self::rounded_rectangle::RoundedRectangle;
 pub use self::scrollbar::Scrollbar;
@@ -65,17 +71,23 @@ pub mod primitive;
 pub mod bordered_rectangle;
 pub mod button;
 pub mod canvas;
+pub mod chart;
+pub mod checkbox;
 pub mod collapsible_area;
+pub mod combo_box;
 pub mod drop_down_list;
 pub mod envelope_editor;
 pub mod file_navigator;
 pub mod grid;
+pub mod knob;
 pub mod list;
 pub mod list_select;
 pub mod matrix;
+pub mod minimap;
 pub mod graph;
 pub mod number_dialer;
 pub mod plot_path;
+pub mod radio_group;
 pub mod range_slider;
 pub mod rounded_rectangle;
 pub mod scrollbar;
@@ -245,6 +257,10 @@ pub struct CommonStyle {
     pub maybe_x_dimension: Option<Dimension>,
     /// The height of a Widget.
     pub maybe_y_dimension: Option<Dimension>,
+    /// The minimum and maximum width of a Widget.
+    pub x_dimension_limits: DimensionLimits,
+    /// The minimum and maximum height of a Widget.
+    pub y_dimension_limits: DimensionLimits,
     /// The position of a Widget along the *x* axis.
     pub maybe_x_position: Option<Position>,
     /// The position of a Widget along the *y* axis.
@@ -809,6 +825,33 @@ pub trait Widget: Common + Sized {
         self.crop_kids()
     }
 
+    /// Makes the widget's `KidArea` scrollable along both axes using the given `Scroll` args.
+    ///
+    /// The args may be used to enable smooth and kinetic scrolling, e.g.
+    /// `.scroll_kids_with(scroll::Scroll::new().smooth().kinetic())`.
+    ///
+    /// This method calls `Widget::crop_kids` internally.
+    fn scroll_kids_with(self, scroll_args: scroll::Scroll) -> Self {
+        self.scroll_kids_vertically_with(scroll_args)
+            .scroll_kids_horizontally_with(scroll_args)
+    }
+
+    /// Makes the widget's `KidArea` vertically scrollable using the given `Scroll` args.
+    ///
+    /// This method calls `Widget::crop_kids` internally.
+    fn scroll_kids_vertically_with(mut self, scroll_args: scroll::Scroll) -> Self {
+        self.common_mut().maybe_y_scroll = Some(scroll_args);
+        self.crop_kids()
+    }
+
+    /// Makes the widget's `KidArea` horizontally scrollable using the given `Scroll` args.
+    ///
+    /// This method calls `Widget::crop_kids` internally.
+    fn scroll_kids_horizontally_with(mut self, scroll_args: scroll::Scroll) -> Self {
+        self.common_mut().maybe_x_scroll = Some(scroll_args);
+        self.crop_kids()
+    }
+
     /// A builder method that "lifts" the **Widget** through the given `build` function.
     ///
     /// This method is solely for providing slight ergonomic improvement by helping to maintain
@@ -1076,17 +1119,60 @@ fn set_widget<'a, 'b, W>(widget: W, id: Id, ui: &'a mut UiCell<'b>) -> W::Event
 
     for scroll in ui.widget_input(id).scrolls() {
 
-        if widget.common().maybe_x_scroll.is_some() {
+        if widget.common().maybe_x_scroll.map_or(false, |args| !args.is_animated()) {
             maybe_x_scroll_state =
                 Some(scroll::State::update(ui, id, &prev_kid_area, maybe_x_scroll_state, scroll.x))
         }
 
-        if widget.common().maybe_y_scroll.is_some() {
+        if widget.common().maybe_y_scroll.map_or(false, |args| !args.is_animated()) {
             maybe_y_scroll_state =
                 Some(scroll::State::update(ui, id, &prev_kid_area, maybe_y_scroll_state, scroll.y))
         }
     }
 
+    // Smooth and kinetic scrolling axes are stepped once per update with all of their input.
+    {
+        let x_args = widget.common().maybe_x_scroll.filter(scroll::Scroll::is_animated);
+        let y_args = widget.common().maybe_y_scroll.filter(scroll::Scroll::is_animated);
+        if x_args.is_some() || y_args.is_some() {
+            let time = instant::Instant::now();
+            let scroll = ui.widget_input(id).scrolls()
+                .fold([0.0, 0.0], |acc, scroll| [acc[0] + scroll.x, acc[1] + scroll.y]);
+            let maybe_drag = scroll::drag_delta(ui, id);
+
+            if let Some(args) = x_args {
+                let maybe_prev = maybe_prev_common.as_ref().and_then(|p| p.maybe_x_scroll_state);
+                let motion = scroll::Motion {
+                    scroll: scroll[0],
+                    maybe_drag: maybe_drag.map(|delta| delta[0]),
+                    time: time,
+                };
+                let state = scroll::State::update_animated(ui, id, &prev_kid_area, &args,
+                                                           maybe_prev, motion);
+                maybe_x_scroll_state = Some(state);
+            }
+
+            if let Some(args) = y_args {
+                let maybe_prev = maybe_prev_common.as_ref().and_then(|p| p.maybe_y_scroll_state);
+                let motion = scroll::Motion {
+                    scroll: scroll[1],
+                    maybe_drag: maybe_drag.map(|delta| delta[1]),
+                    time: time,
+                };
+                let state = scroll::State::update_animated(ui, id, &prev_kid_area, &args,
+                                                           maybe_prev, motion);
+                maybe_y_scroll_state = Some(state);
+            }
+
+            // Keep updating while either axis continues to move without user input.
+            let is_animating = maybe_x_scroll_state.map_or(false, |s| s.is_animating())
+                || maybe_y_scroll_state.map_or(false, |s| s.is_animating());
+            if is_animating {
+                ui.redraw_at(time);
+            }
+        }
+    }
+
     // Determine whether or not this is the first time set has been called.
     // We'll use this to determine whether or not we need to draw for the first time.
     let is_first_set = maybe_prev_common.is_none();
@@ -1326,4 +1412,18 @@ impl<W> Sizeable for W
     fn get_y_dimension(&self, ui: &Ui) -> Dimension {
         self.common().style.maybe_y_dimension.unwrap_or_else(|| self.default_y_dimension(ui))
     }
+    fn x_dimension_limits(mut self, limits: DimensionLimits) -> Self {
+        self.common_mut().style.x_dimension_limits = limits;
+        self
+    }
+    fn y_dimension_limits(mut self, limits: DimensionLimits) -> Self {
+        self.common_mut().style.y_dimension_limits = limits;
+        self
+    }
+    fn get_x_dimension_limits(&self) -> DimensionLimits {
+        self.common().style.x_dimension_limits
+    }
+    fn get_y_dimension_limits(&self) -> DimensionLimits {
+        self.common().style.y_dimension_limits
+    }
 }
@@ -0,0 +1,263 @@
+//! A group of mutually exclusive options, each displayed as a round button with a label.
+
+use {Color, Colorable, FontSize, Borderable, Positionable, Scalar, Widget};
+use event;
+use input;
+use position::Point;
+use text;
+use widget;
+use widget::checkbox::LabelSide;
+
+
+/// The index of an option.
+pub type Idx = usize;
+
+/// Displays the given `options` as a column of round buttons, at most one of which is selected.
+///
+/// Each option occupies an equal share of the widget's height. Pressing an option, or pressing the
+/// arrow keys while the `RadioGroup` captures the keyboard, selects it.
+///
+/// Note that the RadioGroup will not mutate the selection for you, you should do this yourself
+/// using the returned event.
+#[derive(WidgetCommon_)]
+pub struct RadioGroup<'a, T: 'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    options: &'a [T],
+    selected: Option<Idx>,
+    style: Style,
+    /// If true, will allow user inputs. If false, will disallow user inputs.
+    pub enabled: bool,
+}
+
+/// Styling for the RadioGroup including coloring, bordering and labelling.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// Color of each round button.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The width of each round button's border.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of each round button's border.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the dot marking the selected option.
+    #[conrod(default = "theme.label_color")]
+    pub dot_color: Option<Color>,
+    /// The color of the option labels.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size for the option labels.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the labels.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+    /// The side of the round buttons on which the labels are displayed.
+    #[conrod(default = "LabelSide::Right")]
+    pub label_side: Option<LabelSide>,
+    /// The distance between each round button and its label.
+    #[conrod(default = "6.0")]
+    pub label_padding: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        rims[],
+        circles[],
+        labels[],
+        dot,
+    }
+}
+
+/// The state of the RadioGroup.
+pub struct State {
+    ids: Ids,
+}
+
+impl<'a, T> RadioGroup<'a, T> {
+
+    /// Construct a new RadioGroup with the given options and selected option.
+    pub fn new(options: &'a [T], selected: Option<Idx>) -> Self {
+        RadioGroup {
+            common: widget::CommonBuilder::default(),
+            options: options,
+            selected: selected,
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// Specify the font used for displaying the labels.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    /// Display the labels to the left of the round buttons.
+    pub fn label_left(self) -> Self {
+        self.label_side(LabelSide::Left)
+    }
+
+    /// Display the labels to the right of the round buttons.
+    pub fn label_right(self) -> Self {
+        self.label_side(LabelSide::Right)
+    }
+
+    builder_methods!{
+        pub enabled { enabled = bool }
+        pub dot_color { style.dot_color = Some(Color) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+        pub label_side { style.label_side = Some(LabelSide) }
+        pub label_padding { style.label_padding = Some(Scalar) }
+    }
+
+}
+
+impl<'a, T> Widget for RadioGroup<'a, T>
+    where T: AsRef<str>,
+{
+    type State = State;
+    type Style = Style;
+    type Event = Option<Idx>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the RadioGroup.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, style, rect, ui, .. } = args;
+        let RadioGroup { options, selected, enabled, .. } = self;
+        let num_options = options.len();
+
+        if num_options == 0 {
+            return None;
+        }
+
+        // The index of the option at the given position relative to the centre of the group.
+        let option_h = rect.h() / num_options as Scalar;
+        let option_at = |xy: Point| {
+            let i = ((rect.h() / 2.0 - xy[1]) / option_h).max(0.0) as usize;
+            ::std::cmp::min(i, num_options - 1)
+        };
+
+        // Select options via the mouse and the arrow keys.
+        let mut new_selected = selected.and_then(|i| if i < num_options { Some(i) } else { None });
+        if enabled {
+            let last = num_options - 1;
+            for event in ui.widget_input(id).events() {
+                new_selected = match event {
+                    event::Widget::Click(click) if click.button == input::MouseButton::Left =>
+                        Some(option_at(click.xy)),
+                    event::Widget::Tap(tap) => Some(option_at(tap.xy)),
+                    event::Widget::Press(press) => match press.key().map(|press| press.key) {
+                        Some(input::Key::Up) | Some(input::Key::Left) =>
+                            Some(new_selected.map_or(0, |i| i.saturating_sub(1))),
+                        Some(input::Key::Down) | Some(input::Key::Right) =>
+                            Some(new_selected.map_or(0, |i| ::std::cmp::min(i + 1, last))),
+                        _ => new_selected,
+                    },
+                    _ => new_selected,
+                };
+            }
+        }
+
+        // Ensure there are enough ids for each option.
+        if state.ids.circles.len() < num_options {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.rims.resize(num_options, id_gen);
+                state.ids.circles.resize(num_options, id_gen);
+                state.ids.labels.resize(num_options, id_gen);
+            });
+        }
+
+        // The option currently under the mouse, if any.
+        let maybe_hovered = ui.widget_input(id).mouse().map(|mouse| {
+            (option_at(mouse.rel_xy()), mouse.buttons.left().is_down())
+        });
+
+        let color = style.color(ui.theme());
+        let border = style.border(ui.theme());
+        let border_color = style.border_color(ui.theme());
+        let dot_color = style.dot_color(ui.theme());
+        let label_color = style.label_color(ui.theme());
+        let font_size = style.label_font_size(ui.theme());
+        let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+        let padding = style.label_padding(ui.theme());
+        let label_side = style.label_side(ui.theme());
+
+        // Each round button is a circle on the side of its option opposite the label.
+        let radius = option_h.min(rect.w()) * 0.4;
+        let circle_x = match label_side {
+            LabelSide::Left => rect.w() / 2.0 - option_h / 2.0,
+            LabelSide::Right => option_h / 2.0 - rect.w() / 2.0,
+        };
+
+        for (i, option) in options.iter().enumerate() {
+            let circle_y = rect.h() / 2.0 - option_h * (i as Scalar + 0.5);
+            let color = match maybe_hovered {
+                Some((hovered, is_down)) if enabled && hovered == i =>
+                    if is_down { color.clicked() } else { color.highlighted() },
+                _ => color,
+            };
+
+            widget::Circle::fill_with(radius, border_color)
+                .x_y_relative_to(id, circle_x, circle_y)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.rims[i], ui);
+
+            widget::Circle::fill_with((radius - border).max(0.0), color)
+                .x_y_relative_to(id, circle_x, circle_y)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.circles[i], ui);
+
+            let rim_id = state.ids.rims[i];
+            let text = widget::Text::new(option.as_ref())
+                .and_then(font_id, widget::Text::font_id)
+                .align_middle_y_of(rim_id)
+                .graphics_for(id)
+                .parent(id)
+                .color(label_color)
+                .font_size(font_size);
+            match label_side {
+                LabelSide::Left => text.left_from(rim_id, padding),
+                LabelSide::Right => text.right_from(rim_id, padding),
+            }.set(state.ids.labels[i], ui);
+        }
+
+        // The dot marking the selected option.
+        if let Some(i) = new_selected {
+            widget::Circle::fill_with(radius * 0.5, dot_color)
+                .middle_of(state.ids.circles[i])
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.dot, ui);
+        }
+
+        if new_selected != selected { new_selected } else { None }
+    }
+}
+
+
+impl<'a, T> Colorable for RadioGroup<'a, T> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl<'a, T> Borderable for RadioGroup<'a, T> {
+    builder_methods!{
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
@@ -1,15 +1,82 @@
 //! Scroll related types and logic.
 
 use Ui;
+use animation::{self, Spring};
+use daggy::Walker;
 use position::{Align, Point, Padding, Range, Rect, Scalar};
 use std::marker::PhantomData;
+use utils;
 
 
+/// The speed (in offset units per second) below which a kinetic scroll comes to rest.
+pub const MIN_KINETIC_VELOCITY: Scalar = 5.0;
+
+/// The time (in seconds) that must pass without any scrolling input before a snapping axis begins
+/// moving towards its nearest snap point.
+pub const SNAP_DELAY_SECS: Scalar = 0.15;
+
+/// The stiffness of the critically damped spring used to move towards a snap point when smooth
+/// scrolling is not enabled.
+pub const SNAP_STIFFNESS: Scalar = 300.0;
+
 /// Arguments given via a scrollable `Widget`'s builder methods for the scrolling along a single
 /// axis.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Scroll {
     maybe_initial_alignment: Option<Align>,
+    /// If `Some`, `Scroll` events move a target offset towards which the offset is smoothly
+    /// animated using the given spring, rather than jumping the offset directly.
+    pub maybe_smooth: Option<Spring>,
+    /// If `Some`, the kid area may be dragged via the mouse or touch and flung so that it
+    /// continues scrolling with momentum after release.
+    pub maybe_kinetic: Option<Kinetic>,
+    /// If `Some`, the offset settles on the nearest snap point once scrolling comes to an end.
+    pub maybe_snap: Option<Snap>,
+}
+
+/// The points at which a scrolling axis may come to rest.
+///
+/// Snapping is applied once wheel, drag and kinetic scrolling have come to an end, moving the
+/// offset to the nearest snap point within the `offset_bounds`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Snap {
+    /// Snap so that the given edge of one of the widget's kids is aligned with the same edge of
+    /// the kid area.
+    ///
+    /// Snapping to the `Middle` centres the nearest kid, e.g. for carousels.
+    KidEdges(Align),
+    /// Snap to every multiple of the given distance, measured from the given edge of the
+    /// scrollable range (the bounding box of all kid widgets).
+    ///
+    /// The given edge of the kid area is aligned with the snap point.
+    Interval(Scalar, Align),
+    /// Snap to whole pages, where the length of a page is the length of the kid area.
+    Pages(Align),
+}
+
+/// Parameters for kinetic (aka inertial) scrolling.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Kinetic {
+    /// The rate at which the velocity of a flung kid area decays.
+    ///
+    /// Each second, the velocity is multiplied by `e^-friction`.
+    pub friction: Scalar,
+    /// The maximum distance that the offset may be dragged or flung beyond its bounds.
+    pub max_overscroll: Scalar,
+    /// The spring that pulls the offset back within its bounds after overscrolling.
+    pub bounce: Spring,
+}
+
+/// The input that moves a smooth or kinetic scrolling axis during a single update.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Motion {
+    /// The sum of all `Scroll` events received along the axis.
+    pub scroll: Scalar,
+    /// `Some` while the kid area is held by a pointer, describing the distance that the pointer
+    /// moved along the axis since the last update.
+    pub maybe_drag: Option<Scalar>,
+    /// The moment in time at which the update occurs.
+    pub time: instant::Instant,
 }
 
 /// Scroll state calculated for a single axis.
@@ -30,6 +97,16 @@ pub struct State<A> {
     pub scrollable_range_len: Scalar,
     /// Whether or not the this axis is currently scrolling.
     pub is_scrolling: bool,
+    /// The velocity of the offset in units per second for smooth and kinetic scrolling.
+    pub velocity: Scalar,
+    /// Whether or not the kid area is currently being dragged by a pointer.
+    pub is_dragging: bool,
+    /// The offset towards which a smooth scroll is moving, if any.
+    maybe_target_offset: Option<Scalar>,
+    /// The time of the last update while the offset was in motion.
+    maybe_last_step: Option<instant::Instant>,
+    /// The time of the last scrolling input that has not yet been followed by snapping.
+    maybe_last_input: Option<instant::Instant>,
     /// The axis type used to instantiate this state.
     axis: PhantomData<A>,
 }
@@ -63,12 +140,81 @@ pub type StateX = State<X>;
 /// State for scrolling along the **Y** axis.
 pub type StateY = State<Y>;
 
+/// Describes where a `Rect` should be placed within the visible area of a scrollable widget when
+/// scrolled into view.
+///
+/// Following conrod's conventions, the *start* of an axis is the left edge for **X** and the
+/// bottom edge for **Y**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Align the start edge of the `Rect` with the start edge of the visible area.
+    Start,
+    /// Centre the `Rect` within the visible area.
+    Middle,
+    /// Align the end edge of the `Rect` with the end edge of the visible area.
+    End,
+    /// Scroll by the smallest distance necessary for the `Rect` to become fully visible.
+    ///
+    /// Nothing is scrolled if the `Rect` is already fully visible.
+    Nearest,
+}
+
 
 impl Scroll {
+
     /// The default `Scroll` args.
     pub fn new() -> Self {
         Scroll {
             maybe_initial_alignment: None,
+            maybe_smooth: None,
+            maybe_kinetic: None,
+            maybe_snap: None,
+        }
+    }
+
+    /// Smoothly animate the offset towards the target of `Scroll` events using a critically
+    /// damped spring.
+    pub fn smooth(self) -> Self {
+        self.smooth_with(Spring::critically_damped(300.0))
+    }
+
+    /// Smoothly animate the offset towards the target of `Scroll` events using the given spring.
+    pub fn smooth_with(mut self, spring: Spring) -> Self {
+        self.maybe_smooth = Some(spring);
+        self
+    }
+
+    /// Allow the kid area to be dragged and flung using the default `Kinetic` parameters.
+    pub fn kinetic(self) -> Self {
+        self.kinetic_with(Kinetic::default())
+    }
+
+    /// Allow the kid area to be dragged and flung using the given `Kinetic` parameters.
+    pub fn kinetic_with(mut self, kinetic: Kinetic) -> Self {
+        self.maybe_kinetic = Some(kinetic);
+        self
+    }
+
+    /// Settle on the nearest of the given snap points once scrolling comes to an end.
+    pub fn snap(mut self, snap: Snap) -> Self {
+        self.maybe_snap = Some(snap);
+        self
+    }
+
+    /// Whether or not the offset is animated over time rather than being moved directly by
+    /// `Scroll` events.
+    pub fn is_animated(&self) -> bool {
+        self.maybe_smooth.is_some() || self.maybe_kinetic.is_some() || self.maybe_snap.is_some()
+    }
+
+}
+
+impl Default for Kinetic {
+    fn default() -> Self {
+        Kinetic {
+            friction: 2.0,
+            max_overscroll: 100.0,
+            bounce: Spring::critically_damped(150.0),
         }
     }
 }
@@ -166,31 +312,8 @@ impl<A> State<A>
             .map(|state| state.offset)
             .unwrap_or(0.0);
 
-        // Padding for the range.
-        let padding = A::padding_range(kid_area.pad);
-
-        // Get the range for the Axis that concerns this particular scroll `State`.
-        let kid_area_range = A::parallel_range(kid_area.rect).pad_ends(padding.start, padding.end);
-
-        // The `kid_area_range` but centred at zero.
-        let kid_area_range_origin = Range::from_pos_and_len(0.0, kid_area_range.magnitude());
-
-        // The un-scrolled, scrollable_range relative to the kid_area_range's position.
-        let scrollable_range = {
-            ui.kids_bounding_box(idx)
-                .map(|kids| {
-                    A::parallel_range(kids)
-                        .shift(-current_offset)
-                        .shift(-kid_area_range.middle())
-                })
-                .unwrap_or_else(|| Range::new(0.0, 0.0))
-        };
-
-        // Determine the min and max offset bounds. These bounds are the limits to which the
-        // scrollable_range may be shifted in either direction across the range.
-        let min_offset = Range::new(scrollable_range.start, kid_area_range_origin.start).magnitude();
-        let max_offset = Range::new(scrollable_range.end, kid_area_range_origin.end).magnitude();
-        let offset_bounds = Range::new(min_offset, max_offset);
+        let (offset_bounds, scrollable_range, kid_area_range) =
+            bounds::<A>(ui, idx, kid_area, current_offset);
 
         // The range is only scrollable if it is longer than the padded kid_area_range.
         let is_scrollable = scrollable_range.len() > kid_area_range.len();
@@ -219,9 +342,360 @@ impl<A> State<A>
             scrollable_range_len: scrollable_range.len(),
             axis: PhantomData,
             is_scrolling: additional_offset != 0.0,
+            velocity: 0.0,
+            is_dragging: false,
+            maybe_target_offset: None,
+            maybe_last_step: None,
+            maybe_last_input: None,
+        }
+    }
+
+    /// Calculate the new scroll state for a single axis of a `Widget` whose `Scroll` args enable
+    /// smooth and/or kinetic scrolling.
+    ///
+    /// Unlike `update`, the offset is stepped over time towards its target, continues with
+    /// momentum after a kinetic drag is released and, for kinetic scrolling, may temporarily
+    /// exceed the `offset_bounds` before bouncing back.
+    pub fn update_animated(ui: &Ui,
+                           idx: super::Id,
+                           kid_area: &super::KidArea,
+                           scroll_args: &Scroll,
+                           maybe_prev_scroll_state: Option<Self>,
+                           motion: Motion) -> Self
+    {
+        let Motion { scroll, maybe_drag, time } = motion;
+        let current_offset = maybe_prev_scroll_state.as_ref()
+            .map(|state| state.offset)
+            .unwrap_or(0.0);
+        let (offset_bounds, scrollable_range, kid_area_range) =
+            bounds::<A>(ui, idx, kid_area, current_offset);
+        let is_scrollable = scrollable_range.len() > kid_area_range.len();
+
+        // The time elapsed since the last step while in motion.
+        let dt = maybe_prev_scroll_state.as_ref()
+            .and_then(|prev| prev.maybe_last_step)
+            .map(|last| if time > last { animation::secs(time.duration_since(last)) } else { 0.0 })
+            .unwrap_or(0.0);
+
+        let mut offset = current_offset;
+        let mut velocity = maybe_prev_scroll_state.as_ref().map(|s| s.velocity).unwrap_or(0.0);
+        let mut maybe_target = maybe_prev_scroll_state.as_ref().and_then(|s| s.maybe_target_offset);
+        let mut maybe_last_input = maybe_prev_scroll_state.as_ref()
+            .and_then(|s| s.maybe_last_input);
+
+        // The range within which the offset may move, including any permitted overscroll.
+        let max_overscroll = scroll_args.maybe_kinetic.map(|k| k.max_overscroll).unwrap_or(0.0);
+        let lowest = utils::partial_min(offset_bounds.start, offset_bounds.end);
+        let highest = utils::partial_max(offset_bounds.start, offset_bounds.end);
+        let overscroll_bounds = Range::new(lowest - max_overscroll, highest + max_overscroll);
+
+        let is_dragging = is_scrollable && scroll_args.maybe_kinetic.is_some() && maybe_drag.is_some();
+
+        if !is_scrollable {
+            offset = offset_bounds.clamp_value(offset);
+            velocity = 0.0;
+            maybe_target = None;
+
+        } else if let (true, Some(delta)) = (is_dragging, maybe_drag) {
+            // Follow the pointer, resisting more strongly the further the offset overscrolls.
+            let overscroll = (offset - offset_bounds.clamp_value(offset)).abs();
+            let resistance = if max_overscroll > 0.0 {
+                utils::clamp(1.0 - overscroll / max_overscroll, 0.0, 1.0)
+            } else {
+                1.0
+            };
+            let stays_within_bounds = offset_bounds.clamp_value(offset + delta) == offset + delta;
+            let delta = if stays_within_bounds { delta } else { delta * resistance };
+            offset = overscroll_bounds.clamp_value(offset + delta);
+            maybe_target = None;
+
+            // Track the velocity of the pointer so that the area may be flung upon release.
+            if dt > 0.0 {
+                velocity = velocity * 0.2 + (delta / dt) * 0.8;
+            }
+
+        } else {
+            // Apply any `Scroll` events.
+            if scroll != 0.0 {
+                match scroll_args.maybe_smooth {
+                    Some(_) => {
+                        let from = maybe_target.unwrap_or(offset);
+                        maybe_target = Some(offset_bounds.clamp_value(from + scroll));
+                    },
+                    None => {
+                        offset = offset_bounds.clamp_value(offset + scroll);
+                        velocity = 0.0;
+                        maybe_target = None;
+                    },
+                }
+            }
+
+            // Once the offset has come to rest, move towards the nearest snap point.
+            if let Some(snap) = scroll_args.maybe_snap {
+                let is_at_rest = scroll == 0.0
+                    && maybe_target.is_none()
+                    && velocity == 0.0
+                    && offset_bounds.clamp_value(offset) == offset;
+                let is_waiting = maybe_last_input.map(|last| {
+                    time < last || animation::secs(time.duration_since(last)) < SNAP_DELAY_SECS
+                }).unwrap_or(false);
+                if is_at_rest && !is_waiting {
+                    maybe_last_input = None;
+                    let maybe_snap_offset = snap_offset::<A>(ui, idx, snap, kid_area_range, offset)
+                        .map(|snap_offset| offset_bounds.clamp_value(snap_offset));
+                    if let Some(snap_offset) = maybe_snap_offset {
+                        if (snap_offset - offset).abs() >= animation::REST_DELTA {
+                            maybe_target = Some(snap_offset);
+                        }
+                    }
+                }
+            }
+
+            // Snap points are approached using the smooth scrolling spring if there is one.
+            let target_spring = scroll_args.maybe_smooth.or_else(|| {
+                scroll_args.maybe_snap.map(|_| Spring::critically_damped(SNAP_STIFFNESS))
+            });
+
+            match (maybe_target, target_spring, scroll_args.maybe_kinetic) {
+
+                // Smoothly move towards the target offset.
+                (Some(target), Some(spring), _) => {
+                    let (new_offset, new_velocity) = spring.step(offset, velocity, target, dt);
+                    let is_settled = (new_offset - target).abs() < animation::REST_DELTA
+                        && new_velocity.abs() < MIN_KINETIC_VELOCITY;
+                    if is_settled {
+                        offset = target;
+                        velocity = 0.0;
+                        maybe_target = None;
+                    } else {
+                        offset = new_offset;
+                        velocity = new_velocity;
+                    }
+                },
+
+                // Continue with momentum, bouncing back within bounds if necessary.
+                (_, _, Some(kinetic)) => {
+                    maybe_target = None;
+                    let nearest = offset_bounds.clamp_value(offset);
+                    if nearest != offset {
+                        let (new_offset, new_velocity) =
+                            kinetic.bounce.step(offset, velocity, nearest, dt);
+                        offset = new_offset;
+                        velocity = new_velocity;
+                        if (offset - nearest).abs() < 0.5 && velocity.abs() < MIN_KINETIC_VELOCITY {
+                            offset = nearest;
+                            velocity = 0.0;
+                        }
+                    } else {
+                        offset += velocity * dt;
+                        velocity *= (-kinetic.friction * dt).exp();
+                        if velocity.abs() < MIN_KINETIC_VELOCITY {
+                            velocity = 0.0;
+                        }
+                    }
+                    offset = overscroll_bounds.clamp_value(offset);
+                },
+
+                _ => {
+                    maybe_target = None;
+                    velocity = 0.0;
+                },
+            }
+        }
+
+        // Track scrolling input so that snapping may begin once it comes to an end.
+        if scroll_args.maybe_snap.is_some() && is_scrollable && (is_dragging || scroll != 0.0) {
+            maybe_last_input = Some(time);
+        }
+
+        let is_in_motion = is_dragging
+            || maybe_target.is_some()
+            || velocity != 0.0
+            || offset_bounds.clamp_value(offset) != offset
+            || maybe_last_input.is_some();
+
+        State {
+            offset: offset,
+            offset_bounds: offset_bounds,
+            scrollable_range_len: scrollable_range.len(),
+            axis: PhantomData,
+            is_scrolling: is_in_motion || scroll != 0.0,
+            velocity: velocity,
+            is_dragging: is_dragging,
+            maybe_target_offset: maybe_target,
+            maybe_last_step: if is_in_motion { Some(time) } else { None },
+            maybe_last_input: maybe_last_input,
+        }
+    }
+
+    /// Whether or not the offset is moving independently of user input, i.e. towards the target
+    /// of a smooth scroll, with momentum or while bouncing back from overscroll.
+    ///
+    /// While this is `true`, the widget should continue to be updated.
+    pub fn is_animating(&self) -> bool {
+        !self.is_dragging && self.maybe_last_step.is_some()
+    }
+
+    /// The offset at which the axis will come to rest, i.e. the target of an in-progress smooth
+    /// scroll or otherwise the current offset.
+    pub fn target_offset(&self) -> Scalar {
+        self.maybe_target_offset.unwrap_or(self.offset)
+    }
+
+}
+
+
+impl Alignment {
+
+    /// The distance by which the given `range` must be shifted in order to be aligned within the
+    /// `visible` range.
+    pub fn delta(&self, range: Range, visible: Range) -> Scalar {
+        let range = range.undirected();
+        let visible = visible.undirected();
+        let to_start = visible.start - range.start;
+        let to_end = visible.end - range.end;
+        match *self {
+            Alignment::Start => to_start,
+            Alignment::Middle => visible.middle() - range.middle(),
+            Alignment::End => to_end,
+            Alignment::Nearest =>
+                if to_start > 0.0 && to_end > 0.0 {
+                    utils::partial_min(to_start, to_end)
+                } else if to_start < 0.0 && to_end < 0.0 {
+                    utils::partial_max(to_start, to_end)
+                } else {
+                    0.0
+                },
+        }
+    }
+
+}
+
+
+/// Determine the `offset_bounds`, un-scrolled `scrollable_range` and padded `kid_area_range` for
+/// the scrollable widget at the given index along the axis `A`.
+fn bounds<A>(ui: &Ui, idx: super::Id, kid_area: &super::KidArea, current_offset: Scalar)
+    -> (Range, Range, Range)
+    where A: Axis,
+{
+    // Padding for the range.
+    let padding = A::padding_range(kid_area.pad);
+
+    // Get the range for the Axis that concerns this particular scroll `State`.
+    let kid_area_range = A::parallel_range(kid_area.rect).pad_ends(padding.start, padding.end);
+
+    // The `kid_area_range` but centred at zero.
+    let kid_area_range_origin = Range::from_pos_and_len(0.0, kid_area_range.magnitude());
+
+    // The un-scrolled, scrollable_range relative to the kid_area_range's position.
+    let scrollable_range = {
+        ui.kids_bounding_box(idx)
+            .map(|kids| {
+                A::parallel_range(kids)
+                    .shift(-current_offset)
+                    .shift(-kid_area_range.middle())
+            })
+            .unwrap_or_else(|| Range::new(0.0, 0.0))
+    };
+
+    // Determine the min and max offset bounds. These bounds are the limits to which the
+    // scrollable_range may be shifted in either direction across the range.
+    let min_offset = Range::new(scrollable_range.start, kid_area_range_origin.start).magnitude();
+    let max_offset = Range::new(scrollable_range.end, kid_area_range_origin.end).magnitude();
+    let offset_bounds = Range::new(min_offset, max_offset);
+
+    (offset_bounds, scrollable_range, kid_area_range)
+}
+
+/// Determine the offset at which the nearest snap point is aligned with the kid area of the widget
+/// at the given index.
+///
+/// Returns `None` if there are no snap points.
+fn snap_offset<A>(ui: &Ui, idx: super::Id, snap: Snap, kid_area_range: Range, offset: Scalar)
+    -> Option<Scalar>
+    where A: Axis,
+{
+    // The position of the given edge of some range.
+    fn edge(range: Range, align: Align) -> Scalar {
+        let range = range.undirected();
+        match align {
+            Align::Start => range.start,
+            Align::Middle => range.middle(),
+            Align::End => range.end,
+        }
+    }
+
+    // The offset at which the nearest multiple of `interval` from the `align` edge of the
+    // scrollable range is aligned with the same edge of the kid area.
+    let interval_offset = |interval: Scalar, align: Align| {
+        if interval <= 0.0 {
+            return None;
+        }
+        ui.kids_bounding_box(idx).map(|kids| {
+            let origin = edge(A::parallel_range(kids), align);
+            let target = edge(kid_area_range, align);
+            let step = if let Align::End = align { -interval } else { interval };
+            let point = origin + ((target - origin) / step).round() * step;
+            offset + target - point
+        })
+    };
+
+    match snap {
+        Snap::KidEdges(align) => {
+            let graph = ui.widget_graph();
+            let prev_updated = ui.prev_updated_widgets();
+            let target = edge(kid_area_range, align);
+            graph.depth_children(idx).iter(graph).nodes()
+                .filter(|&n| graph.graphic_parent(n).is_none() && prev_updated.contains(&n))
+                .filter_map(|n| ui.rect_of(n))
+                .map(|rect| offset + target - edge(A::parallel_range(rect), align))
+                .fold(None, |nearest: Option<Scalar>, kid_offset| match nearest {
+                    Some(n) if (n - offset).abs() <= (kid_offset - offset).abs() => Some(n),
+                    _ => Some(kid_offset),
+                })
+        },
+        Snap::Interval(interval, align) => interval_offset(interval, align),
+        Snap::Pages(align) => interval_offset(kid_area_range.len(), align),
+    }
+}
+
+/// The distance that a pointer held over the kid area of the widget at the given index has moved
+/// since the last update.
+///
+/// This includes left mouse button drags and touches that began over the widget or any of its
+/// descendants.
+///
+/// Returns `None` if no pointer is currently held over the widget's kid area.
+pub fn drag_delta(ui: &Ui, idx: super::Id) -> Option<Point> {
+    let global = ui.global_input();
+    let is_widget_or_descendant = |w: super::Id| {
+        w == idx || ui.widget_graph().does_recursive_depth_edge_exist(idx, w)
+    };
+    let mut maybe_delta = None;
+
+    // Mouse drags that began over the widget or any of its descendants.
+    if let Some((_, Some(w))) = global.current.mouse.buttons.left().if_down() {
+        if is_widget_or_descendant(w) {
+            let xy = global.current.mouse.xy;
+            let prev_xy = global.start.mouse.xy;
+            maybe_delta = Some([xy[0] - prev_xy[0], xy[1] - prev_xy[1]]);
+        }
+    }
+
+    // Touches that began over the widget or any of its descendants.
+    for (touch_id, touch) in global.current.touch.iter() {
+        if !touch.start.widget.map_or(false, &is_widget_or_descendant) {
+            continue;
         }
+        let prev_xy = global.start.touch.get(touch_id)
+            .map(|prev| prev.xy)
+            .unwrap_or(touch.start.xy);
+        let delta = maybe_delta.unwrap_or([0.0, 0.0]);
+        maybe_delta = Some([delta[0] + touch.xy[0] - prev_xy[0],
+                            delta[1] + touch.xy[1] - prev_xy[1]]);
     }
 
+    maybe_delta
 }
 
 
@@ -273,3 +747,53 @@ impl Axis for Y {
     }
 
 }
+
+/// Determine the `Scroll` events required to bring the given `rect` into view.
+///
+/// The `rect` is assumed to be positioned somewhere within the kid area of the widget at the given
+/// index, e.g. the `Rect` of one of its kid widgets. Beginning with this widget and working
+/// outwards through its depth parents, each scrollable widget is scrolled so that the `rect` is
+/// aligned within its padded kid area, limited by its `offset_bounds`.
+///
+/// Returns the index of each widget that must be scrolled, along with the amount by which it must
+/// be scrolled along each axis.
+pub fn scroll_into_view(ui: &Ui, idx: super::Id, rect: Rect, alignment: Alignment)
+    -> Vec<(super::Id, Point)>
+{
+    // Determine the distance to scroll a single axis along with the distance that the `rect` will
+    // move as a result.
+    fn scroll_axis<A>(state: &State<A>, kid_area: Rect, rect: Rect, alignment: Alignment)
+        -> (Scalar, Scalar)
+        where A: Axis,
+    {
+        let visible = A::parallel_range(kid_area);
+        if state.scrollable_range_len <= visible.len() {
+            return (0.0, 0.0);
+        }
+        let delta = alignment.delta(A::parallel_range(rect), visible);
+        let offset = state.offset_bounds.clamp_value(state.offset + delta);
+        (offset - state.target_offset(), offset - state.offset)
+    }
+
+    let graph = ui.widget_graph();
+    let mut rect = rect;
+    let mut scrolls = Vec::new();
+    let mut maybe_idx = Some(idx);
+    while let Some(idx) = maybe_idx {
+        if let Some(container) = graph.widget(idx) {
+            let kid_area = container.kid_area.rect.padding(container.kid_area.pad);
+            let (scroll_x, shift_x) = container.maybe_x_scroll_state
+                .map(|state| scroll_axis(&state, kid_area, rect, alignment))
+                .unwrap_or((0.0, 0.0));
+            let (scroll_y, shift_y) = container.maybe_y_scroll_state
+                .map(|state| scroll_axis(&state, kid_area, rect, alignment))
+                .unwrap_or((0.0, 0.0));
+            if scroll_x != 0.0 || scroll_y != 0.0 {
+                scrolls.push((idx, [scroll_x, scroll_y]));
+            }
+            rect = rect.shift([shift_x, shift_y]);
+        }
+        maybe_idx = graph.depth_parent(idx);
+    }
+    scrolls
+}
@@ -51,6 +51,9 @@ pub struct Style {
     /// The font used for the `Text`.
     #[conrod(default = "theme.font_id")]
     pub font_id: Option<Option<text::font::Id>>,
+    /// The interval at which the cursor alternates between visible and hidden.
+    #[conrod(default = "theme.text_cursor_blink_interval")]
+    pub cursor_blink_interval: Option<Option<std::time::Duration>>,
 }
 
 widget_ids! {
@@ -68,6 +71,10 @@ pub struct State {
     drag: Option<Drag>,
     /// Information about each line of text.
     line_infos: Vec<text::line::Info>,
+    /// The moment from which the cursor blink phase is measured.
+    ///
+    /// This is `None` while the widget is not capturing the keyboard.
+    cursor_blink_start: Option<instant::Instant>,
     ids: Ids,
 }
 
@@ -167,6 +174,18 @@ impl<'a> TextEdit<'a> {
         self
     }
 
+    /// Specify the interval at which the cursor alternates between visible and hidden.
+    pub fn cursor_blink_interval(mut self, interval: std::time::Duration) -> Self {
+        self.style.cursor_blink_interval = Some(Some(interval));
+        self
+    }
+
+    /// Do not blink the cursor, instead always showing it while the `TextEdit` is focused.
+    pub fn no_cursor_blink(mut self) -> Self {
+        self.style.cursor_blink_interval = Some(None);
+        self
+    }
+
     builder_methods!{
         pub font_size { style.font_size = Some(FontSize) }
         pub justify { style.justify = Some(text::Justify) }
@@ -192,6 +211,7 @@ impl<'a> Widget for TextEdit<'a> {
             cursor: Cursor::Idx(text::cursor::Index { line: 0, char: 0 }),
             drag: None,
             line_infos: Vec::new(),
+            cursor_blink_start: None,
             ids: Ids::new(id_gen),
         }
     }
@@ -858,6 +878,23 @@ impl<'a> Widget for TextEdit<'a> {
             state.update(|state| state.drag = drag);
         }
 
+        // Restart the cursor blink whenever the widget gains keyboard capture, the text is edited
+        // or the cursor is moved.
+        let is_capturing_keyboard = ui.global_input().current.widget_capturing_keyboard == Some(id);
+        let text_has_changed = match text {
+            std::borrow::Cow::Owned(_) => true,
+            std::borrow::Cow::Borrowed(_) => false,
+        };
+        let now = instant::Instant::now();
+        let cursor_blink_start = match state.cursor_blink_start {
+            _ if !is_capturing_keyboard => None,
+            Some(start) if !cursor_has_changed && !text_has_changed => Some(start),
+            _ => Some(now),
+        };
+        if state.cursor_blink_start != cursor_blink_start {
+            state.update(|state| state.cursor_blink_start = cursor_blink_start);
+        }
+
         // Takes the `String` from the `Cow` if the `Cow` is `Owned`.
         fn take_if_owned(text: std::borrow::Cow<str>) -> Option<String> {
             match text {
@@ -895,9 +932,28 @@ impl<'a> Widget for TextEdit<'a> {
         };
 
         // If this widget is not capturing the keyboard, no need to draw cursor or selection.
-        if ui.global_input().current.widget_capturing_keyboard != Some(id) {
-            return take_if_owned(text);
-        }
+        let cursor_blink_start = match cursor_blink_start {
+            Some(start) => start,
+            None => return take_if_owned(text),
+        };
+
+        // Determine whether the cursor is within the visible phase of its blink and request a
+        // redraw for the moment at which the phase next changes.
+        let is_cursor_visible = match style.cursor_blink_interval(ui.theme()) {
+            Some(interval) if interval > std::time::Duration::from_millis(0) => {
+                let elapsed = now.duration_since(cursor_blink_start);
+                let interval_nanos = duration_nanos(interval);
+                let num_phases = duration_nanos(elapsed) / interval_nanos;
+                let next_phase_nanos = (num_phases + 1) * interval_nanos;
+                let next_phase = std::time::Duration::new(
+                    (next_phase_nanos / 1_000_000_000) as u64,
+                    (next_phase_nanos % 1_000_000_000) as u32,
+                );
+                ui.redraw_at(cursor_blink_start + next_phase);
+                num_phases % 2 == 0
+            },
+            _ => true,
+        };
 
         let (cursor_x, cursor_y_range) = {
             let font = ui.fonts.get(font_id).unwrap();
@@ -912,18 +968,20 @@ impl<'a> Widget for TextEdit<'a> {
         let start = [0.0, cursor_y_range.start];
         let end = [0.0, cursor_y_range.end];
         let prev_cursor_rect = ui.rect_of(state.ids.cursor);
-        widget::Line::centred(start, end)
-            .x_y(cursor_x, cursor_y_range.middle())
-            .graphics_for(id)
-            .parent(id)
-            .color(color)
-            .set(state.ids.cursor, ui);
+        if is_cursor_visible {
+            widget::Line::centred(start, end)
+                .x_y(cursor_x, cursor_y_range.middle())
+                .graphics_for(id)
+                .parent(id)
+                .color(color)
+                .set(state.ids.cursor, ui);
+        }
 
         // If the cursor position has changed due to input AND one of our parent widgets are
         // scrollable AND the change in cursor position would cause the cursor to fall outside the
         // scrollable parent's `Rect`, attempt to scroll the scrollable parent so that the cursor
         // would be visible.
-        if cursor_has_changed {
+        if cursor_has_changed && is_cursor_visible {
             let cursor_rect = ui.rect_of(state.ids.cursor).unwrap();
             if prev_cursor_rect != Some(cursor_rect) {
                 use graph::Walker;
@@ -983,6 +1041,12 @@ impl<'a> Widget for TextEdit<'a> {
 }
 
 
+/// The total number of nanoseconds within the given `Duration`.
+fn duration_nanos(duration: std::time::Duration) -> u128 {
+    duration.as_secs() as u128 * 1_000_000_000 + duration.subsec_nanos() as u128
+}
+
+
 impl<'a> Colorable for TextEdit<'a> {
     builder_method!(color { style.color = Some(Color) });
 }
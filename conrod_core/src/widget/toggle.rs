@@ -1,6 +1,7 @@
 //! A button that allows for toggling boolean state.
 
 use {Color, Colorable, FontSize, Borderable, Labelable, Positionable, Scalar, Widget};
+use animation;
 use position::{self, Align};
 use text;
 use widget;
@@ -51,6 +52,9 @@ pub struct Style {
     /// The position of the title bar's `Label` widget over the *y* axis.
     #[conrod(default = "position::Relative::Align(Align::Middle)")]
     pub label_y: Option<position::Relative>,
+    /// The transition used to animate the Toggle's color between its states.
+    #[conrod(default = "theme.transition")]
+    pub transition: Option<animation::Transition>,
 }
 
 widget_ids! {
@@ -122,6 +126,12 @@ impl<'a> Toggle<'a> {
         self
     }
 
+    /// Specify the transition used to animate the `Toggle`'s color between its states.
+    pub fn transition(mut self, transition: animation::Transition) -> Self {
+        self.style.transition = Some(transition);
+        self
+    }
+
     builder_methods!{
         pub enabled { enabled = bool }
     }
@@ -162,7 +172,14 @@ impl<'a> Widget for Toggle<'a> {
         let color = {
             let color = style.color(ui.theme());
             let new_value = times_clicked.clone().last().unwrap_or(value);
-            let color = if new_value { color } else { color.with_luminance(0.1) };
+            let target = if new_value { 1.0 } else { 0.0 };
+            let transition = style.transition(ui.theme());
+            let amount = ui.animate(id, "amount", target, transition);
+            let color = match amount {
+                a if a >= 1.0 => color,
+                a if a <= 0.0 => color.with_luminance(0.1),
+                a => color.with_luminance(0.1).lerp(color, a as f32),
+            };
             match ui.widget_input(id).mouse() {
                 Some(mouse) =>
                     if mouse.buttons.left().is_down() { color.clicked() }